@@ -0,0 +1,66 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Hash sources of every day crate together with the common crate, answers cached by the runner
+// are valid only for the sources they were solved by
+fn main() {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let common = workspace.join("common");
+
+    let mut days = fs::read_dir(&workspace)
+        .expect("Failed to read workspace")
+        .map(|entry| entry.expect("Failed to read workspace").path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("day_"))
+        })
+        .collect::<Vec<_>>();
+    days.sort();
+
+    let hashes = days
+        .iter()
+        .map(|day| {
+            let mut context = md5::Context::new();
+
+            for dir in [day, &common] {
+                println!("cargo:rerun-if-changed={}", dir.join("src").display());
+                println!(
+                    "cargo:rerun-if-changed={}",
+                    dir.join("Cargo.toml").display()
+                );
+
+                let mut files = vec![dir.join("Cargo.toml")];
+                collect_files(&dir.join("src"), &mut files);
+                files.sort();
+
+                for file in files {
+                    let relative = file.strip_prefix(&workspace).unwrap_or(&file);
+                    context.consume(relative.to_string_lossy().as_bytes());
+                    context.consume(fs::read(&file).expect("Failed to read source file"));
+                }
+            }
+
+            format!("{:x}", context.finalize())
+        })
+        .collect::<Vec<_>>();
+
+    let content = format!(
+        "pub const SOURCE_HASHES: [&str; {}] = {hashes:?};\n",
+        hashes.len()
+    );
+
+    let out = PathBuf::from(std::env::var("OUT_DIR").expect("Missing OUT_DIR"));
+    fs::write(out.join("source_hashes.rs"), content).expect("Failed to write source hashes");
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).expect("Failed to read source directory") {
+        let path = entry.expect("Failed to read source directory").path();
+
+        match path.is_dir() {
+            true => collect_files(&path, files),
+            false => files.push(path),
+        }
+    }
+}
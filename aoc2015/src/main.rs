@@ -0,0 +1,222 @@
+use std::error::Error;
+
+use common::input::InputSource;
+
+use crate::runner::answers::{self, Answers};
+use crate::runner::args::{Args, USAGE};
+use crate::runner::batch;
+use crate::runner::bench::{self, Baseline, BenchConfig, Comparison};
+use crate::runner::cache::{self, Cache};
+use crate::runner::export::{self, Format};
+use crate::runner::fuzz::{self, FuzzConfig};
+use crate::runner::generate::{self, GenerateConfig};
+use crate::runner::report::{self, ReportConfig};
+use crate::runner::results::{DayResult, SolveOptions};
+use crate::runner::{parallel, registry, results, table};
+
+mod runner;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(std::env::args().skip(1))?;
+
+    if args.help {
+        println!("{USAGE}");
+        return Ok(());
+    }
+
+    if let Some(config) = &args.bench {
+        return run_bench(&args, config);
+    }
+
+    if let Some(config) = &args.generate {
+        return run_generate(&args, config);
+    }
+
+    if let Some(config) = &args.fuzz {
+        return run_fuzz(&args, config);
+    }
+
+    if args.clear_cache {
+        return run_clear_cache();
+    }
+
+    // Load answers up front, there is no point in solving anything with invalid file
+    let answers = match args.verify {
+        true => {
+            let path = args.answers.clone().unwrap_or_else(answers::default_path);
+            Some(Answers::load(&path)?)
+        }
+        false => None,
+    };
+
+    // Answers of previous runs, the same file is updated by this run
+    let cache_path = cache::default_path();
+    let mut cache = match args.no_cache {
+        true => None,
+        false => Some(Cache::load(&cache_path)?),
+    };
+
+    let options = SolveOptions {
+        limits: args.limits,
+        explain: args.explain,
+        cache: cache.as_ref(),
+        log: Some(&args.log),
+        verify: args.verify,
+    };
+
+    // Batch solves the single selected day once for every input file of the directory
+    let batch = args.batch.as_deref().map(batch::list_inputs).transpose()?;
+
+    let days = args
+        .days
+        .iter()
+        .filter_map(|day| registry::find(*day))
+        .flat_map(|entry| match &batch {
+            Some(files) => files
+                .iter()
+                .map(|file| (entry, Some(InputSource::File(file.clone()))))
+                .collect(),
+            None => vec![(entry, args.input_source(entry.day))],
+        })
+        .collect::<Vec<_>>();
+
+    let mut results = match args.serial {
+        true => days
+            .into_iter()
+            .map(|(entry, source)| results::run_day(entry, &args.parts, source, options))
+            .collect::<Vec<_>>(),
+        false => {
+            let jobs = args.jobs.unwrap_or_else(parallel::default_jobs);
+            parallel::run_days(&days, &args.parts, jobs, options)
+        }
+    };
+
+    if let Some(cache) = &mut cache
+        && cache.update(&results) > 0
+    {
+        cache.save(&cache_path)?;
+    }
+
+    if let Some(answers) = &answers {
+        results.iter_mut().for_each(|result| result.verify(answers));
+    }
+
+    if let Some(config) = &args.report {
+        return run_report(config, &results);
+    }
+
+    match (&batch, args.format) {
+        (Some(files), _) => println!("{}", batch::render(files, &results)),
+        (None, Format::Table) => table::print(&results),
+        (None, Format::JsonLines) => println!("{}", export::render_json_lines(&results)),
+        (None, Format::Csv) => println!("{}", export::render_csv(&results)),
+    }
+
+    let failed = results.iter().map(|r| r.failed_count()).sum::<usize>();
+    if failed > 0 {
+        return Err(format!("{failed} part(s) failed").into());
+    }
+
+    Ok(())
+}
+
+fn run_generate(args: &Args, config: &GenerateConfig) -> Result<(), Box<dyn Error>> {
+    let dir = config.output_dir();
+
+    for entry in args.days.iter().filter_map(|day| registry::find(*day)) {
+        let (path, count) = generate::write_day(entry, config, &dir)?;
+        println!(
+            "Day {:02}: {count} line(s) written to '{}'",
+            entry.day,
+            path.display()
+        );
+    }
+
+    Ok(())
+}
+
+fn run_fuzz(args: &Args, config: &FuzzConfig) -> Result<(), Box<dyn Error>> {
+    let mut failed = 0;
+
+    for entry in args.days.iter().filter_map(|day| registry::find(*day)) {
+        match fuzz::fuzz_day(entry, config) {
+            Ok(()) => println!(
+                "Day {:02}: {} mutation(s) parsed without panic",
+                entry.day, config.iterations
+            ),
+            Err(failure) => {
+                println!("Day {:02}: {failure}", entry.day);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{failed} day(s) panicked").into());
+    }
+
+    Ok(())
+}
+
+// Unsolved parts are listed in the report, they do not fail the command
+fn run_report(config: &ReportConfig, results: &[DayResult]) -> Result<(), Box<dyn Error>> {
+    let path = config.output_path();
+    report::write(&path, &report::render(results))?;
+
+    println!(
+        "Report of {} day(s) with {} star(s) written to '{}'",
+        results.len(),
+        report::star_count(results),
+        path.display()
+    );
+
+    Ok(())
+}
+
+fn run_clear_cache() -> Result<(), Box<dyn Error>> {
+    let path = cache::default_path();
+
+    match Cache::clear(&path)? {
+        true => println!("Cache '{}' cleared", path.display()),
+        false => println!("Cache '{}' is already empty", path.display()),
+    }
+
+    Ok(())
+}
+
+fn run_bench(args: &Args, config: &BenchConfig) -> Result<(), Box<dyn Error>> {
+    let path = args
+        .baseline
+        .clone()
+        .unwrap_or_else(bench::default_baseline_path);
+    let mut baseline = Baseline::load(&path)?;
+
+    let benches = args
+        .days
+        .iter()
+        .filter_map(|day| registry::find(*day))
+        .map(|entry| bench::bench_day(entry, &args.parts, args.input_source(entry.day), config))
+        .collect::<Vec<_>>();
+
+    let measurements = bench::compare(&benches, &baseline, config.threshold);
+    println!("{}", bench::render(&benches, &measurements));
+
+    if args.save_baseline {
+        baseline.update(&measurements);
+        baseline.save(&path)?;
+    }
+
+    let failed = benches.iter().filter(|bench| bench.result.is_err()).count();
+    let slower = measurements
+        .iter()
+        .filter(|measurement| matches!(measurement.comparison, Comparison::Slower(_)))
+        .count();
+
+    if failed > 0 || slower > 0 {
+        return Err(
+            format!("{failed} day(s) failed, {slower} phase(s) slower than baseline").into(),
+        );
+    }
+
+    Ok(())
+}
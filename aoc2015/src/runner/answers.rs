@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+// Answers file stored in the repository, i.e. <workspace>/input/answers.txt
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../input/answers.txt")
+}
+
+// Hash identifying the input, line endings and trailing new line do not matter
+pub fn hash_input(lines: &[String]) -> String {
+    format!("{:x}", md5::compute(lines.join("\n")))
+}
+
+// Result of comparing answer of the part with the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Match => "match",
+            Verdict::Mismatch { .. } => "mismatch",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    pub fn expected(&self) -> Option<&str> {
+        match self {
+            Verdict::Mismatch { expected } => Some(expected),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Mismatch { expected } => write!(f, "mismatch, expected {expected}"),
+            verdict => write!(f, "{}", verdict.name()),
+        }
+    }
+}
+
+// Known answers keyed by day, part and hash of the input. Every line of the file has format
+// '<day> <part> <hash> <answer>', empty lines and lines starting with '#' are ignored.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: HashMap<(usize, usize, String), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read answers file '{}' [{err}]", path.display()))?;
+
+        Self::parse(&content)
+            .map_err(|err| format!("Invalid answers file '{}' [{err}]", path.display()).into())
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut entries = HashMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("Line {} has invalid format '{line}'", index + 1);
+
+            let mut fields = line.splitn(4, char::is_whitespace);
+            let (Some(day), Some(part), Some(hash), Some(answer)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid());
+            };
+
+            let day = day.parse::<usize>().map_err(|_| invalid())?;
+            let part = part.parse::<usize>().map_err(|_| invalid())?;
+
+            entries.insert((day, part, hash.to_string()), answer.trim().to_string());
+        }
+
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, day: usize, part: usize, hash: &str) -> Option<&str> {
+        self.entries
+            .get(&(day, part, hash.to_string()))
+            .map(String::as_str)
+    }
+
+    // Failed part does not match the known answer, there is nothing to compare it with
+    // when the input could not be read
+    pub fn verify(
+        &self,
+        day: usize,
+        part: usize,
+        hash: Option<&str>,
+        answer: &Result<String, String>,
+    ) -> Verdict {
+        let Some(expected) = hash.and_then(|hash| self.get(day, part, hash)) else {
+            return Verdict::Unknown;
+        };
+
+        match answer {
+            Ok(answer) if answer == expected => Verdict::Match,
+            _ => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "\
+# day part hash answer
+1 1 abc 280
+
+1 2 abc 1797
+25 2 def Not solved
+";
+
+    #[test]
+    fn test_hash_input() {
+        let lines = vec![String::from("(()"), String::from("))")];
+        assert_eq!(hash_input(&lines), format!("{:x}", md5::compute("(()\n))")));
+        assert_ne!(hash_input(&lines), hash_input(&lines[..1]));
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(CONTENT).unwrap();
+        assert_eq!(answers.get(1, 1, "abc"), Some("280"));
+        assert_eq!(answers.get(1, 2, "abc"), Some("1797"));
+        assert_eq!(answers.get(25, 2, "def"), Some("Not solved"));
+        assert_eq!(answers.get(1, 1, "def"), None);
+        assert_eq!(answers.get(2, 1, "abc"), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("1 1 abc").is_err());
+        assert!(Answers::parse("x 1 abc 280").is_err());
+        assert!(Answers::parse("1 y abc 280").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse(CONTENT).unwrap();
+        let verify = |part, hash, answer: Result<&str, &str>| {
+            let answer = answer.map(String::from).map_err(String::from);
+            answers.verify(1, part, hash, &answer)
+        };
+
+        assert_eq!(verify(1, Some("abc"), Ok("280")), Verdict::Match);
+        assert_eq!(
+            verify(1, Some("abc"), Ok("281")),
+            Verdict::Mismatch {
+                expected: String::from("280")
+            }
+        );
+        assert_eq!(
+            verify(2, Some("abc"), Err("Failed")),
+            Verdict::Mismatch {
+                expected: String::from("1797")
+            }
+        );
+        assert_eq!(verify(1, Some("xyz"), Ok("280")), Verdict::Unknown);
+        assert_eq!(verify(1, None, Ok("280")), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_verdict_display() {
+        assert_eq!(Verdict::Match.to_string(), "match");
+        assert_eq!(Verdict::Unknown.to_string(), "unknown");
+        assert_eq!(
+            Verdict::Mismatch {
+                expected: String::from("280")
+            }
+            .to_string(),
+            "mismatch, expected 280"
+        );
+    }
+}
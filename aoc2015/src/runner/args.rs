@@ -0,0 +1,781 @@
+use std::error::Error;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use common::budget::Limits;
+use common::input::{self, InputSource};
+use common::log::{self, Level};
+
+use crate::runner::bench::BenchConfig;
+use crate::runner::export::Format;
+use crate::runner::fuzz::FuzzConfig;
+use crate::runner::generate::GenerateConfig;
+use crate::runner::report::ReportConfig;
+
+// Subcommands used instead of printing answers, i.e. writing generated inputs, fuzzing of
+// parsers, removing of cached answers and writing summary of the year
+const GENERATE: &str = "generate";
+const FUZZ: &str = "fuzz";
+const CLEAR_CACHE: &str = "clear-cache";
+const REPORT: &str = "report";
+
+pub const USAGE: &str = "\
+Usage: aoc2015 [OPTIONS]
+       aoc2015 generate [--day <N>|--days <A-B>|--all] [--seed <N>] [--size <N>] [--output <DIR>]
+       aoc2015 fuzz [--day <N>|--days <A-B>|--all] [--seed <N>] [--iterations <N>]
+       aoc2015 clear-cache
+       aoc2015 report [--day <N>|--days <A-B>|--all] [--input-dir <DIR>] [--output <FILE>]
+
+Options:
+  --all              Run all days (default)
+  --day <N>          Run single day, can be repeated
+  --days <A-B>       Run range of days, e.g. 1-10
+  --part <N>         Run only part 1 or 2 (default both)
+  --input <PATH|->   Input file of a single selected day, '-' reads stdin
+  --input-dir <DIR>  Directory with day_XX.txt input files
+  --batch <DIR>      Solve a single selected day against every input file of the directory
+  --format <FORMAT>  Output format: table (default), jsonl or csv
+  --jobs <N>         Number of worker threads (default number of CPUs)
+  --serial           Solve days and parts one after another, e.g. for timing
+  --timeout <SECS>   Wall-clock limit of long searches of every day, e.g. 2.5
+  --max-steps <N>    Limit of steps of every long search
+  --verify           Compare answers with the known answers of the input, every part is
+                     solved even when its answer is cached
+  --answers <PATH>   Answers file used by '--verify' (default input/answers.txt)
+  --no-cache         Solve every part, answers of previous runs are neither used nor stored
+                     (cached in target/cache/answers.txt, removed by 'clear-cache')
+  --explain          Print evidence of the answers, e.g. the route of day 9, where available
+  --log <[DAY=]LEVEL>
+                     Write messages of the days up to the level to stderr: error, warn
+                     (default), info, debug or trace. Can be repeated, e.g. '--log 18=trace'
+                     raises the level of day 18 only
+  --bench            Benchmark parse and solve phases instead of printing answers
+  --warmup <N>       Untimed runs before benchmarking a day (default 1)
+  --samples <N>      Timed runs of every day (default 10)
+  --threshold <PCT>  Slowdown of median against the baseline to flag (default 10)
+  --baseline <PATH>  Baseline file (default target/bench/baseline.txt)
+  --save-baseline    Store medians of this run as the new baseline
+  --seed <N>         Seed of generated inputs or mutations, same seed gives same inputs
+                     (default 2015)
+  --size <N>         Size of generated inputs, meaning depends on the day (default 100)
+  --output <DIR>     Directory of generated inputs (default target/generated), use it
+                     with '--input-dir' to solve them
+  --output <FILE>    Markdown file of the report (default target/report/summary.md)
+  --iterations <N>   Number of mutated inputs fed to the parser of every day (default 1000)
+  -h, --help         Print this help
+
+Environment:
+  AOC_INPUT_DAY_XX   Input file of day XX, '-' reads stdin
+  AOC_INPUT_DIR      Directory with day_XX.txt input files";
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub days: Vec<usize>,
+    pub parts: Vec<usize>,
+    pub input: Option<InputSource>,
+    pub input_dir: Option<PathBuf>,
+    pub batch: Option<PathBuf>,
+    pub format: Format,
+    pub jobs: Option<usize>,
+    pub serial: bool,
+    pub limits: Limits,
+    pub verify: bool,
+    pub answers: Option<PathBuf>,
+    pub explain: bool,
+    pub no_cache: bool,
+    pub log: log::Filter,
+    pub bench: Option<BenchConfig>,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: bool,
+    pub generate: Option<GenerateConfig>,
+    pub fuzz: Option<FuzzConfig>,
+    pub clear_cache: bool,
+    pub report: Option<ReportConfig>,
+    pub help: bool,
+}
+
+impl Args {
+    pub fn parse<I>(args: I) -> Result<Self, Box<dyn Error>>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut days = Vec::new();
+        let mut parts = Vec::new();
+        let mut input = None;
+        let mut input_dir = None;
+        let mut batch = None;
+        let mut format = Format::Table;
+        let mut jobs = None;
+        let mut serial = false;
+        let mut limits = Limits::default();
+        let mut verify = false;
+        let mut answers = None;
+        let mut explain = false;
+        let mut no_cache = false;
+        let mut log = log::Filter::default();
+        let mut bench = false;
+        let mut config = BenchConfig::default();
+        let mut baseline = None;
+        let mut save_baseline = false;
+        let mut seed = None;
+        let mut size = None;
+        let mut output = None;
+        let mut iterations = None;
+        let mut help = false;
+
+        let mut args = args.into_iter().peekable();
+        let command =
+            args.next_if(|arg| [GENERATE, FUZZ, CLEAR_CACHE, REPORT].contains(&arg.as_str()));
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => days.extend(1..=25),
+                "--day" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    days.push(Self::parse_day(&value)?);
+                }
+                "--days" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    days.extend(Self::parse_days(&value)?);
+                }
+                "--part" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    parts.push(Self::parse_part(&value)?);
+                }
+                "--input" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    input = Some(InputSource::from_arg(&value));
+                }
+                "--input-dir" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    input_dir = Some(PathBuf::from(value));
+                }
+                "--batch" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    batch = Some(PathBuf::from(value));
+                }
+                "--format" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    format = Format::from_arg(&value).ok_or_else(|| {
+                        format!("Format shall be table, jsonl or csv, found '{value}'")
+                    })?;
+                }
+                "--jobs" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    let count = Self::parse_count(&arg, &value)?;
+
+                    if count == 0 {
+                        return Err("Option '--jobs' requires at least one thread".into());
+                    }
+
+                    jobs = Some(count);
+                }
+                "--serial" => serial = true,
+                "--timeout" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    limits.timeout = Some(
+                        value
+                            .parse::<f64>()
+                            .ok()
+                            .filter(|secs| *secs > 0.0)
+                            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                            .ok_or_else(|| format!("Invalid timeout '{value}'"))?,
+                    );
+                }
+                "--max-steps" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    let count = Self::parse_count(&arg, &value)?;
+
+                    if count == 0 {
+                        return Err("Option '--max-steps' requires at least one step".into());
+                    }
+
+                    limits.max_steps = Some(count as u64);
+                }
+                "--verify" => verify = true,
+                "--answers" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    answers = Some(PathBuf::from(value));
+                }
+                "--explain" => explain = true,
+                "--no-cache" => no_cache = true,
+                "--log" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+
+                    match value.split_once('=') {
+                        Some((day, level)) => {
+                            log.days
+                                .insert(Self::parse_day(day)?, level.parse::<Level>()?);
+                        }
+                        None => log.default = value.parse::<Level>()?,
+                    }
+                }
+                "--bench" => bench = true,
+                "--warmup" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    config.warmup = Self::parse_count(&arg, &value)?;
+                }
+                "--samples" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    config.samples = Self::parse_count(&arg, &value)?;
+
+                    if config.samples == 0 {
+                        return Err("Option '--samples' requires at least one sample".into());
+                    }
+                }
+                "--threshold" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    config.threshold = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|threshold| *threshold >= 0.0)
+                        .ok_or_else(|| format!("Invalid threshold '{value}'"))?;
+                }
+                "--baseline" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    baseline = Some(PathBuf::from(value));
+                }
+                "--save-baseline" => save_baseline = true,
+                "--seed" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    seed = Some(
+                        value
+                            .parse::<u64>()
+                            .map_err(|err| format!("Failed to parse seed '{value}' [{err}]"))?,
+                    );
+                }
+                "--size" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    let count = Self::parse_count(&arg, &value)?;
+
+                    if count == 0 {
+                        return Err("Option '--size' requires size of at least one".into());
+                    }
+
+                    size = Some(count);
+                }
+                "--output" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    output = Some(PathBuf::from(value));
+                }
+                "--iterations" => {
+                    let value = Self::next_value(&mut args, &arg)?;
+                    iterations = Some(Self::parse_count(&arg, &value)?);
+                }
+                "-h" | "--help" => help = true,
+                _ => return Err(format!("Unknown argument '{arg}'").into()),
+            }
+        }
+
+        // Nothing selected means everything
+        if days.is_empty() {
+            days.extend(1..=25);
+        }
+
+        let all_parts = parts.is_empty();
+        if all_parts {
+            parts.extend([1, 2]);
+        }
+
+        days.sort();
+        days.dedup();
+        parts.sort();
+        parts.dedup();
+
+        // Single input can not be shared by multiple days
+        if input.is_some() && days.len() != 1 {
+            return Err("Option '--input' requires exactly one selected day".into());
+        }
+
+        if input.is_some() && input_dir.is_some() {
+            return Err("Options '--input' and '--input-dir' can not be combined".into());
+        }
+
+        if batch.is_some() && days.len() != 1 {
+            return Err("Option '--batch' requires exactly one selected day".into());
+        }
+
+        // Batch prints its own matrix of files and parts
+        if batch.is_some()
+            && (input.is_some() || input_dir.is_some() || explain || format != Format::Table)
+        {
+            return Err(
+                "Option '--batch' can not be combined with '--input', '--input-dir', \
+                 '--explain' or '--format'"
+                    .into(),
+            );
+        }
+
+        if serial && jobs.is_some() {
+            return Err("Options '--serial' and '--jobs' can not be combined".into());
+        }
+
+        // Benchmark measures silent runs only
+        if bench
+            && (verify
+                || batch.is_some()
+                || format != Format::Table
+                || log != log::Filter::default())
+        {
+            return Err(
+                "Option '--bench' can not be combined with '--verify', '--batch', '--format' or \
+                 '--log'"
+                    .into(),
+            );
+        }
+
+        // Witnesses are nested values, there is no column for them in CSV
+        if explain && (bench || format == Format::Csv) {
+            return Err(
+                "Option '--explain' can not be combined with '--bench' or '--format csv'".into(),
+            );
+        }
+
+        // Benchmark of a search cut short by the budget would be meaningless
+        if bench && limits != Limits::default() {
+            return Err(
+                "Option '--bench' can not be combined with '--timeout' or '--max-steps'".into(),
+            );
+        }
+
+        let command = command.as_deref();
+
+        if size.is_some() && command != Some(GENERATE) {
+            return Err(format!("Option '--size' requires '{GENERATE}' subcommand").into());
+        }
+
+        if output.is_some() && command != Some(GENERATE) && command != Some(REPORT) {
+            return Err(format!(
+                "Option '--output' requires '{GENERATE}' or '{REPORT}' subcommand"
+            )
+            .into());
+        }
+
+        if iterations.is_some() && command != Some(FUZZ) {
+            return Err(format!("Option '--iterations' requires '{FUZZ}' subcommand").into());
+        }
+
+        if seed.is_some() && command.is_none() {
+            return Err(
+                format!("Option '--seed' requires '{GENERATE}' or '{FUZZ}' subcommand").into(),
+            );
+        }
+
+        // Report solves both parts of the selected days and prints nothing but the summary
+        if command == Some(REPORT)
+            && (!all_parts
+                || input.is_some()
+                || batch.is_some()
+                || verify
+                || explain
+                || bench
+                || format != Format::Table)
+        {
+            return Err(format!(
+                "Subcommand '{REPORT}' can not be combined with '--part', '--input', '--batch', \
+                 '--verify', '--explain', '--bench' or '--format'"
+            )
+            .into());
+        }
+
+        // Other subcommands do not solve anything
+        if let Some(command) = command
+            && command != REPORT
+            && (input.is_some()
+                || input_dir.is_some()
+                || batch.is_some()
+                || verify
+                || explain
+                || no_cache
+                || log != log::Filter::default()
+                || bench)
+        {
+            return Err(format!(
+                "Subcommand '{command}' can not be combined with '--input', '--input-dir', \
+                 '--batch', '--verify', '--explain', '--no-cache', '--log' or '--bench'"
+            )
+            .into());
+        }
+
+        let generate = (command == Some(GENERATE)).then(|| {
+            let default = GenerateConfig::default();
+
+            GenerateConfig {
+                seed: seed.unwrap_or(default.seed),
+                size: size.unwrap_or(default.size),
+                output: output.clone(),
+            }
+        });
+
+        let fuzz = (command == Some(FUZZ)).then(|| {
+            let default = FuzzConfig::default();
+
+            FuzzConfig {
+                seed: seed.unwrap_or(default.seed),
+                iterations: iterations.unwrap_or(default.iterations),
+            }
+        });
+
+        Ok(Self {
+            days,
+            parts,
+            input,
+            input_dir,
+            batch,
+            format,
+            jobs,
+            serial,
+            limits,
+            verify,
+            answers,
+            explain,
+            no_cache,
+            log,
+            bench: bench.then_some(config),
+            baseline,
+            save_baseline,
+            generate,
+            fuzz,
+            clear_cache: command == Some(CLEAR_CACHE),
+            report: (command == Some(REPORT)).then_some(ReportConfig { output }),
+            help,
+        })
+    }
+
+    // Input overridden from the command line, None means input is resolved by the day itself
+    pub fn input_source(&self, day: usize) -> Option<InputSource> {
+        if let Some(input) = &self.input {
+            return Some(input.clone());
+        }
+
+        self.input_dir
+            .as_ref()
+            .map(|dir| InputSource::File(dir.join(input::file_name(day))))
+    }
+
+    fn next_value<I>(args: &mut I, option: &str) -> Result<String, Box<dyn Error>>
+    where
+        I: Iterator<Item = String>,
+    {
+        args.next()
+            .ok_or_else(|| format!("Missing value for '{option}'").into())
+    }
+
+    fn parse_day(value: &str) -> Result<usize, Box<dyn Error>> {
+        let day = value
+            .parse::<usize>()
+            .map_err(|err| format!("Failed to parse day '{value}' [{err}]"))?;
+
+        if !(1..=25).contains(&day) {
+            return Err(format!("Day shall be within 1..=25, found '{day}'").into());
+        }
+
+        Ok(day)
+    }
+
+    fn parse_days(value: &str) -> Result<Vec<usize>, Box<dyn Error>> {
+        let (start, end) = value
+            .split_once('-')
+            .ok_or_else(|| format!("Range of days shall be in format A-B, found '{value}'"))?;
+
+        let start = Self::parse_day(start)?;
+        let end = Self::parse_day(end)?;
+
+        if start > end {
+            return Err(format!("Invalid range of days '{value}'").into());
+        }
+
+        Ok((start..=end).collect())
+    }
+
+    fn parse_count(option: &str, value: &str) -> Result<usize, Box<dyn Error>> {
+        value
+            .parse::<usize>()
+            .map_err(|err| format!("Failed to parse value '{value}' of '{option}' [{err}]").into())
+    }
+
+    fn parse_part(value: &str) -> Result<usize, Box<dyn Error>> {
+        match value {
+            "1" => Ok(1),
+            "2" => Ok(2),
+            _ => Err(format!("Part shall be 1 or 2, found '{value}'").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, Box<dyn Error>> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_default() {
+        let args = parse(&[]).unwrap();
+        assert_eq!(args.days, (1..=25).collect::<Vec<_>>());
+        assert_eq!(args.parts, vec![1, 2]);
+        assert_eq!(args.format, Format::Table);
+        assert_eq!(args.jobs, None);
+        assert!(!args.serial);
+        assert_eq!(args.limits, Limits::default());
+        assert!(!args.verify);
+        assert!(!args.explain);
+        assert!(!args.no_cache);
+        assert_eq!(args.log, log::Filter::default());
+        assert!(!args.clear_cache);
+        assert_eq!(args.bench, None);
+        assert_eq!(args.generate, None);
+        assert_eq!(args.fuzz, None);
+        assert!(!args.help);
+    }
+
+    #[test]
+    fn test_parse_day_and_part() {
+        let args = parse(&["--day", "7", "--part", "2"]).unwrap();
+        assert_eq!(args.days, vec![7]);
+        assert_eq!(args.parts, vec![2]);
+    }
+
+    #[test]
+    fn test_parse_days() {
+        let args = parse(&["--days", "1-10", "--day", "5", "--day", "20"]).unwrap();
+        assert_eq!(args.days, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 20]);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let args = parse(&["--day", "3", "--input", "-"]).unwrap();
+        assert_eq!(args.input_source(3), Some(InputSource::Stdin));
+
+        let args = parse(&["--days", "1-2", "--input-dir", "/inputs"]).unwrap();
+        assert_eq!(
+            args.input_source(2),
+            Some(InputSource::File(PathBuf::from("/inputs/day_02.txt")))
+        );
+
+        let args = parse(&["--day", "3"]).unwrap();
+        assert_eq!(args.input_source(3), None);
+    }
+
+    #[test]
+    fn test_parse_batch() {
+        let args = parse(&["--day", "9", "--batch", "inputs", "--verify"]).unwrap();
+        assert_eq!(args.batch, Some(PathBuf::from("inputs")));
+        assert!(args.verify);
+
+        let args = parse(&["--day", "9"]).unwrap();
+        assert_eq!(args.batch, None);
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let args = parse(&["--format", "jsonl"]).unwrap();
+        assert_eq!(args.format, Format::JsonLines);
+
+        let args = parse(&["--format", "csv"]).unwrap();
+        assert_eq!(args.format, Format::Csv);
+    }
+
+    #[test]
+    fn test_parse_jobs() {
+        let args = parse(&["--jobs", "3"]).unwrap();
+        assert_eq!(args.jobs, Some(3));
+
+        let args = parse(&["--serial"]).unwrap();
+        assert!(args.serial);
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        let args = parse(&["--timeout", "2.5", "--max-steps", "1000"]).unwrap();
+        assert_eq!(
+            args.limits,
+            Limits {
+                timeout: Some(Duration::from_millis(2500)),
+                max_steps: Some(1000)
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let args = parse(&["--verify"]).unwrap();
+        assert!(args.verify);
+        assert_eq!(args.answers, None);
+
+        let args = parse(&["--verify", "--answers", "my.txt"]).unwrap();
+        assert_eq!(args.answers, Some(PathBuf::from("my.txt")));
+    }
+
+    #[test]
+    fn test_parse_explain() {
+        let args = parse(&["--day", "9", "--explain", "--format", "jsonl"]).unwrap();
+        assert!(args.explain);
+    }
+
+    #[test]
+    fn test_parse_cache() {
+        let args = parse(&["--no-cache"]).unwrap();
+        assert!(args.no_cache);
+
+        let args = parse(&["clear-cache"]).unwrap();
+        assert!(args.clear_cache);
+        assert_eq!(args.generate, None);
+    }
+
+    #[test]
+    fn test_parse_log() {
+        let args = parse(&["--log", "info", "--log", "18=trace", "--log", "7=DEBUG"]).unwrap();
+        assert_eq!(args.log.level(18), Level::Trace);
+        assert_eq!(args.log.level(7), Level::Debug);
+        assert_eq!(args.log.level(1), Level::Info);
+
+        let args = parse(&["report", "--log", "error"]).unwrap();
+        assert_eq!(args.log.default, Level::Error);
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let args = parse(&["--bench"]).unwrap();
+        assert_eq!(args.bench, Some(BenchConfig::default()));
+
+        let args = parse(&[
+            "--bench",
+            "--warmup",
+            "0",
+            "--samples",
+            "3",
+            "--threshold",
+            "25.5",
+            "--save-baseline",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.bench,
+            Some(BenchConfig {
+                warmup: 0,
+                samples: 3,
+                threshold: 25.5
+            })
+        );
+        assert!(args.save_baseline);
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        let args = parse(&["generate", "--day", "7"]).unwrap();
+        assert_eq!(args.days, vec![7]);
+        assert_eq!(args.generate, Some(GenerateConfig::default()));
+
+        let args = parse(&[
+            "generate", "--seed", "42", "--size", "10", "--output", "gen",
+        ])
+        .unwrap();
+        assert_eq!(args.days, (1..=25).collect::<Vec<_>>());
+        assert_eq!(
+            args.generate,
+            Some(GenerateConfig {
+                seed: 42,
+                size: 10,
+                output: Some(PathBuf::from("gen"))
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_report() {
+        let args = parse(&["report"]).unwrap();
+        assert_eq!(args.report, Some(ReportConfig::default()));
+        assert_eq!(args.days, (1..=25).collect::<Vec<_>>());
+        assert_eq!(args.parts, vec![1, 2]);
+
+        let args = parse(&["report", "--days", "1-5", "--no-cache", "--output", "a.md"]).unwrap();
+        assert_eq!(
+            args.report,
+            Some(ReportConfig {
+                output: Some(PathBuf::from("a.md"))
+            })
+        );
+        assert!(args.no_cache);
+        assert_eq!(args.generate, None);
+    }
+
+    #[test]
+    fn test_parse_fuzz() {
+        let args = parse(&["fuzz", "--days", "7-8"]).unwrap();
+        assert_eq!(args.days, vec![7, 8]);
+        assert_eq!(args.fuzz, Some(FuzzConfig::default()));
+        assert_eq!(args.generate, None);
+
+        let args = parse(&["fuzz", "--seed", "3", "--iterations", "50"]).unwrap();
+        assert_eq!(
+            args.fuzz,
+            Some(FuzzConfig {
+                seed: 3,
+                iterations: 50
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse(&["--day"]).is_err());
+        assert!(parse(&["--day", "0"]).is_err());
+        assert!(parse(&["--day", "26"]).is_err());
+        assert!(parse(&["--days", "10-1"]).is_err());
+        assert!(parse(&["--days", "10"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--unknown"]).is_err());
+        assert!(parse(&["--format"]).is_err());
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert!(parse(&["--answers"]).is_err());
+        assert!(parse(&["--jobs", "0"]).is_err());
+        assert!(parse(&["--serial", "--jobs", "2"]).is_err());
+        assert!(parse(&["--timeout", "0"]).is_err());
+        assert!(parse(&["--timeout", "x"]).is_err());
+        assert!(parse(&["--max-steps", "0"]).is_err());
+        assert!(parse(&["--bench", "--timeout", "1"]).is_err());
+        assert!(parse(&["--samples", "0"]).is_err());
+        assert!(parse(&["--warmup", "x"]).is_err());
+        assert!(parse(&["--threshold", "-1"]).is_err());
+        assert!(parse(&["--bench", "--verify"]).is_err());
+        assert!(parse(&["--bench", "--format", "csv"]).is_err());
+        assert!(parse(&["--bench", "--explain"]).is_err());
+        assert!(parse(&["--explain", "--format", "csv"]).is_err());
+        assert!(parse(&["--input", "a.txt"]).is_err());
+        assert!(parse(&["--days", "1-2", "--input", "a.txt"]).is_err());
+        assert!(parse(&["--day", "1", "--input", "a.txt", "--input-dir", "b"]).is_err());
+        assert!(parse(&["--batch", "inputs"]).is_err());
+        assert!(parse(&["--day", "1", "--batch"]).is_err());
+        assert!(parse(&["--day", "1", "--batch", "a", "--input", "b"]).is_err());
+        assert!(parse(&["--day", "1", "--batch", "a", "--format", "jsonl"]).is_err());
+        assert!(parse(&["--day", "1", "--batch", "a", "--bench"]).is_err());
+        assert!(parse(&["generate", "--day", "1", "--batch", "a"]).is_err());
+        assert!(parse(&["--seed", "1"]).is_err());
+        assert!(parse(&["--day", "1", "generate"]).is_err());
+        assert!(parse(&["generate", "--seed", "-1"]).is_err());
+        assert!(parse(&["generate", "--size", "0"]).is_err());
+        assert!(parse(&["generate", "--bench"]).is_err());
+        assert!(parse(&["generate", "--input-dir", "b"]).is_err());
+        assert!(parse(&["generate", "--iterations", "5"]).is_err());
+        assert!(parse(&["fuzz", "--size", "5"]).is_err());
+        assert!(parse(&["fuzz", "--verify"]).is_err());
+        assert!(parse(&["fuzz", "--explain"]).is_err());
+        assert!(parse(&["clear-cache", "--no-cache"]).is_err());
+        assert!(parse(&["clear-cache", "--size", "5"]).is_err());
+        assert!(parse(&["clear-cache", "--output", "a"]).is_err());
+        assert!(parse(&["--log"]).is_err());
+        assert!(parse(&["--log", "verbose"]).is_err());
+        assert!(parse(&["--log", "26=trace"]).is_err());
+        assert!(parse(&["--log", "debug", "--bench"]).is_err());
+        assert!(parse(&["fuzz", "--log", "debug"]).is_err());
+        assert!(parse(&["report", "--part", "1"]).is_err());
+        assert!(parse(&["report", "--verify"]).is_err());
+        assert!(parse(&["report", "--format", "jsonl"]).is_err());
+        assert!(parse(&["report", "--size", "5"]).is_err());
+        assert!(parse(&["--output", "a.md"]).is_err());
+        assert!(parse(&["--iterations", "5"]).is_err());
+    }
+}
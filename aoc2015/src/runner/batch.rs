@@ -0,0 +1,196 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use crate::runner::results::DayResult;
+use crate::runner::table;
+
+// Input files of the batch, i.e. all files of the directory sorted by name. Hidden files and
+// subdirectories are skipped.
+pub fn list_inputs(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|err| format!("Failed to read batch directory '{}' [{err}]", dir.display()))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("Failed to read batch directory '{}' [{err}]", dir.display()))?
+            .path();
+
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+
+        if path.is_file() && !hidden {
+            files.push(path);
+        }
+    }
+
+    if files.is_empty() {
+        return Err(format!("Batch directory '{}' has no input files", dir.display()).into());
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+// Matrix of files and parts, results are in the order of the files. Input which failed to
+// parse is reported in its rows, the full diagnostics are printed below the table.
+pub fn render(files: &[PathBuf], results: &[DayResult]) -> String {
+    let verify = results
+        .iter()
+        .flat_map(|result| &result.parts)
+        .any(|part| part.verdict.is_some());
+
+    let mut header = ["File", "Part", "Answer", "Parse", "Solve"]
+        .map(String::from)
+        .to_vec();
+    if verify {
+        header.push(String::from("Verify"));
+    }
+
+    let mut rows = vec![header];
+    let mut diagnostics = Vec::new();
+
+    for (file, result) in files.iter().zip(results) {
+        let file = file_name(file);
+
+        let parse = match &result.parse {
+            Ok(elapsed) => table::format_duration(*elapsed),
+            Err(err) => {
+                diagnostics.push((file.clone(), err.clone()));
+                String::from("-")
+            }
+        };
+
+        for part in &result.parts {
+            let answer = match (&part.answer, &result.parse) {
+                (Ok(answer), _) => answer.clone(),
+                (Err(_), Err(err)) => format!(
+                    "ERROR: Failed to parse input [{}]",
+                    err.lines().next().unwrap_or_default()
+                ),
+                (Err(err), _) => format!("ERROR: {err}"),
+            };
+
+            let mut row = vec![
+                file.clone(),
+                part.part.to_string(),
+                answer,
+                parse.clone(),
+                match part.cached {
+                    true => String::from("cached"),
+                    false => table::format_duration(part.elapsed),
+                },
+            ];
+
+            if verify {
+                row.push(
+                    part.verdict
+                        .as_ref()
+                        .map_or_else(|| String::from("-"), |verdict| verdict.to_string()),
+                );
+            }
+
+            rows.push(row);
+        }
+    }
+
+    let mut lines = table::render_rows(&rows);
+
+    for (file, err) in diagnostics {
+        lines.push(String::new());
+        lines.push(format!("{file}: Failed to parse input"));
+        lines.push(err);
+    }
+
+    lines.join("\n")
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::runner::results::PartResult;
+
+    use super::*;
+
+    fn part(part: usize, answer: Result<&str, &str>, elapsed: u64) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.map(String::from).map_err(String::from),
+            elapsed: Duration::from_micros(elapsed),
+            verdict: None,
+            witness: None,
+            cached: false,
+        }
+    }
+
+    fn result(parse: Result<u64, &str>, parts: Vec<PartResult>) -> DayResult {
+        DayResult {
+            day: 1,
+            name: String::from("Day 01: Not Quite Lisp"),
+            input_hash: None,
+            parse: parse.map(Duration::from_micros).map_err(String::from),
+            parts,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let files = [
+            PathBuf::from("inputs/alice.txt"),
+            PathBuf::from("inputs/bob.txt"),
+        ];
+        let results = [
+            result(
+                Ok(100),
+                vec![part(1, Ok("280"), 1500), part(2, Ok("1797"), 500)],
+            ),
+            result(
+                Err("Line 1 has invalid format\n 1 | (x\n   |  ^"),
+                vec![
+                    part(1, Err("Failed to parse input"), 0),
+                    part(2, Err("Failed to parse input"), 0),
+                ],
+            ),
+        ];
+
+        let expected = [
+            "File      | Part | Answer                                                   | Parse    | Solve",
+            "----------+------+----------------------------------------------------------+----------+---------",
+            "alice.txt | 1    | 280                                                      | 0.100 ms | 1.500 ms",
+            "alice.txt | 2    | 1797                                                     | 0.100 ms | 0.500 ms",
+            "bob.txt   | 1    | ERROR: Failed to parse input [Line 1 has invalid format] | -        | 0.000 ms",
+            "bob.txt   | 2    | ERROR: Failed to parse input [Line 1 has invalid format] | -        | 0.000 ms",
+            "",
+            "bob.txt: Failed to parse input",
+            "Line 1 has invalid format",
+            " 1 | (x",
+            "   |  ^",
+        ];
+
+        assert_eq!(render(&files, &results), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_list_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc2015_batch_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        for file in ["b.txt", "a.txt", ".hidden"] {
+            std::fs::write(dir.join(file), "()").unwrap();
+        }
+
+        let files = list_inputs(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(files.unwrap(), vec![dir.join("a.txt"), dir.join("b.txt")]);
+        assert!(list_inputs(&dir).is_err());
+    }
+}
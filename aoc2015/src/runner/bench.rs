@@ -0,0 +1,470 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use common::input::InputSource;
+
+use crate::runner::registry::Entry;
+use crate::runner::results;
+use crate::runner::table::{self, format_duration};
+
+// Baseline stored next to the build artifacts, timings are specific to the machine
+pub fn default_baseline_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/bench/baseline.txt")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub samples: usize,
+    // Allowed slowdown of the median against the baseline in percents
+    pub threshold: f64,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            samples: 10,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::Part1),
+            "part2" => Some(Phase::Part2),
+            _ => None,
+        }
+    }
+
+    fn from_part(part: usize) -> Self {
+        match part {
+            1 => Phase::Part1,
+            _ => Phase::Part2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[middle - 1] + sorted[middle]) / 2,
+            _ => sorted[middle],
+        };
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / sorted.len() as u32,
+        })
+    }
+}
+
+// Comparison of the median with the baseline, change is in percents
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    New,
+    Within(f64),
+    Slower(f64),
+}
+
+impl Comparison {
+    pub fn new(median: Duration, baseline: Option<Duration>, threshold: f64) -> Self {
+        let Some(baseline) = baseline else {
+            return Comparison::New;
+        };
+
+        let change = match baseline.is_zero() {
+            true => 0.0,
+            false => (median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0,
+        };
+
+        match change > threshold {
+            true => Comparison::Slower(change),
+            false => Comparison::Within(change),
+        }
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Comparison::New => write!(f, "new"),
+            Comparison::Within(change) => write!(f, "{change:+.1}%"),
+            Comparison::Slower(change) => write!(f, "{change:+.1}% SLOWER"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub day: usize,
+    pub name: String,
+    pub phase: Phase,
+    pub stats: Stats,
+    pub comparison: Comparison,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: usize,
+    pub name: String,
+    pub result: Result<Vec<(Phase, Stats)>, String>,
+}
+
+// Medians of the previous run keyed by day and phase. Every line of the file has format
+// '<day> <phase> <median in nanoseconds>'.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    entries: BTreeMap<(usize, Phase), Duration>,
+}
+
+impl Baseline {
+    // Missing file is an empty baseline, e.g. the very first run
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read baseline '{}' [{err}]", path.display()))?;
+
+        Self::parse(&content)
+            .map_err(|err| format!("Invalid baseline '{}' [{err}]", path.display()).into())
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut entries = BTreeMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let invalid = || format!("Line {} has invalid format '{line}'", index + 1);
+
+            let [day, phase, nanos] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+                return Err(invalid());
+            };
+
+            let day = day.parse::<usize>().map_err(|_| invalid())?;
+            let phase = Phase::from_name(phase).ok_or_else(invalid)?;
+            let nanos = nanos.parse::<u64>().map_err(|_| invalid())?;
+
+            entries.insert((day, phase), Duration::from_nanos(nanos));
+        }
+
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, day: usize, phase: Phase) -> Option<Duration> {
+        self.entries.get(&(day, phase)).copied()
+    }
+
+    pub fn update(&mut self, measurements: &[Measurement]) {
+        for measurement in measurements {
+            self.entries.insert(
+                (measurement.day, measurement.phase),
+                measurement.stats.median,
+            );
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("Failed to create '{}' [{err}]", dir.display()))?;
+        }
+
+        std::fs::write(path, self.to_string())
+            .map_err(|err| format!("Failed to write baseline '{}' [{err}]", path.display()).into())
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for ((day, phase), median) in &self.entries {
+            writeln!(f, "{day} {} {}", phase.name(), median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+// Time parse and selected parts of the day. Every sample solves a fresh puzzle the same way
+// as the runner does, input is read only once and is not part of the measurement.
+pub fn bench_day(
+    entry: &Entry,
+    parts: &[usize],
+    source: Option<InputSource>,
+    config: &BenchConfig,
+) -> DayBench {
+    let puzzle = (entry.create)();
+    let name = results::day_name(puzzle.as_ref());
+
+    let result = results::read_input(puzzle.as_ref(), entry.day, source)
+        .map_err(|err| err.to_string())
+        .and_then(|lines| {
+            for _ in 0..config.warmup {
+                sample(entry, parts, lines.as_ref())?;
+            }
+
+            let mut timings: BTreeMap<Phase, Vec<Duration>> = BTreeMap::new();
+            for _ in 0..config.samples {
+                for (phase, elapsed) in sample(entry, parts, lines.as_ref())? {
+                    timings.entry(phase).or_default().push(elapsed);
+                }
+            }
+
+            Ok(timings
+                .into_iter()
+                .filter_map(|(phase, samples)| Some((phase, Stats::from_samples(&samples)?)))
+                .collect())
+        });
+
+    DayBench {
+        day: entry.day,
+        name,
+        result,
+    }
+}
+
+pub fn compare(benches: &[DayBench], baseline: &Baseline, threshold: f64) -> Vec<Measurement> {
+    benches
+        .iter()
+        .filter_map(|bench| Some((bench, bench.result.as_ref().ok()?)))
+        .flat_map(|(bench, phases)| {
+            phases.iter().map(|(phase, stats)| Measurement {
+                day: bench.day,
+                name: bench.name.clone(),
+                phase: *phase,
+                stats: *stats,
+                comparison: Comparison::new(
+                    stats.median,
+                    baseline.get(bench.day, *phase),
+                    threshold,
+                ),
+            })
+        })
+        .collect()
+}
+
+pub fn render(benches: &[DayBench], measurements: &[Measurement]) -> String {
+    let header = ["Day", "Name", "Phase", "Min", "Median", "Mean", "Baseline"];
+    let mut rows = vec![header.map(String::from).to_vec()];
+
+    for measurement in measurements {
+        rows.push(vec![
+            measurement.day.to_string(),
+            measurement.name.clone(),
+            measurement.phase.name().to_string(),
+            format_duration(measurement.stats.min),
+            format_duration(measurement.stats.median),
+            format_duration(measurement.stats.mean),
+            measurement.comparison.to_string(),
+        ]);
+    }
+
+    for bench in benches {
+        if let Err(err) = &bench.result {
+            let error = format!("ERROR: {}", err.lines().next().unwrap_or_default());
+            rows.push(vec![bench.day.to_string(), bench.name.clone(), error]);
+        }
+    }
+
+    table::render_rows(&rows).join("\n")
+}
+
+// Single run of the day, returns elapsed time of every phase
+fn sample(
+    entry: &Entry,
+    parts: &[usize],
+    lines: Option<&Vec<String>>,
+) -> Result<Vec<(Phase, Duration)>, String> {
+    let mut puzzle = (entry.create)();
+    let mut timings = Vec::with_capacity(parts.len() + 1);
+
+    if let Some(lines) = lines {
+        let lines = lines.clone();
+
+        let start = Instant::now();
+        puzzle
+            .parse_content(lines)
+            .map_err(|err| format!("Failed to parse input [{err}]"))?;
+        timings.push((Phase::Parse, start.elapsed()));
+    }
+
+    for &part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            1 => puzzle.solve_part1(),
+            _ => puzzle.solve_part2(),
+        };
+        let elapsed = start.elapsed();
+
+        answer.map_err(|err| format!("Part {part} failed [{err}]"))?;
+        timings.push((Phase::from_part(part), elapsed));
+    }
+
+    Ok(timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(day: usize, phase: Phase, millis: u64) -> Measurement {
+        let duration = Duration::from_millis(millis);
+
+        Measurement {
+            day,
+            name: format!("Day {day:02}"),
+            phase,
+            stats: Stats {
+                min: duration,
+                median: duration,
+                mean: duration,
+            },
+            comparison: Comparison::New,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let millis = |values: &[u64]| {
+            values
+                .iter()
+                .map(|value| Duration::from_millis(*value))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(Stats::from_samples(&[]), None);
+        assert_eq!(
+            Stats::from_samples(&millis(&[5, 1, 3])),
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                mean: Duration::from_millis(3),
+            })
+        );
+        assert_eq!(
+            Stats::from_samples(&millis(&[4, 1, 2, 9])),
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                mean: Duration::from_millis(4),
+            })
+        );
+    }
+
+    #[test]
+    fn test_comparison() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(Comparison::new(ms(10), None, 10.0), Comparison::New);
+        assert_eq!(
+            Comparison::new(ms(11), Some(ms(10)), 10.0).to_string(),
+            "+10.0%"
+        );
+        assert_eq!(
+            Comparison::new(ms(9), Some(ms(10)), 10.0).to_string(),
+            "-10.0%"
+        );
+        assert_eq!(
+            Comparison::new(ms(15), Some(ms(10)), 10.0).to_string(),
+            "+50.0% SLOWER"
+        );
+    }
+
+    #[test]
+    fn test_baseline() {
+        let mut baseline = Baseline::parse("1 parse 1000\n1 part1 2000000\n").unwrap();
+        assert_eq!(
+            baseline.get(1, Phase::Parse),
+            Some(Duration::from_micros(1))
+        );
+        assert_eq!(baseline.get(1, Phase::Part2), None);
+
+        // Measured phases are replaced, other entries are kept
+        baseline.update(&[
+            measurement(1, Phase::Part1, 3),
+            measurement(2, Phase::Part2, 4),
+        ]);
+        assert_eq!(
+            baseline.to_string(),
+            "1 parse 1000\n1 part1 3000000\n2 part2 4000000\n"
+        );
+        assert_eq!(Baseline::parse(&baseline.to_string()), Ok(baseline));
+    }
+
+    #[test]
+    fn test_baseline_invalid() {
+        assert!(Baseline::parse("1 parse").is_err());
+        assert!(Baseline::parse("1 part3 1000").is_err());
+        assert!(Baseline::parse("x parse 1000").is_err());
+    }
+
+    #[test]
+    fn test_compare_and_render() {
+        let benches = [
+            DayBench {
+                day: 1,
+                name: String::from("Day 01"),
+                result: Ok(vec![(Phase::Part1, measurement(1, Phase::Part1, 2).stats)]),
+            },
+            DayBench {
+                day: 2,
+                name: String::from("Day 02"),
+                result: Err(String::from("Failed to parse input")),
+            },
+        ];
+        let baseline = Baseline::parse("1 part1 1000000\n").unwrap();
+
+        let measurements = compare(&benches, &baseline, 10.0);
+        assert_eq!(measurements.len(), 1);
+        assert_eq!(measurements[0].comparison, Comparison::Slower(100.0));
+
+        let expected = [
+            "Day | Name   | Phase                        | Min      | Median   | Mean     | Baseline",
+            "----+--------+------------------------------+----------+----------+----------+---------------",
+            "1   | Day 01 | part1                        | 2.000 ms | 2.000 ms | 2.000 ms | +100.0% SLOWER",
+            "2   | Day 02 | ERROR: Failed to parse input",
+        ];
+        assert_eq!(render(&benches, &measurements), expected.join("\n"));
+    }
+}
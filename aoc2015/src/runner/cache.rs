@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use crate::runner::results::DayResult;
+
+// Hashes of sources of the day crates, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/source_hashes.rs"));
+
+// Cache stored in the build directory, i.e. <workspace>/target/cache/answers.txt
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/cache/answers.txt")
+}
+
+// Hash of sources the day was built from, any change of the day or common crate changes it
+pub fn source_hash(day: usize) -> &'static str {
+    SOURCE_HASHES[day - 1]
+}
+
+// Answers solved by previous runs keyed by day, part, hash of the input and hash of the
+// sources. Every line of the file has format '<day> <part> <input> <source> <answer>'.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Cache {
+    entries: BTreeMap<(usize, usize, String, String), String>,
+}
+
+impl Cache {
+    // Missing file is an empty cache
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read cache '{}' [{err}]", path.display()))?;
+
+        Self::parse(&content).map_err(|err| {
+            format!(
+                "Invalid cache '{}' [{err}], remove it by 'aoc2015 clear-cache'",
+                path.display()
+            )
+            .into()
+        })
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut entries = BTreeMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let invalid = || format!("Line {} has invalid format '{line}'", index + 1);
+
+            let mut fields = line.splitn(5, ' ');
+            let (Some(day), Some(part), Some(input), Some(source), Some(answer)) = (
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) else {
+                return Err(invalid());
+            };
+
+            let day = day
+                .parse::<usize>()
+                .ok()
+                .filter(|day| (1..=SOURCE_HASHES.len()).contains(day))
+                .ok_or_else(invalid)?;
+            let part = part.parse::<usize>().map_err(|_| invalid())?;
+
+            entries.insert(
+                (day, part, input.to_string(), source.to_string()),
+                answer.to_string(),
+            );
+        }
+
+        Ok(Self { entries })
+    }
+
+    // Answer of the part solved by the current sources of the day
+    pub fn get(&self, day: usize, part: usize, input: &str) -> Option<&str> {
+        self.entries
+            .get(&(day, part, input.to_string(), source_hash(day).to_string()))
+            .map(String::as_str)
+    }
+
+    // Store solved answers and drop answers of outdated sources, failed parts are not cached
+    // since they might be cut short by the budget. Returns number of newly cached answers,
+    // including stale answers replaced by answers solved under '--verify'.
+    pub fn update(&mut self, results: &[DayResult]) -> usize {
+        self.entries
+            .retain(|(day, _, _, source), _| source == source_hash(*day));
+
+        let mut changed = 0;
+
+        for result in results {
+            let Some(input) = &result.input_hash else {
+                continue;
+            };
+
+            for part in &result.parts {
+                // Answer has to survive the round trip through the file
+                let Ok(answer) = &part.answer else {
+                    continue;
+                };
+                if part.cached || answer.is_empty() || answer.contains('\n') {
+                    continue;
+                }
+
+                let key = (
+                    result.day,
+                    part.part,
+                    input.clone(),
+                    source_hash(result.day).to_string(),
+                );
+
+                if self.entries.insert(key, answer.clone()).as_ref() != Some(answer) {
+                    changed += 1;
+                }
+            }
+        }
+
+        changed
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("Failed to create '{}' [{err}]", dir.display()))?;
+        }
+
+        std::fs::write(path, self.to_string())
+            .map_err(|err| format!("Failed to write cache '{}' [{err}]", path.display()).into())
+    }
+
+    // Remove the cache file, returns false if there was nothing to remove
+    pub fn clear(path: &Path) -> Result<bool, Box<dyn Error>> {
+        if !path.exists() {
+            return Ok(false);
+        }
+
+        std::fs::remove_file(path)
+            .map_err(|err| format!("Failed to remove cache '{}' [{err}]", path.display()))?;
+        Ok(true)
+    }
+}
+
+impl Display for Cache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for ((day, part, input, source), answer) in &self.entries {
+            writeln!(f, "{day} {part} {input} {source} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::runner::results::PartResult;
+
+    use super::*;
+
+    fn part(part: usize, answer: Result<&str, &str>, cached: bool) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.map(String::from).map_err(String::from),
+            elapsed: Duration::ZERO,
+            verdict: None,
+            witness: None,
+            cached,
+        }
+    }
+
+    #[test]
+    fn test_update() {
+        let results = [DayResult {
+            day: 11,
+            name: String::from("Day 11: Corporate Policy"),
+            input_hash: Some(String::from("abc")),
+            parse: Ok(Duration::ZERO),
+            parts: vec![
+                part(1, Ok("hxbxxyzz"), false),
+                part(2, Err("Timeout"), false),
+            ],
+        }];
+
+        let mut cache = Cache::parse("11 2 abc outdated hxcaabcc\n").unwrap();
+        assert_eq!(cache.update(&results), 1);
+        assert_eq!(cache.get(11, 1, "abc"), Some("hxbxxyzz"));
+        assert_eq!(cache.get(11, 2, "abc"), None);
+        assert_eq!(cache.get(11, 1, "def"), None);
+
+        // Answers of outdated sources are dropped
+        assert_eq!(
+            cache.to_string(),
+            format!("11 1 abc {} hxbxxyzz\n", source_hash(11))
+        );
+        assert_eq!(Cache::parse(&cache.to_string()), Ok(cache));
+    }
+
+    #[test]
+    fn test_update_stale() {
+        let result = |answer| DayResult {
+            day: 11,
+            name: String::from("Day 11: Corporate Policy"),
+            input_hash: Some(String::from("abc")),
+            parse: Ok(Duration::ZERO),
+            parts: vec![part(1, Ok(answer), false)],
+        };
+
+        let content = format!("11 1 abc {} stale\n", source_hash(11));
+        let mut cache = Cache::parse(&content).unwrap();
+
+        // Stale answer is replaced, the same answer is not counted again
+        assert_eq!(cache.update(&[result("hxbxwxba")]), 1);
+        assert_eq!(cache.update(&[result("hxbxwxba")]), 0);
+        assert_eq!(cache.get(11, 1, "abc"), Some("hxbxwxba"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let cache = Cache::parse("25 2 abc def Not solved\n\n").unwrap();
+        assert_eq!(cache.to_string(), "25 2 abc def Not solved\n");
+
+        assert!(Cache::parse("1 1 abc def").is_err());
+        assert!(Cache::parse("x 1 abc def 280").is_err());
+        assert!(Cache::parse("26 1 abc def 280").is_err());
+    }
+
+    #[test]
+    fn test_save_and_clear() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc2015_cache_{}", std::process::id()))
+            .join("answers.txt");
+
+        let cache = Cache::parse(&format!("1 1 abc {} 280\n", source_hash(1))).unwrap();
+        cache.save(&path).unwrap();
+        assert_eq!(Cache::load(&path).unwrap(), cache);
+
+        assert!(Cache::clear(&path).unwrap());
+        assert!(!Cache::clear(&path).unwrap());
+        assert_eq!(Cache::load(&path).unwrap(), Cache::default());
+
+        std::fs::remove_dir(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_source_hash() {
+        let hashes = (1..=25).map(source_hash).collect::<Vec<_>>();
+        assert!(hashes.iter().all(|hash| hash.len() == 32));
+        assert_ne!(hashes[0], hashes[1]);
+    }
+}
@@ -0,0 +1,290 @@
+use std::time::Duration;
+
+use common::explain::{Value, Witness};
+use serde_json::json;
+
+use crate::runner::answers::Verdict;
+use crate::runner::results::{DayResult, PartResult};
+
+const CSV_HEADER: [&str; 11] = [
+    "day", "part", "name", "status", "answer", "error", "parse_ms", "solve_ms", "verify",
+    "expected", "cached",
+];
+
+// Format of the results printed by the runner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    JsonLines,
+    Csv,
+}
+
+impl Format {
+    pub fn from_arg(value: &str) -> Option<Self> {
+        match value {
+            "table" => Some(Self::Table),
+            "jsonl" => Some(Self::JsonLines),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+// Single record per day and part, i.e. one line of JSON Lines or CSV
+struct Record<'a> {
+    day: usize,
+    part: usize,
+    name: &'a str,
+    answer: Option<&'a str>,
+    error: Option<&'a str>,
+    parse: Option<Duration>,
+    solve: Duration,
+    verdict: Option<&'a Verdict>,
+    witness: Option<&'a Result<Witness, String>>,
+    cached: bool,
+}
+
+impl<'a> Record<'a> {
+    fn new(result: &'a DayResult, part: &'a PartResult) -> Self {
+        Self {
+            day: result.day,
+            part: part.part,
+            name: &result.name,
+            answer: part.answer.as_ref().ok().map(String::as_str),
+            error: part.answer.as_ref().err().map(String::as_str),
+            parse: result.parse.as_ref().ok().copied(),
+            solve: part.elapsed,
+            verdict: part.verdict.as_ref(),
+            witness: part.witness.as_ref(),
+            cached: part.cached,
+        }
+    }
+
+    fn status(&self) -> &'static str {
+        match self.error {
+            Some(_) => "error",
+            None => "ok",
+        }
+    }
+}
+
+pub fn render_json_lines(results: &[DayResult]) -> String {
+    records(results)
+        .map(|record| {
+            let mut line = json!({
+                "day": record.day,
+                "part": record.part,
+                "name": record.name,
+                "status": record.status(),
+                "answer": record.answer,
+                "error": record.error,
+                "parse_ms": record.parse.map(as_millis),
+                "solve_ms": as_millis(record.solve),
+                "verify": record.verdict.map(Verdict::name),
+                "expected": record.verdict.and_then(Verdict::expected),
+                "cached": record.cached,
+            });
+
+            // Witness is present only when the answer was explained
+            match record.witness {
+                Some(Ok(witness)) => line["witness"] = witness_json(witness),
+                Some(Err(err)) => {
+                    line["witness"] = serde_json::Value::Null;
+                    line["witness_error"] = json!(err);
+                }
+                None => {}
+            }
+
+            line.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn render_csv(results: &[DayResult]) -> String {
+    let header = CSV_HEADER.join(",");
+
+    let rows = records(results).map(|record| {
+        [
+            record.day.to_string(),
+            record.part.to_string(),
+            csv_field(record.name),
+            record.status().to_string(),
+            csv_field(record.answer.unwrap_or_default()),
+            csv_field(record.error.unwrap_or_default()),
+            record
+                .parse
+                .map(|parse| as_millis(parse).to_string())
+                .unwrap_or_default(),
+            as_millis(record.solve).to_string(),
+            record
+                .verdict
+                .map(Verdict::name)
+                .unwrap_or_default()
+                .to_string(),
+            csv_field(
+                record
+                    .verdict
+                    .and_then(Verdict::expected)
+                    .unwrap_or_default(),
+            ),
+            record.cached.to_string(),
+        ]
+        .join(",")
+    });
+
+    std::iter::once(header)
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Fields of the witness as an object, numbers out of range of JSON numbers become strings
+fn witness_json(witness: &Witness) -> serde_json::Value {
+    fn value_json(value: &Value) -> serde_json::Value {
+        match value {
+            Value::Number(number) => i64::try_from(*number)
+                .map(serde_json::Value::from)
+                .or_else(|_| u64::try_from(*number).map(serde_json::Value::from))
+                .unwrap_or_else(|_| json!(number.to_string())),
+            Value::Text(text) => json!(text),
+            Value::List(values) => values.iter().map(value_json).collect(),
+        }
+    }
+
+    witness
+        .fields
+        .iter()
+        .map(|(name, value)| (name.clone(), value_json(value)))
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+fn records(results: &[DayResult]) -> impl Iterator<Item = Record<'_>> {
+    results
+        .iter()
+        .flat_map(|result| result.parts.iter().map(|part| Record::new(result, part)))
+}
+
+// Milliseconds with microsecond precision, finer digits are only noise
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+// Quote the field only when needed, i.e. it contains separator, quotes or new lines
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<DayResult> {
+        vec![
+            DayResult {
+                day: 1,
+                name: String::from("Day 01: Not Quite Lisp"),
+                input_hash: None,
+                parse: Ok(Duration::from_micros(250)),
+                parts: vec![
+                    PartResult {
+                        part: 1,
+                        answer: Ok(String::from("280")),
+                        elapsed: Duration::from_nanos(1_500_400),
+                        verdict: Some(Verdict::Match),
+                        witness: None,
+                        cached: false,
+                    },
+                    PartResult {
+                        part: 2,
+                        answer: Err(String::from("Floor \"-1\" not found, sorry")),
+                        elapsed: Duration::ZERO,
+                        verdict: Some(Verdict::Mismatch {
+                            expected: String::from("1797"),
+                        }),
+                        witness: None,
+                        cached: false,
+                    },
+                ],
+            },
+            DayResult {
+                day: 2,
+                name: String::from("Day 02: No Math"),
+                input_hash: None,
+                parse: Err(String::from("Invalid input")),
+                parts: vec![PartResult {
+                    part: 1,
+                    answer: Err(String::from("Failed to parse input")),
+                    elapsed: Duration::ZERO,
+                    verdict: None,
+                    witness: None,
+                    cached: false,
+                }],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format_from_arg() {
+        assert_eq!(Format::from_arg("table"), Some(Format::Table));
+        assert_eq!(Format::from_arg("jsonl"), Some(Format::JsonLines));
+        assert_eq!(Format::from_arg("csv"), Some(Format::Csv));
+        assert_eq!(Format::from_arg("xml"), None);
+    }
+
+    #[test]
+    fn test_render_json_lines() {
+        let expected = [
+            r#"{"answer":"280","cached":false,"day":1,"error":null,"expected":null,"name":"Day 01: Not Quite Lisp","parse_ms":0.25,"part":1,"solve_ms":1.5,"status":"ok","verify":"match"}"#,
+            r#"{"answer":null,"cached":false,"day":1,"error":"Floor \"-1\" not found, sorry","expected":"1797","name":"Day 01: Not Quite Lisp","parse_ms":0.25,"part":2,"solve_ms":0.0,"status":"error","verify":"mismatch"}"#,
+            r#"{"answer":null,"cached":false,"day":2,"error":"Failed to parse input","expected":null,"name":"Day 02: No Math","parse_ms":null,"part":1,"solve_ms":0.0,"status":"error","verify":null}"#,
+        ];
+
+        assert_eq!(render_json_lines(&results()), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_render_json_lines_witness() {
+        let mut results = results();
+        results.truncate(1);
+        results[0].parts[0].witness = Some(Ok(Witness::new()
+            .with("route", vec!["Belfast", "Dublin"])
+            .with("distance", 141_usize)
+            .with("huge", Value::Number(i128::MAX))));
+        results[0].parts[1].witness = Some(Err(String::from("No routes")));
+
+        let lines = render_json_lines(&results);
+        let lines = lines
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines[0]["witness"],
+            json!({
+                "route": ["Belfast", "Dublin"],
+                "distance": 141,
+                "huge": i128::MAX.to_string()
+            })
+        );
+        assert_eq!(lines[1]["witness"], serde_json::Value::Null);
+        assert_eq!(lines[1]["witness_error"], json!("No routes"));
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let expected = [
+            "day,part,name,status,answer,error,parse_ms,solve_ms,verify,expected,cached",
+            "1,1,Day 01: Not Quite Lisp,ok,280,,0.25,1.5,match,,false",
+            r#"1,2,Day 01: Not Quite Lisp,error,,"Floor ""-1"" not found, sorry",0.25,0,mismatch,1797,false"#,
+            "2,1,Day 02: No Math,error,,Failed to parse input,,0,,,false",
+        ];
+
+        assert_eq!(render_csv(&results()), expected.join("\n"));
+    }
+}
@@ -0,0 +1,62 @@
+use common::fuzz::{self, Failure};
+use common::input::{self, InputSource};
+
+use crate::runner::registry::Entry;
+
+// Size of the generated input mutated along with the real one, small inputs shrink faster
+const CORPUS_SIZE: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzConfig {
+    pub seed: u64,
+    pub iterations: usize,
+}
+
+impl Default for FuzzConfig {
+    fn default() -> Self {
+        Self {
+            seed: 2015,
+            iterations: 1_000,
+        }
+    }
+}
+
+// Feed mutated generated and real inputs (if available) into parse_content() of the day
+pub fn fuzz_day(entry: &Entry, config: &FuzzConfig) -> Result<(), Failure> {
+    let mut corpus = vec![(entry.generate)(config.seed, CORPUS_SIZE)];
+
+    // Real input is read from the file only, standard input is not an option here
+    if let source @ InputSource::File(_) = input::resolve(entry.day)
+        && let Ok(lines) = source.read_lines()
+    {
+        corpus.push(lines);
+    }
+
+    fuzz::fuzz(
+        |lines| (entry.create)().parse_content(lines),
+        &corpus,
+        config.seed,
+        config.iterations,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::registry::ENTRIES;
+
+    use super::*;
+
+    #[test]
+    fn test_fuzz_all_days() {
+        let config = FuzzConfig {
+            seed: 1,
+            iterations: 200,
+        };
+
+        for entry in &ENTRIES {
+            if let Err(failure) = fuzz_day(entry, &config) {
+                panic!("Day {:02}: {failure}", entry.day);
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use common::input;
+
+use crate::runner::registry::Entry;
+
+// Directory of generated inputs, i.e. <workspace>/target/generated
+pub fn default_output_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/generated")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerateConfig {
+    pub seed: u64,
+    // Meaning depends on the day, e.g. number of lines, cities or instructions
+    pub size: usize,
+    pub output: Option<PathBuf>,
+}
+
+impl Default for GenerateConfig {
+    fn default() -> Self {
+        Self {
+            seed: 2015,
+            size: 100,
+            output: None,
+        }
+    }
+}
+
+impl GenerateConfig {
+    pub fn output_dir(&self) -> PathBuf {
+        self.output.clone().unwrap_or_else(default_output_dir)
+    }
+}
+
+// Write generated input of the day to <dir>/day_XX.txt, same seed gives the same file. Returns
+// path of the file and number of written lines.
+pub fn write_day(
+    entry: &Entry,
+    config: &GenerateConfig,
+    dir: &Path,
+) -> Result<(PathBuf, usize), Box<dyn Error>> {
+    std::fs::create_dir_all(dir)
+        .map_err(|err| format!("Failed to create '{}' [{err}]", dir.display()))?;
+
+    let lines = (entry.generate)(config.seed, config.size);
+    let path = dir.join(input::file_name(entry.day));
+
+    let content = lines
+        .iter()
+        .map(|line| format!("{line}\n"))
+        .collect::<String>();
+
+    std::fs::write(&path, content)
+        .map_err(|err| format!("Failed to write input '{}' [{err}]", path.display()))?;
+
+    Ok((path, lines.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::input::InputSource;
+
+    use crate::runner::registry;
+
+    use super::*;
+
+    #[test]
+    fn test_write_day() {
+        let dir = std::env::temp_dir().join(format!("aoc2015_generate_{}", std::process::id()));
+        let entry = registry::find(9).unwrap();
+        let config = GenerateConfig {
+            seed: 1,
+            size: 4,
+            output: Some(dir.clone()),
+        };
+
+        let (path, count) = write_day(entry, &config, &config.output_dir()).unwrap();
+        assert_eq!(path, dir.join("day_09.txt"));
+        assert_eq!(count, 6);
+
+        // Written file can be read back as an input of the day
+        let lines = InputSource::File(path.clone()).read_lines().unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(lines, (entry.generate)(1, 4));
+    }
+}
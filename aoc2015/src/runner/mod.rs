@@ -0,0 +1,13 @@
+pub mod answers;
+pub mod args;
+pub mod batch;
+pub mod bench;
+pub mod cache;
+pub mod export;
+pub mod fuzz;
+pub mod generate;
+pub mod parallel;
+pub mod registry;
+pub mod report;
+pub mod results;
+pub mod table;
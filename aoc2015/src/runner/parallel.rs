@@ -0,0 +1,256 @@
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::input::InputSource;
+
+use crate::runner::registry::Entry;
+use crate::runner::results::{self, DayResult, SolveOptions};
+
+// Number of worker threads used when not set on the command line
+pub fn default_jobs() -> usize {
+    std::thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+// Solve days on a pool of worker threads. Every part is a separate task solving its own
+// instance of the puzzle, so parts of the same day run concurrently too. Input of every day
+// is read once up front, e.g. standard input can not be read by several tasks. Every task
+// gets its own budget.
+pub fn run_days(
+    days: &[(&Entry, Option<InputSource>)],
+    parts: &[usize],
+    jobs: usize,
+    options: SolveOptions,
+) -> Vec<DayResult> {
+    let inputs = days
+        .iter()
+        .map(|(entry, source)| results::load_input(entry, source.clone()))
+        .collect::<Vec<_>>();
+
+    let tasks = (0..days.len())
+        .flat_map(|day| parts.iter().map(move |part| (day, *part)))
+        .collect::<Vec<_>>();
+
+    let next = AtomicUsize::new(0);
+    let finished = Mutex::new(Vec::with_capacity(tasks.len()));
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&(day, part)) = tasks.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = results::solve_day(days[day].0, &[part], &inputs[day], options);
+                    finished.lock().unwrap().push((day, part, result));
+                }
+            });
+        }
+    });
+
+    // Tasks finish in any order, results are printed in order of days and parts. The same day
+    // can be given several times with different inputs, therefore parts are merged by position.
+    let mut finished = finished.into_inner().unwrap();
+    finished.sort_by_key(|(day, part, _)| (*day, *part));
+
+    let mut results: Vec<Option<DayResult>> = (0..days.len()).map(|_| None).collect();
+    for (day, _, result) in finished {
+        match &mut results[day] {
+            Some(merged) => merged.merge(result),
+            slot => *slot = Some(result),
+        }
+    }
+
+    results.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use common::budget::Limits;
+
+    use crate::runner::cache::{self, Cache};
+    use crate::runner::registry;
+
+    use super::*;
+
+    fn example(day: &str, file: &str) -> Option<InputSource> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(day)
+            .join("examples")
+            .join(file);
+
+        Some(InputSource::File(path))
+    }
+
+    fn answers(results: &[DayResult]) -> Vec<(usize, usize, Result<String, String>)> {
+        results
+            .iter()
+            .flat_map(|result| {
+                result
+                    .parts
+                    .iter()
+                    .map(|part| (result.day, part.part, part.answer.clone()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_run_days() {
+        let days = [
+            (
+                registry::find(2).unwrap(),
+                example("day_02_i_was_told_there_would_be_no_math", "example_01.txt"),
+            ),
+            (
+                registry::find(1).unwrap(),
+                example("day_01_not_quite_lisp", "example_07.txt"),
+            ),
+            (
+                registry::find(3).unwrap(),
+                example("missing", "missing.txt"),
+            ),
+        ];
+
+        let results = run_days(&days, &[1, 2], 4, SolveOptions::default());
+
+        // Order of the days is kept and parts of every day are merged
+        assert_eq!(
+            answers(&results)
+                .into_iter()
+                .map(|(day, part, answer)| (day, part, answer.ok()))
+                .collect::<Vec<_>>(),
+            vec![
+                (2, 1, Some(String::from("58"))),
+                (2, 2, Some(String::from("34"))),
+                (1, 1, Some(String::from("-1"))),
+                (1, 2, Some(String::from("5"))),
+                (3, 1, None),
+                (3, 2, None),
+            ]
+        );
+        assert!(results[0].parse.is_ok());
+        assert!(results[2].parse.is_err());
+
+        // Same answers as when solved serially
+        let serial = days
+            .iter()
+            .map(|(entry, source)| {
+                results::run_day(entry, &[1, 2], source.clone(), SolveOptions::default())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(answers(&results), answers(&serial));
+    }
+
+    #[test]
+    fn test_run_days_same_day() {
+        let entry = registry::find(1).unwrap();
+        let days = [
+            (entry, example("day_01_not_quite_lisp", "example_07.txt")),
+            (entry, example("day_01_not_quite_lisp", "example_02.txt")),
+        ];
+
+        let results = run_days(&days, &[1, 2], 3, SolveOptions::default());
+
+        // Inputs of the same day are not merged together
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            answers(&results)
+                .into_iter()
+                .map(|(day, part, _)| (day, part))
+                .collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (1, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_run_days_cache() {
+        let days = [(
+            registry::find(1).unwrap(),
+            example("day_01_not_quite_lisp", "example_07.txt"),
+        )];
+
+        // Cached answer of part 2 is returned as is, part 1 is solved
+        let input = results::load_input(days[0].0, days[0].1.clone());
+        let content = format!("1 2 {} {} 42\n", input.hash.unwrap(), cache::source_hash(1));
+        let cache = Cache::parse(&content).unwrap();
+
+        let options = SolveOptions {
+            cache: Some(&cache),
+            ..SolveOptions::default()
+        };
+        let results = run_days(&days, &[1, 2], 2, options);
+
+        assert_eq!(
+            answers(&results),
+            vec![
+                (1, 1, Ok(String::from("-1"))),
+                (1, 2, Ok(String::from("42")))
+            ]
+        );
+        assert!(!results[0].parts[0].cached);
+        assert!(results[0].parts[1].cached);
+    }
+
+    #[test]
+    fn test_run_days_cache_verify() {
+        let days = [(
+            registry::find(1).unwrap(),
+            example("day_01_not_quite_lisp", "example_07.txt"),
+        )];
+
+        // Stale answer of part 2 is not trusted when answers are verified
+        let input = results::load_input(days[0].0, days[0].1.clone());
+        let content = format!("1 2 {} {} 42\n", input.hash.unwrap(), cache::source_hash(1));
+        let cache = Cache::parse(&content).unwrap();
+
+        let options = SolveOptions {
+            cache: Some(&cache),
+            verify: true,
+            ..SolveOptions::default()
+        };
+        let results = run_days(&days, &[1, 2], 2, options);
+
+        assert_eq!(
+            answers(&results),
+            vec![
+                (1, 1, Ok(String::from("-1"))),
+                (1, 2, Ok(String::from("5")))
+            ]
+        );
+        assert!(results[0].parts.iter().all(|part| !part.cached));
+    }
+
+    #[test]
+    fn test_run_days_limits() {
+        // Program incrementing register b forever
+        let path = std::env::temp_dir().join(format!("aoc2015_loop_{}.txt", std::process::id()));
+        std::fs::write(&path, "inc b\njmp -1\n").unwrap();
+
+        let days = [(
+            registry::find(23).unwrap(),
+            Some(InputSource::File(path.clone())),
+        )];
+        let limits = Limits {
+            timeout: None,
+            max_steps: Some(100),
+        };
+
+        let options = SolveOptions {
+            limits,
+            ..SolveOptions::default()
+        };
+
+        let results = run_days(&days, &[1, 2], 2, options);
+        std::fs::remove_file(&path).unwrap();
+
+        for (_, _, answer) in answers(&results) {
+            assert_eq!(
+                answer,
+                Err(String::from(
+                    "Budget exceeded, limit of 100 step(s) reached after 100 step(s), \
+                     output register holds '50'"
+                ))
+            );
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use common::explain::Explain;
+
+// Registry entry binding day number with the constructor of its solution and the generator
+// of its inputs
+pub struct Entry {
+    pub day: usize,
+    pub create: fn() -> Box<dyn Explain>,
+    pub generate: fn(u64, usize) -> Vec<String>,
+}
+
+pub const ENTRIES: [Entry; 25] = [
+    Entry {
+        day: 1,
+        create: create::<day_01_not_quite_lisp::Solution>,
+        generate: day_01_not_quite_lisp::generate,
+    },
+    Entry {
+        day: 2,
+        create: create::<day_02_i_was_told_there_would_be_no_math::Solution>,
+        generate: day_02_i_was_told_there_would_be_no_math::generate,
+    },
+    Entry {
+        day: 3,
+        create: create::<day_03_perfectly_spherical_houses_in_a_vacuum::Solution>,
+        generate: day_03_perfectly_spherical_houses_in_a_vacuum::generate,
+    },
+    Entry {
+        day: 4,
+        create: create::<day_04_the_ideal_stocking_stuffer::Solution>,
+        generate: day_04_the_ideal_stocking_stuffer::generate,
+    },
+    Entry {
+        day: 5,
+        create: create::<day_05_doesnt_he_have_intern_elves_for_this::Solution>,
+        generate: day_05_doesnt_he_have_intern_elves_for_this::generate,
+    },
+    Entry {
+        day: 6,
+        create: create::<day_06_probably_a_fire_hazard::Solution>,
+        generate: day_06_probably_a_fire_hazard::generate,
+    },
+    Entry {
+        day: 7,
+        create: create::<day_07_some_assembly_required::Solution>,
+        generate: day_07_some_assembly_required::generate,
+    },
+    Entry {
+        day: 8,
+        create: create::<day_08_matchsticks::Solution>,
+        generate: day_08_matchsticks::generate,
+    },
+    Entry {
+        day: 9,
+        create: create::<day_09_all_in_a_single_night::Solution>,
+        generate: day_09_all_in_a_single_night::generate,
+    },
+    Entry {
+        day: 10,
+        create: create::<day_10_elves_look_elves_say::Solution>,
+        generate: day_10_elves_look_elves_say::generate,
+    },
+    Entry {
+        day: 11,
+        create: create::<day_11_corporate_policy::Solution>,
+        generate: day_11_corporate_policy::generate,
+    },
+    Entry {
+        day: 12,
+        create: create::<day_12_js_abacus_framework_io::Solution>,
+        generate: day_12_js_abacus_framework_io::generate,
+    },
+    Entry {
+        day: 13,
+        create: create::<day_13_knights_of_the_dinner_table::Solution>,
+        generate: day_13_knights_of_the_dinner_table::generate,
+    },
+    Entry {
+        day: 14,
+        create: create::<day_14_reindeer_olympics::Solution>,
+        generate: day_14_reindeer_olympics::generate,
+    },
+    Entry {
+        day: 15,
+        create: create::<day_15_science_for_hungry_people::Solution>,
+        generate: day_15_science_for_hungry_people::generate,
+    },
+    Entry {
+        day: 16,
+        create: create::<day_16_aunt_sue::Solution>,
+        generate: day_16_aunt_sue::generate,
+    },
+    Entry {
+        day: 17,
+        create: create::<day_17_no_such_thing_as_too_much::Solution>,
+        generate: day_17_no_such_thing_as_too_much::generate,
+    },
+    Entry {
+        day: 18,
+        create: create::<day_18_like_a_gif_for_your_yard::Solution>,
+        generate: day_18_like_a_gif_for_your_yard::generate,
+    },
+    Entry {
+        day: 19,
+        create: create::<day_19_medicine_for_rudolph::Solution>,
+        generate: day_19_medicine_for_rudolph::generate,
+    },
+    Entry {
+        day: 20,
+        create: create::<day_20_infinite_elves_and_infinite_houses::Solution>,
+        generate: day_20_infinite_elves_and_infinite_houses::generate,
+    },
+    Entry {
+        day: 21,
+        create: create::<day_21_rpg_simulator_20XX::Solution>,
+        generate: day_21_rpg_simulator_20XX::generate,
+    },
+    Entry {
+        day: 22,
+        create: create::<day_22_wizard_simulator_20XX::Solution>,
+        generate: day_22_wizard_simulator_20XX::generate,
+    },
+    Entry {
+        day: 23,
+        create: create::<day_23_opening_the_turing_lock::Solution>,
+        generate: day_23_opening_the_turing_lock::generate,
+    },
+    Entry {
+        day: 24,
+        create: create::<day_24_it_hangs_in_the_balance::Solution>,
+        generate: day_24_it_hangs_in_the_balance::generate,
+    },
+    Entry {
+        day: 25,
+        create: create::<day_25_let_it_snow::Solution>,
+        generate: day_25_let_it_snow::generate,
+    },
+];
+
+fn create<T: Explain + Default + 'static>() -> Box<dyn Explain> {
+    Box::new(T::default())
+}
+
+pub fn find(day: usize) -> Option<&'static Entry> {
+    ENTRIES.iter().find(|entry| entry.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries() {
+        for (index, entry) in ENTRIES.iter().enumerate() {
+            assert_eq!(entry.day, index + 1);
+
+            let puzzle = (entry.create)();
+            assert!(puzzle.name().contains(&format!("Day {:02}:", entry.day)));
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        for entry in &ENTRIES {
+            let mut puzzle = (entry.create)();
+            let lines = (entry.generate)(1, 5);
+
+            assert!(puzzle.parse_content(lines).is_ok(), "Day {}", entry.day);
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(7).map(|entry| entry.day), Some(7));
+        assert!(find(0).is_none());
+        assert!(find(26).is_none());
+    }
+}
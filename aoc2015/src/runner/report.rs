@@ -0,0 +1,241 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use common::answer;
+
+use crate::runner::results::{DayResult, PartResult};
+use crate::runner::table;
+
+// Report stored in the build directory, i.e. <workspace>/target/report/summary.md
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/report/summary.md")
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReportConfig {
+    pub output: Option<PathBuf>,
+}
+
+impl ReportConfig {
+    pub fn output_path(&self) -> PathBuf {
+        self.output.clone().unwrap_or_else(default_path)
+    }
+}
+
+// Part earns a star only with a real answer, i.e. not an error nor the placeholder
+pub fn is_star(part: &PartResult) -> bool {
+    part.answer
+        .as_ref()
+        .is_ok_and(|answer| answer::is_solved(answer))
+}
+
+pub fn star_count(results: &[DayResult]) -> usize {
+    results
+        .iter()
+        .flat_map(|result| &result.parts)
+        .filter(|part| is_star(part))
+        .count()
+}
+
+// Markdown summary of the year, i.e. table of days with answers, timings and stars followed by
+// the list of unsolved parts
+pub fn render(results: &[DayResult]) -> String {
+    let total = results
+        .iter()
+        .map(|result| result.parts.len())
+        .sum::<usize>();
+    let elapsed = results
+        .iter()
+        .flat_map(|result| result.parse.iter().copied().chain(solve_times(result)))
+        .sum::<Duration>();
+
+    let mut lines = vec![
+        String::from("# Advent of Code 2015"),
+        String::new(),
+        format!("Stars: {} / {total}", star_count(results)),
+        String::new(),
+        format!("Total time: {}", table::format_duration(elapsed)),
+        String::new(),
+        String::from("| Day | Title | Part 1 | Part 2 | Parse | Solve | Stars |"),
+        String::from("| ---: | --- | --- | --- | ---: | ---: | --- |"),
+    ];
+
+    for result in results {
+        // Title without the day, e.g. 'Day 01: Not Quite Lisp' -> 'Not Quite Lisp'
+        let title = result
+            .name
+            .split_once(": ")
+            .map_or(result.name.as_str(), |(_, title)| title);
+
+        let answer = |part| {
+            result
+                .parts
+                .iter()
+                .find(|result| result.part == part)
+                .map_or_else(|| String::from("-"), format_answer)
+        };
+
+        let parse = match &result.parse {
+            Ok(elapsed) => table::format_duration(*elapsed),
+            Err(_) => String::from("-"),
+        };
+
+        let stars = result.parts.iter().filter(|part| is_star(part)).count();
+
+        lines.push(format!(
+            "| {} | {} | {} | {} | {parse} | {} | {} |",
+            result.day,
+            escape(title),
+            answer(1),
+            answer(2),
+            table::format_duration(solve_times(result).sum()),
+            "*".repeat(stars)
+        ));
+    }
+
+    let unsolved = results
+        .iter()
+        .flat_map(|result| result.parts.iter().map(move |part| (result, part)))
+        .filter(|(_, part)| !is_star(part))
+        .map(|(result, part)| {
+            let reason = match &part.answer {
+                Ok(answer) => answer.clone(),
+                Err(err) => err.lines().next().unwrap_or_default().to_string(),
+            };
+
+            format!(
+                "- Day {} part {}: {}",
+                result.day,
+                part.part,
+                escape(&reason)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    lines.push(String::new());
+    lines.push(String::from("## Unsolved"));
+    lines.push(String::new());
+
+    match unsolved.is_empty() {
+        true => lines.push(String::from("All parts are solved.")),
+        false => lines.extend(unsolved),
+    }
+
+    lines.join("\n") + "\n"
+}
+
+pub fn write(path: &Path, content: &str) -> Result<(), Box<dyn Error>> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("Failed to create '{}' [{err}]", dir.display()))?;
+    }
+
+    std::fs::write(path, content)
+        .map_err(|err| format!("Failed to write report '{}' [{err}]", path.display()).into())
+}
+
+// Cached answers were not solved by this run, they do not count into the time
+fn solve_times(result: &DayResult) -> impl Iterator<Item = Duration> + '_ {
+    result
+        .parts
+        .iter()
+        .filter(|part| !part.cached)
+        .map(|part| part.elapsed)
+}
+
+fn format_answer(part: &PartResult) -> String {
+    match &part.answer {
+        Ok(answer) if answer::is_solved(answer) => format!("`{}`", escape(answer)),
+        Ok(_) => String::from("-"),
+        Err(_) => String::from("error"),
+    }
+}
+
+// Pipes would split the cell of the table
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(part: usize, answer: Result<&str, &str>, elapsed: u64) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.map(String::from).map_err(String::from),
+            elapsed: Duration::from_micros(elapsed),
+            verdict: None,
+            witness: None,
+            cached: false,
+        }
+    }
+
+    fn results() -> Vec<DayResult> {
+        vec![
+            DayResult {
+                day: 1,
+                name: String::from("Day 01: Not Quite Lisp"),
+                input_hash: None,
+                parse: Ok(Duration::from_micros(250)),
+                parts: vec![part(1, Ok("280"), 1500), part(2, Ok("1797"), 500)],
+            },
+            DayResult {
+                day: 7,
+                name: String::from("Day 07: Some Assembly Required"),
+                input_hash: None,
+                parse: Ok(Duration::from_micros(750)),
+                parts: vec![
+                    part(1, Ok("46065"), 1000),
+                    part(2, Err("Budget exceeded\nafter 5 steps"), 1000),
+                ],
+            },
+            DayResult {
+                day: 25,
+                name: String::from("Day 25: Let It Snow"),
+                input_hash: None,
+                parse: Err(String::from("Invalid input")),
+                parts: vec![
+                    part(1, Err("Failed to parse input"), 0),
+                    part(2, Ok(answer::NOT_SOLVED), 0),
+                ],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_render() {
+        let expected = [
+            "# Advent of Code 2015",
+            "",
+            "Stars: 3 / 6",
+            "",
+            "Total time: 5.000 ms",
+            "",
+            "| Day | Title | Part 1 | Part 2 | Parse | Solve | Stars |",
+            "| ---: | --- | --- | --- | ---: | ---: | --- |",
+            "| 1 | Not Quite Lisp | `280` | `1797` | 0.250 ms | 2.000 ms | ** |",
+            "| 7 | Some Assembly Required | `46065` | error | 0.750 ms | 2.000 ms | * |",
+            "| 25 | Let It Snow | error | - | - | 0.000 ms |  |",
+            "",
+            "## Unsolved",
+            "",
+            "- Day 7 part 2: Budget exceeded",
+            "- Day 25 part 1: Failed to parse input",
+            "- Day 25 part 2: Not solved",
+            "",
+        ];
+
+        assert_eq!(render(&results()), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_render_all_solved() {
+        let mut results = results();
+        results.truncate(1);
+
+        assert_eq!(star_count(&results), 2);
+        assert!(render(&results).ends_with("## Unsolved\n\nAll parts are solved.\n"));
+    }
+}
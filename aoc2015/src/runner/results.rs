@@ -0,0 +1,232 @@
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+use common::budget::{self, Budget, Limits};
+use common::explain::Witness;
+use common::input::{self, InputSource};
+use common::log::{self, Logger};
+use puzzler::puzzler::puzzle::Puzzle;
+
+use crate::runner::answers::{self, Answers, Verdict};
+use crate::runner::cache::Cache;
+use crate::runner::registry::Entry;
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: usize,
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+    pub verdict: Option<Verdict>,
+    // Searched only when explanation was requested and the puzzle provides one
+    pub witness: Option<Result<Witness, String>>,
+    // Answer was taken from the cache instead of solving the part
+    pub cached: bool,
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: usize,
+    pub name: String,
+    pub input_hash: Option<String>,
+    pub parse: Result<Duration, String>,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    pub fn failed_count(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|part| {
+                part.answer.is_err() || matches!(part.verdict, Some(Verdict::Mismatch { .. }))
+            })
+            .count()
+    }
+
+    // Append parts solved by another task, the first measured parse time is kept
+    pub fn merge(&mut self, other: DayResult) {
+        if self.parse.is_err() && other.parse.is_ok() {
+            self.parse = other.parse;
+        }
+
+        self.parts.extend(other.parts);
+    }
+
+    // Compare answers of all parts with the known answers of the input
+    pub fn verify(&mut self, answers: &Answers) {
+        for part in &mut self.parts {
+            part.verdict = Some(answers.verify(
+                self.day,
+                part.part,
+                self.input_hash.as_deref(),
+                &part.answer,
+            ));
+        }
+    }
+}
+
+// How the parts are solved, shared by all days of the run
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SolveOptions<'a> {
+    pub limits: Limits,
+    pub explain: bool,
+    pub cache: Option<&'a Cache>,
+    pub log: Option<&'a log::Filter>,
+    // Answers are verified, i.e. every part is solved even when its answer is cached
+    pub verify: bool,
+}
+
+// Input of the day read up front, hash is known only when the input was read
+#[derive(Debug)]
+pub struct DayInput {
+    pub hash: Option<String>,
+    pub lines: Result<Option<Vec<String>>, String>,
+}
+
+pub fn run_day(
+    entry: &Entry,
+    parts: &[usize],
+    source: Option<InputSource>,
+    options: SolveOptions,
+) -> DayResult {
+    let input = load_input(entry, source);
+    solve_day(entry, parts, &input, options)
+}
+
+pub fn load_input(entry: &Entry, source: Option<InputSource>) -> DayInput {
+    let lines =
+        read_input((entry.create)().as_ref(), entry.day, source).map_err(|err| err.to_string());
+
+    // Puzzle without input is identified by hash of the empty input
+    let hash = lines
+        .as_ref()
+        .ok()
+        .map(|lines| answers::hash_input(lines.as_deref().unwrap_or_default()));
+
+    DayInput { hash, lines }
+}
+
+// Parse the input by a fresh puzzle and solve selected parts in order. Long searches share
+// the budget started here, i.e. the timeout covers parsing, all the parts and their witnesses.
+// Messages of the day are written up to its level, nothing but warnings and errors by default.
+pub fn solve_day(
+    entry: &Entry,
+    parts: &[usize],
+    input: &DayInput,
+    options: SolveOptions,
+) -> DayResult {
+    let level = options
+        .log
+        .map_or_else(Default::default, |filter| filter.level(entry.day));
+
+    log::scoped(Logger::new(level).with_day(entry.day), || {
+        budget::scoped(Budget::new(options.limits), || {
+            solve_parts(entry, parts, input, options)
+        })
+    })
+}
+
+fn solve_parts(
+    entry: &Entry,
+    parts: &[usize],
+    input: &DayInput,
+    options: SolveOptions,
+) -> DayResult {
+    let mut puzzle = (entry.create)();
+
+    let name = day_name(puzzle.as_ref());
+
+    let start = Instant::now();
+    let parse = input
+        .lines
+        .clone()
+        .and_then(|lines| match lines {
+            Some(lines) => puzzle.parse_content(lines).map_err(|err| err.to_string()),
+            None => Ok(()),
+        })
+        .map(|_| start.elapsed());
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            // Parts can not be solved without parsed input
+            if let Err(err) = &parse {
+                return PartResult {
+                    part,
+                    answer: Err(format!("Failed to parse input [{err}]")),
+                    elapsed: Duration::ZERO,
+                    verdict: None,
+                    witness: None,
+                    cached: false,
+                };
+            }
+
+            // Input is still parsed for cached answers, witnesses need the parsed puzzle
+            let cached = options
+                .cache
+                .filter(|_| !options.verify)
+                .zip(input.hash.as_deref())
+                .and_then(|(cache, hash)| cache.get(entry.day, part, hash));
+
+            let start = Instant::now();
+            let answer = match (cached, part) {
+                (Some(answer), _) => Ok(answer.to_string()),
+                (None, 1) => puzzle.solve_part1(),
+                (None, 2) => puzzle.solve_part2(),
+                (None, p) => Err(format!("Unsupported part '{p}'").into()),
+            };
+
+            let elapsed = start.elapsed();
+
+            // Witness is searched after the answer is timed, failed part has nothing to explain
+            let witness = match part {
+                1 if options.explain && answer.is_ok() => puzzle.explain_part1(),
+                2 if options.explain && answer.is_ok() => puzzle.explain_part2(),
+                _ => None,
+            };
+
+            PartResult {
+                part,
+                answer: answer.map_err(|err| err.to_string()),
+                elapsed,
+                verdict: None,
+                witness: witness.map(|witness| witness.map_err(|err| err.to_string())),
+                cached: cached.is_some(),
+            }
+        })
+        .collect();
+
+    DayResult {
+        day: entry.day,
+        name,
+        input_hash: input.hash.clone(),
+        parse,
+        parts,
+    }
+}
+
+// Strip decoration, i.e. "--- Day 01: Not Quite Lisp ---" -> "Day 01: Not Quite Lisp"
+pub fn day_name(puzzle: &dyn Puzzle) -> String {
+    puzzle.name().trim_matches(['-', ' ']).to_string()
+}
+
+// Read input of the day, None if the puzzle does not need any. Input given on the command line
+// takes precedence over the input resolved from the environment or the repository.
+pub fn read_input(
+    puzzle: &dyn Puzzle,
+    day: usize,
+    source: Option<InputSource>,
+) -> Result<Option<Vec<String>>, Box<dyn Error>> {
+    let source = match source {
+        Some(source) => source,
+        None => {
+            // Puzzle does not need any input
+            if puzzle.get_input_file_path().is_none() {
+                return Ok(None);
+            }
+
+            input::resolve(day)
+        }
+    };
+
+    source.read_lines().map(Some)
+}
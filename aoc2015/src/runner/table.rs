@@ -0,0 +1,296 @@
+use std::time::Duration;
+
+use crate::runner::results::DayResult;
+
+const HEADER: [&str; 6] = ["Day", "Name", "Part", "Answer", "Parse", "Solve"];
+
+pub fn print(results: &[DayResult]) {
+    println!("{}", render(results));
+}
+
+pub fn render(results: &[DayResult]) -> String {
+    // Column with verdicts is shown only when answers were verified
+    let verify = results
+        .iter()
+        .flat_map(|result| &result.parts)
+        .any(|part| part.verdict.is_some());
+
+    // Column with witnesses is shown only when answers were explained
+    let explain = results
+        .iter()
+        .flat_map(|result| &result.parts)
+        .any(|part| part.witness.is_some());
+
+    let mut header = HEADER.map(String::from).to_vec();
+    if verify {
+        header.push(String::from("Verify"));
+    }
+    if explain {
+        header.push(String::from("Witness"));
+    }
+
+    // Build all rows first so we can calculate width of columns
+    let mut rows = vec![header];
+
+    for result in results {
+        let parse = match &result.parse {
+            Ok(elapsed) => format_duration(*elapsed),
+            Err(_) => String::from("-"),
+        };
+
+        for part in &result.parts {
+            let answer = match (&part.answer, &result.parse) {
+                (Ok(answer), _) => answer.clone(),
+                // Multi-line diagnostics would break the table, they are printed below it
+                (Err(_), Err(err)) if err.contains('\n') => {
+                    String::from("ERROR: Failed to parse input, see below")
+                }
+                (Err(err), _) => format!("ERROR: {err}"),
+            };
+
+            let mut row = vec![
+                result.day.to_string(),
+                result.name.clone(),
+                part.part.to_string(),
+                answer,
+                parse.clone(),
+                // Cached answer was not solved, there is no time to show
+                match part.cached {
+                    true => String::from("cached"),
+                    false => format_duration(part.elapsed),
+                },
+            ];
+
+            if verify {
+                row.push(
+                    part.verdict
+                        .as_ref()
+                        .map_or_else(|| String::from("-"), |verdict| verdict.to_string()),
+                );
+            }
+
+            if explain {
+                row.push(match &part.witness {
+                    Some(Ok(witness)) => witness.to_string(),
+                    Some(Err(err)) => format!("ERROR: {err}"),
+                    None => String::from("-"),
+                });
+            }
+
+            rows.push(row);
+        }
+    }
+
+    let mut lines = render_rows(&rows);
+
+    for result in results {
+        if let Err(err) = &result.parse
+            && err.contains('\n')
+        {
+            lines.push(String::new());
+            lines.push(format!("{}: Failed to parse input", result.name));
+            lines.push(err.clone());
+        }
+    }
+
+    lines.join("\n")
+}
+
+// Align cells of the rows into columns, the first row is the header
+pub fn render_rows(rows: &[Vec<String>]) -> Vec<String> {
+    let mut widths = vec![0; rows.first().map_or(0, Vec::len)];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = std::cmp::max(*width, cell.chars().count());
+        }
+    }
+
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("-+-");
+
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+
+    for (index, row) in rows.iter().enumerate() {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        lines.push(line.trim_end().to_string());
+
+        // Separate header from the results
+        if index == 0 {
+            lines.push(separator.clone());
+        }
+    }
+
+    lines
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::explain::Witness;
+
+    use crate::runner::answers::Verdict;
+    use crate::runner::results::PartResult;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let results = [DayResult {
+            day: 1,
+            name: String::from("Day 01: Not Quite Lisp"),
+            input_hash: None,
+            parse: Ok(Duration::from_micros(250)),
+            parts: vec![
+                PartResult {
+                    part: 1,
+                    answer: Ok(String::from("280")),
+                    elapsed: Duration::from_micros(1500),
+                    verdict: None,
+                    witness: None,
+                    cached: false,
+                },
+                PartResult {
+                    part: 2,
+                    answer: Err(String::from("Floor number not found")),
+                    elapsed: Duration::ZERO,
+                    verdict: None,
+                    witness: None,
+                    cached: false,
+                },
+            ],
+        }];
+
+        let expected = [
+            "Day | Name                   | Part | Answer                        | Parse    | Solve",
+            "----+------------------------+------+-------------------------------+----------+---------",
+            "1   | Day 01: Not Quite Lisp | 1    | 280                           | 0.250 ms | 1.500 ms",
+            "1   | Day 01: Not Quite Lisp | 2    | ERROR: Floor number not found | 0.250 ms | 0.000 ms",
+        ];
+
+        assert_eq!(render(&results), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_render_parse_diagnostics() {
+        let results = [DayResult {
+            day: 2,
+            name: String::from("Day 02: No Math"),
+            input_hash: None,
+            parse: Err(String::from(
+                "Line 1 has invalid format\n 1 | 1x2\n   |    ^",
+            )),
+            parts: vec![PartResult {
+                part: 1,
+                answer: Err(String::from("Failed to parse input")),
+                elapsed: Duration::ZERO,
+                verdict: None,
+                witness: None,
+                cached: false,
+            }],
+        }];
+
+        let expected = [
+            "Day | Name            | Part | Answer                                  | Parse | Solve",
+            "----+-----------------+------+-----------------------------------------+-------+---------",
+            "2   | Day 02: No Math | 1    | ERROR: Failed to parse input, see below | -     | 0.000 ms",
+            "",
+            "Day 02: No Math: Failed to parse input",
+            "Line 1 has invalid format",
+            " 1 | 1x2",
+            "   |    ^",
+        ];
+
+        assert_eq!(render(&results), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_render_verify() {
+        let results = [DayResult {
+            day: 1,
+            name: String::from("Day 01: Not Quite Lisp"),
+            input_hash: Some(String::from("abc")),
+            parse: Ok(Duration::from_micros(250)),
+            parts: vec![
+                PartResult {
+                    part: 1,
+                    answer: Ok(String::from("280")),
+                    elapsed: Duration::from_micros(1500),
+                    verdict: Some(Verdict::Match),
+                    witness: None,
+                    cached: false,
+                },
+                PartResult {
+                    part: 2,
+                    answer: Ok(String::from("1798")),
+                    elapsed: Duration::from_micros(500),
+                    verdict: Some(Verdict::Mismatch {
+                        expected: String::from("1797"),
+                    }),
+                    witness: None,
+                    cached: false,
+                },
+            ],
+        }];
+
+        let expected = [
+            "Day | Name                   | Part | Answer | Parse    | Solve    | Verify",
+            "----+------------------------+------+--------+----------+----------+------------------------",
+            "1   | Day 01: Not Quite Lisp | 1    | 280    | 0.250 ms | 1.500 ms | match",
+            "1   | Day 01: Not Quite Lisp | 2    | 1798   | 0.250 ms | 0.500 ms | mismatch, expected 1797",
+        ];
+
+        assert_eq!(render(&results), expected.join("\n"));
+        assert_eq!(results[0].failed_count(), 1);
+    }
+
+    #[test]
+    fn test_render_explain() {
+        let results = [DayResult {
+            day: 9,
+            name: String::from("Day 09: All in a Single Night"),
+            input_hash: None,
+            parse: Ok(Duration::from_micros(100)),
+            parts: vec![
+                PartResult {
+                    part: 1,
+                    answer: Ok(String::from("605")),
+                    elapsed: Duration::from_micros(200),
+                    verdict: None,
+                    witness: Some(Ok(Witness::new()
+                        .with("route", vec!["Belfast", "Dublin", "London"])
+                        .with("distance", 605_usize))),
+                    cached: false,
+                },
+                PartResult {
+                    part: 2,
+                    answer: Ok(String::from("982")),
+                    elapsed: Duration::from_micros(300),
+                    verdict: None,
+                    witness: Some(Err(String::from("No routes"))),
+                    cached: true,
+                },
+            ],
+        }];
+
+        let expected = [
+            "Day | Name                          | Part | Answer | Parse    | Solve    | Witness",
+            "----+-------------------------------+------+--------+----------+----------+------------------------------------------------",
+            "9   | Day 09: All in a Single Night | 1    | 605    | 0.100 ms | 0.200 ms | route: [Belfast, Dublin, London]; distance: 605",
+            "9   | Day 09: All in a Single Night | 2    | 982    | 0.100 ms | cached   | ERROR: No routes",
+        ];
+
+        assert_eq!(render(&results), expected.join("\n"));
+    }
+}
@@ -0,0 +1,18 @@
+// Answer of a part without a puzzle, i.e. day 25 part 2 is a star for all the other stars
+pub const NOT_SOLVED: &str = "Not solved";
+
+// Placeholder answer does not earn a star
+pub fn is_solved(answer: &str) -> bool {
+    answer != NOT_SOLVED
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_solved() {
+        assert!(is_solved("280"));
+        assert!(!is_solved(NOT_SOLVED));
+    }
+}
@@ -0,0 +1,277 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+// Clock and cancellation are not free, they are polled once per this number of steps
+const POLL_INTERVAL: u64 = 1024;
+
+thread_local! {
+    static CURRENT: RefCell<Budget> = RefCell::new(Budget::unlimited());
+}
+
+// Limits of a single day as given on the command line
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub max_steps: Option<u64>,
+}
+
+// Flag shared between threads to stop searches cooperatively
+#[derive(Debug, Default, Clone)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// Budget of long running searches. The wall-clock deadline is shared by all searches of the
+// day, the step limit applies to every search separately.
+#[derive(Debug, Default, Clone)]
+pub struct Budget {
+    deadline: Option<Instant>,
+    timeout: Option<Duration>,
+    max_steps: Option<u64>,
+    cancel: CancelToken,
+}
+
+impl Budget {
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    // Deadline starts now
+    pub fn new(limits: Limits) -> Self {
+        Self {
+            deadline: limits.timeout.map(|timeout| Instant::now() + timeout),
+            timeout: limits.timeout,
+            max_steps: limits.max_steps,
+            cancel: CancelToken::new(),
+        }
+    }
+
+    pub fn with_cancel(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+
+    // Start metering a single search
+    pub fn meter(&self) -> Meter {
+        Meter {
+            budget: self.clone(),
+            steps: 0,
+        }
+    }
+}
+
+// Reason why a search ran out of budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    Timeout(Duration),
+    Steps(u64),
+    Cancelled,
+}
+
+// Search ran out of budget after the given number of steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exceeded {
+    pub limit: Limit,
+    pub steps: u64,
+}
+
+impl Display for Exceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.limit {
+            Limit::Timeout(timeout) => write!(
+                f,
+                "Budget exceeded, time limit of {:.3} s",
+                timeout.as_secs_f64()
+            )?,
+            Limit::Steps(steps) => write!(f, "Budget exceeded, limit of {steps} step(s)")?,
+            Limit::Cancelled => write!(f, "Budget exceeded, search was cancelled")?,
+        }
+
+        write!(f, " reached after {} step(s)", self.steps)
+    }
+}
+
+impl Error for Exceeded {}
+
+// Step counter of a single search
+#[derive(Debug, Clone)]
+pub struct Meter {
+    budget: Budget,
+    steps: u64,
+}
+
+impl Meter {
+    // Count a single step of the search, e.g. one state or one instruction
+    pub fn step(&mut self) -> Result<(), Exceeded> {
+        let exceeded = |limit| Exceeded {
+            limit,
+            steps: self.steps,
+        };
+
+        if let Some(max_steps) = self.budget.max_steps
+            && self.steps >= max_steps
+        {
+            return Err(exceeded(Limit::Steps(max_steps)));
+        }
+
+        if self.steps.is_multiple_of(POLL_INTERVAL) {
+            if self.budget.cancel.is_cancelled() {
+                return Err(exceeded(Limit::Cancelled));
+            }
+
+            if let (Some(deadline), Some(timeout)) = (self.budget.deadline, self.budget.timeout)
+                && Instant::now() >= deadline
+            {
+                return Err(exceeded(Limit::Timeout(timeout)));
+            }
+        }
+
+        self.steps += 1;
+        Ok(())
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+}
+
+// Budget of searches running on the current thread, unlimited unless set by scoped()
+pub fn current() -> Budget {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+// Run the function with the budget installed for the current thread, previous budget is
+// restored afterwards
+pub fn scoped<T, F>(budget: Budget, func: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let previous = CURRENT.with(|current| current.replace(budget));
+    let result = func();
+    CURRENT.with(|current| current.replace(previous));
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(meter: &mut Meter, steps: u64) -> Result<(), Exceeded> {
+        (0..steps).try_for_each(|_| meter.step())
+    }
+
+    #[test]
+    fn test_unlimited() {
+        let mut meter = Budget::unlimited().meter();
+
+        assert_eq!(run(&mut meter, 10_000), Ok(()));
+        assert_eq!(meter.steps(), 10_000);
+    }
+
+    #[test]
+    fn test_max_steps() {
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(5),
+        });
+
+        let mut meter = budget.meter();
+        assert_eq!(run(&mut meter, 5), Ok(()));
+        assert_eq!(
+            meter.step(),
+            Err(Exceeded {
+                limit: Limit::Steps(5),
+                steps: 5
+            })
+        );
+
+        // Every search has its own counter
+        assert_eq!(run(&mut budget.meter(), 5), Ok(()));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let budget = Budget::new(Limits {
+            timeout: Some(Duration::ZERO),
+            max_steps: None,
+        });
+
+        assert_eq!(
+            budget.meter().step(),
+            Err(Exceeded {
+                limit: Limit::Timeout(Duration::ZERO),
+                steps: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_cancel() {
+        let cancel = CancelToken::new();
+        let budget = Budget::unlimited().with_cancel(cancel.clone());
+
+        let mut meter = budget.meter();
+        assert_eq!(run(&mut meter, POLL_INTERVAL), Ok(()));
+
+        budget.cancel_token().cancel();
+        assert_eq!(
+            meter.step(),
+            Err(Exceeded {
+                limit: Limit::Cancelled,
+                steps: POLL_INTERVAL
+            })
+        );
+        assert!(cancel.is_cancelled());
+    }
+
+    #[test]
+    fn test_scoped() {
+        let limits = Limits {
+            timeout: None,
+            max_steps: Some(1),
+        };
+
+        let steps = scoped(Budget::new(limits), || {
+            let mut meter = current().meter();
+            run(&mut meter, 2).unwrap_err().steps
+        });
+        assert_eq!(steps, 1);
+
+        // Budget is restored
+        assert_eq!(run(&mut current().meter(), 2), Ok(()));
+    }
+
+    #[test]
+    fn test_exceeded_display() {
+        let exceeded = Exceeded {
+            limit: Limit::Timeout(Duration::from_millis(1500)),
+            steps: 42,
+        };
+
+        assert_eq!(
+            exceeded.to_string(),
+            "Budget exceeded, time limit of 1.500 s reached after 42 step(s)"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use puzzler::puzzler::puzzle::Puzzle;
+
+// Single value of a witness, e.g. distance or list of visited cities
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Number(i128),
+    Text(String),
+    List(Vec<Value>),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{number}"),
+            Value::Text(text) => write!(f, "{text}"),
+            Value::List(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Value::Number(value as i128)
+    }
+}
+
+impl From<isize> for Value {
+    fn from(value: isize) -> Self {
+        Value::Number(value as i128)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+// Evidence of an answer as named values in the order of insertion, e.g. the route of day 9
+// together with its distance
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Witness {
+    pub fields: Vec<(String, Value)>,
+}
+
+impl Witness {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with<V: Into<Value>>(mut self, name: &str, value: V) -> Self {
+        self.fields.push((name.to_string(), value.into()));
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl Display for Witness {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, (name, value)) in self.fields.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{name}: {value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// Explanation of the answers on top of the Puzzle. Witness is searched separately from
+// solve_part1() and solve_part2(), therefore answers are not slowed down by it. Puzzles without
+// witnesses keep the defaults.
+pub trait Explain: Puzzle {
+    // None if the puzzle does not explain the answer of part 1
+    fn explain_part1(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        None
+    }
+
+    // None if the puzzle does not explain the answer of part 2
+    fn explain_part2(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_witness_display() {
+        let witness = Witness::new()
+            .with("route", vec!["London", "Dublin", "Belfast"])
+            .with("distance", 605_usize)
+            .with("change", -3_isize);
+
+        assert_eq!(
+            witness.to_string(),
+            "route: [London, Dublin, Belfast]; distance: 605; change: -3"
+        );
+        assert_eq!(witness.get("distance"), Some(&Value::Number(605)));
+        assert_eq!(witness.get("unknown"), None);
+    }
+
+    #[test]
+    fn test_value_display() {
+        assert_eq!(Value::from(Vec::<usize>::new()).to_string(), "[]");
+        assert_eq!(
+            Value::from(vec![vec![1_usize, 2], vec![3]]).to_string(),
+            "[[1, 2], [3]]"
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use std::path::{Path, PathBuf};
+
+use puzzler::puzzler::puzzle::Puzzle;
+
+use crate::input::InputSource;
+
+// File in the examples directory of the day listing examples with their expected answers
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+// Example from the puzzle statement with expected answer of a single part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub file: String,
+    pub part: usize,
+    pub answer: String,
+}
+
+// Directory with examples of the day, i.e. <crate>/examples
+pub fn examples_dir(manifest_dir: &str) -> PathBuf {
+    Path::new(manifest_dir).join("examples")
+}
+
+// Parse list of examples. Every line has format '<file> <part> <answer>', empty lines and
+// lines starting with '#' are ignored.
+pub fn parse_examples(content: &str) -> Result<Vec<Example>, String> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let invalid = || format!("Line {} has invalid format '{line}'", index + 1);
+
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let (Some(file), Some(part), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid());
+            };
+
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(invalid()),
+            };
+
+            Ok(Example {
+                file: file.to_string(),
+                part,
+                answer: answer.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+// Run every example of the day through parse_content() and solve_partN() of a fresh puzzle.
+// All examples are run, the test fails with the list of examples which did not pass.
+pub fn check_examples<P, F>(manifest_dir: &str, create: F)
+where
+    P: Puzzle,
+    F: Fn() -> P,
+{
+    let dir = examples_dir(manifest_dir);
+    let path = dir.join(ANSWERS_FILE);
+
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Failed to read '{}' [{err}]", path.display()));
+    let examples = parse_examples(&content)
+        .unwrap_or_else(|err| panic!("Invalid examples file '{}' [{err}]", path.display()));
+
+    assert!(!examples.is_empty(), "No examples in '{}'", path.display());
+
+    let failures = examples
+        .iter()
+        .filter_map(|example| {
+            let result = run_example(&dir, example, create());
+
+            match result {
+                Ok(answer) if answer == example.answer => None,
+                Ok(answer) => Some(format!(
+                    "{} part {}: expected '{}', found '{answer}'",
+                    example.file, example.part, example.answer
+                )),
+                Err(err) => Some(format!("{} part {}: {err}", example.file, example.part)),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    assert!(
+        failures.is_empty(),
+        "Examples failed:\n{}",
+        failures.join("\n")
+    );
+}
+
+// Lines of a single example of the day, e.g. for tests of parameterised functions when the
+// answers of the puzzle depend on constants which differ from the puzzle statement
+pub fn read_example(manifest_dir: &str, file: &str) -> Vec<String> {
+    InputSource::File(examples_dir(manifest_dir).join(file))
+        .read_lines()
+        .unwrap_or_else(|err| panic!("Failed to read example [{err}]"))
+}
+
+// Parse the real input of the puzzle. Inputs are private and may be missing, e.g. in a fresh
+// clone of the repository, so None is returned and the caller skips the test.
+pub fn parse_real_input<P: Puzzle>(mut puzzle: P) -> Option<P> {
+    if let Some(path) = puzzle.get_input_file_path()
+        && !path.exists()
+    {
+        eprintln!("Skipping test, input file '{}' not found", path.display());
+        return None;
+    }
+
+    puzzle
+        .parse_input_file()
+        .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+
+    Some(puzzle)
+}
+
+fn run_example<P: Puzzle>(dir: &Path, example: &Example, mut puzzle: P) -> Result<String, String> {
+    let lines = InputSource::File(dir.join(&example.file))
+        .read_lines()
+        .map_err(|err| err.to_string())?;
+
+    puzzle
+        .parse_content(lines)
+        .map_err(|err| format!("Failed to parse input [{err}]"))?;
+
+    let answer = match example.part {
+        1 => puzzle.solve_part1(),
+        _ => puzzle.solve_part2(),
+    };
+
+    answer.map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+
+    // Puzzle summing numbers, part 2 fails on negative sum
+    struct Sum {
+        numbers: Vec<i64>,
+    }
+
+    impl Puzzle for Sum {
+        fn name(&self) -> &str {
+            "--- Sum ---"
+        }
+
+        fn get_input_file_path(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/missing/input/day_00.txt"))
+        }
+
+        fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
+            self.numbers = lines
+                .iter()
+                .map(|line| line.parse::<i64>())
+                .collect::<Result<_, _>>()?;
+            Ok(())
+        }
+
+        fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
+            Ok(self.numbers.iter().sum::<i64>().to_string())
+        }
+
+        fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
+            let sum = self.numbers.iter().sum::<i64>();
+            if sum < 0 {
+                return Err("Negative sum".into());
+            }
+            Ok((2 * sum).to_string())
+        }
+    }
+
+    fn sum() -> Sum {
+        Sum {
+            numbers: Vec::new(),
+        }
+    }
+
+    // Temporary crate directory with examples, removed when dropped
+    struct Fixture {
+        dir: PathBuf,
+    }
+
+    impl Fixture {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("common_fixtures_{name}_{}", std::process::id()));
+            let examples = examples_dir(dir.to_str().unwrap());
+            std::fs::create_dir_all(&examples).unwrap();
+
+            for (file, content) in files {
+                std::fs::write(examples.join(file), content).unwrap();
+            }
+
+            Self { dir }
+        }
+
+        fn manifest_dir(&self) -> &str {
+            self.dir.to_str().unwrap()
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        let examples = parse_examples("# file part answer\n\na.txt 1 42\nb.txt 2 Not solved\n");
+
+        assert_eq!(
+            examples,
+            Ok(vec![
+                Example {
+                    file: String::from("a.txt"),
+                    part: 1,
+                    answer: String::from("42")
+                },
+                Example {
+                    file: String::from("b.txt"),
+                    part: 2,
+                    answer: String::from("Not solved")
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_examples_invalid() {
+        assert!(parse_examples("a.txt 1").is_err());
+        assert!(parse_examples("a.txt 3 42").is_err());
+    }
+
+    #[test]
+    fn test_read_example() {
+        let fixture = Fixture::new("read", &[("one.txt", "1\n2\n")]);
+
+        assert_eq!(
+            read_example(fixture.manifest_dir(), "one.txt"),
+            vec![String::from("1"), String::from("2")]
+        );
+    }
+
+    #[test]
+    fn test_check_examples() {
+        let fixture = Fixture::new(
+            "pass",
+            &[
+                ("answers.txt", "one.txt 1 6\none.txt 2 12\n"),
+                ("one.txt", "1\n2\n3\n"),
+            ],
+        );
+
+        check_examples(fixture.manifest_dir(), sum);
+    }
+
+    #[test]
+    fn test_check_examples_failure() {
+        let fixture = Fixture::new(
+            "fail",
+            &[
+                (
+                    "answers.txt",
+                    "one.txt 1 7\nneg.txt 1 -1\nneg.txt 2 -2\nbad.txt 1 0\n",
+                ),
+                ("one.txt", "1\n2\n3\n"),
+                ("neg.txt", "-1\n"),
+                ("bad.txt", "x\n"),
+            ],
+        );
+
+        let err =
+            std::panic::catch_unwind(|| check_examples(fixture.manifest_dir(), sum)).unwrap_err();
+        let message = err.downcast_ref::<String>().unwrap();
+
+        assert!(message.contains("one.txt part 1: expected '7', found '6'"));
+        assert!(message.contains("neg.txt part 2: Negative sum"));
+        assert!(message.contains("bad.txt part 1: Failed to parse input"));
+        assert!(!message.contains("neg.txt part 1"));
+    }
+
+    #[test]
+    fn test_parse_real_input_missing() {
+        assert!(parse_real_input(sum()).is_none());
+    }
+}
@@ -0,0 +1,408 @@
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use crate::random::Rng;
+
+// Characters inserted into mutated inputs, mix of syntax of all days and some troublemakers
+const CHARS: &[char] = &[
+    '0', '1', '9', '-', '+', 'a', 'z', 'A', 'Z', 'x', ' ', '\t', ',', ':', '.', '(', ')', '[', ']',
+    '{', '}', '"', '\\', '>', '#', 'é', '€',
+];
+
+// Numbers replacing numbers of the input, i.e. boundaries and overflows
+const NUMBERS: &[&str] = &[
+    "0",
+    "-1",
+    "65536",
+    "4294967296",
+    "18446744073709551615",
+    "99999999999999999999999",
+];
+
+// Upper bound of parser calls made by shrinking of a single failure
+const MAX_SHRINK_CALLS: usize = 10_000;
+
+// Parser panicked on the input, input is already shrunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub iteration: usize,
+    pub message: String,
+    pub lines: Vec<String>,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Parser panicked '{}' on mutation {}, minimal input has {} line(s):",
+            self.message,
+            self.iteration,
+            self.lines.len()
+        )?;
+
+        for line in &self.lines {
+            write!(f, "\n  {line:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for Failure {}
+
+// Feed the corpus and its random mutations to the parser. Parser may return Ok or Err, the
+// first panic is shrunk to a minimal input and returned.
+pub fn fuzz<F>(
+    parse: F,
+    corpus: &[Vec<String>],
+    seed: u64,
+    iterations: usize,
+) -> Result<(), Failure>
+where
+    F: Fn(Vec<String>) -> Result<(), Box<dyn Error>>,
+{
+    let mut rng = Rng::new(seed);
+
+    let failure = |iteration, lines, message| {
+        let lines = shrink(&parse, lines);
+        let message = check(&parse, &lines).unwrap_or(message);
+
+        Failure {
+            iteration,
+            message,
+            lines,
+        }
+    };
+
+    // Unmodified corpus goes first as iteration 0
+    for lines in corpus {
+        if let Some(message) = check(&parse, lines) {
+            return Err(failure(0, lines.clone(), message));
+        }
+    }
+
+    for iteration in 1..=iterations {
+        let lines = match corpus.is_empty() {
+            true => mutate(&mut rng, &[]),
+            false => {
+                let base = rng.choose(corpus).clone();
+                mutate(&mut rng, &base)
+            }
+        };
+
+        if let Some(message) = check(&parse, &lines) {
+            return Err(failure(iteration, lines, message));
+        }
+    }
+
+    Ok(())
+}
+
+// Panic message if the parser panicked on the input
+pub fn check<F>(parse: &F, lines: &[String]) -> Option<String>
+where
+    F: Fn(Vec<String>) -> Result<(), Box<dyn Error>>,
+{
+    let lines = lines.to_vec();
+
+    quietly(|| {
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            // Both results are fine, parser shall not panic only
+            let _ = parse(lines);
+        }))
+    })
+    .err()
+    .map(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+// Apply one to three random mutations to the input
+pub fn mutate(rng: &mut Rng, lines: &[String]) -> Vec<String> {
+    let mut lines = lines.to_vec();
+
+    for _ in 0..rng.range(1..=3) {
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+
+        let index = rng.range(0..=lines.len() - 1);
+        let mut chars = lines[index].chars().collect::<Vec<_>>();
+        let position = rng.range(0..=chars.len());
+
+        match rng.range(0..=10) {
+            // Input ends early
+            0 => lines.truncate(index),
+            1 => {
+                chars.truncate(position);
+                lines[index] = chars.into_iter().collect();
+            }
+            2 => {
+                lines.remove(index);
+            }
+            3 => lines.insert(index, lines[index].clone()),
+            4 => lines.insert(index, String::new()),
+            5 => {
+                let other = rng.range(0..=lines.len() - 1);
+                lines.swap(index, other);
+            }
+            6 => {
+                let tail = chars.split_off(position);
+                lines[index] = chars.into_iter().collect();
+                lines.insert(index + 1, tail.into_iter().collect());
+            }
+            7 if position < chars.len() => {
+                chars.remove(position);
+                lines[index] = chars.into_iter().collect();
+            }
+            8 if position < chars.len() => {
+                chars[position] = *rng.choose(CHARS);
+                lines[index] = chars.into_iter().collect();
+            }
+            9 => lines[index] = replace_number(rng, &lines[index]),
+            _ => {
+                chars.insert(position, *rng.choose(CHARS));
+                lines[index] = chars.into_iter().collect();
+            }
+        }
+    }
+
+    lines
+}
+
+// Replace a random number of the line, line without numbers gets one appended
+fn replace_number(rng: &mut Rng, line: &str) -> String {
+    let number = rng.choose(NUMBERS);
+
+    let digits = line
+        .char_indices()
+        .filter(|(_, c)| c.is_ascii_digit())
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    if digits.is_empty() {
+        return format!("{line}{number}");
+    }
+
+    // Extend the digit to the whole number, digits are single byte characters
+    let digit = *rng.choose(&digits);
+    let start = line[..digit]
+        .trim_end_matches(|c: char| c.is_ascii_digit())
+        .len();
+    let end = line.len()
+        - line[digit..]
+            .trim_start_matches(|c: char| c.is_ascii_digit())
+            .len();
+
+    format!("{}{number}{}", &line[..start], &line[end..])
+}
+
+// Remove lines and characters as long as the parser keeps panicking
+pub fn shrink<F>(parse: &F, lines: Vec<String>) -> Vec<String>
+where
+    F: Fn(Vec<String>) -> Result<(), Box<dyn Error>>,
+{
+    let mut calls = 0;
+    let mut panics = |lines: &[String]| {
+        calls += 1;
+        calls <= MAX_SHRINK_CALLS && check(parse, lines).is_some()
+    };
+
+    let mut lines = lines;
+
+    loop {
+        let mut progress = false;
+
+        // Drop chunks of lines, from halves down to single lines
+        let mut chunk = lines.len().div_ceil(2);
+        while chunk > 0 {
+            let mut start = 0;
+            while start < lines.len() {
+                let mut candidate = lines.clone();
+                candidate.drain(start..(start + chunk).min(lines.len()));
+
+                if panics(&candidate) {
+                    lines = candidate;
+                    progress = true;
+                } else {
+                    start += chunk;
+                }
+            }
+            chunk /= 2;
+        }
+
+        // Drop chunks of characters of every remaining line
+        for index in 0..lines.len() {
+            let mut chunk = lines[index].chars().count().div_ceil(2);
+            while chunk > 0 {
+                let mut start = 0;
+                while start < lines[index].chars().count() {
+                    let mut chars = lines[index].chars().collect::<Vec<_>>();
+                    chars.drain(start..(start + chunk).min(chars.len()));
+
+                    let mut candidate = lines.clone();
+                    candidate[index] = chars.into_iter().collect();
+
+                    if panics(&candidate) {
+                        lines = candidate;
+                        progress = true;
+                    } else {
+                        start += chunk;
+                    }
+                }
+                chunk /= 2;
+            }
+        }
+
+        if !progress {
+            return lines;
+        }
+    }
+}
+
+// Panic hook wrapping the previous one is installed only once for all threads
+static QUIET_HOOK: Once = Once::new();
+
+thread_local! {
+    // Panics of the current thread are not printed while fuzzing
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+// Clears the flag of the current thread also when the function panics
+struct QuietGuard {
+    previous: bool,
+}
+
+impl Drop for QuietGuard {
+    fn drop(&mut self) {
+        QUIET.with(|quiet| quiet.set(self.previous));
+    }
+}
+
+// Run the function without printing messages of caught panics of the current thread, panics of
+// other threads are reported by the previous hook
+fn quietly<T, F>(func: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    QUIET_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+
+    let _guard = QuietGuard {
+        previous: QUIET.with(|quiet| quiet.replace(true)),
+    };
+
+    func()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Panics if any line contains 'x' followed by a digit
+    fn parse(lines: Vec<String>) -> Result<(), Box<dyn Error>> {
+        for line in &lines {
+            if let Some(index) = line.find('x') {
+                let digit = line[index + 1..].chars().next().ok_or("Missing digit")?;
+                digit.to_digit(10).expect("Not a digit");
+
+                if digit == '0' {
+                    panic!("Zero");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(&parse, &lines(&["x1"])), None);
+        assert_eq!(check(&parse, &lines(&["x"])), None);
+        assert_eq!(check(&parse, &lines(&["x0"])), Some("Zero".to_string()));
+    }
+
+    #[test]
+    fn test_quietly() {
+        assert!(quietly(|| QUIET.with(Cell::get)));
+        assert!(!QUIET.with(Cell::get));
+
+        // Flag is cleared also after a panic, other threads are not affected
+        let caught = panic::catch_unwind(|| quietly(|| panic!("Quiet")));
+        assert!(caught.is_err());
+        assert!(!QUIET.with(Cell::get));
+        assert!(!std::thread::spawn(|| QUIET.with(Cell::get)).join().unwrap());
+    }
+
+    #[test]
+    fn test_shrink() {
+        let input = lines(&["abc", "de x1 x2", "ghi x0 jkl", "mno"]);
+        assert_eq!(shrink(&parse, input), lines(&["x0"]));
+    }
+
+    #[test]
+    fn test_mutate() {
+        let input = lines(&["123 abc", "x5", "", "def 45"]);
+
+        let mut rng = Rng::new(3);
+        let first = (0..100)
+            .map(|_| mutate(&mut rng, &input))
+            .collect::<Vec<_>>();
+
+        let mut rng = Rng::new(3);
+        let second = (0..100)
+            .map(|_| mutate(&mut rng, &input))
+            .collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+        assert!(first.iter().any(|mutated| *mutated != input));
+
+        // Empty input gets a line to mutate
+        mutate(&mut rng, &[]);
+    }
+
+    #[test]
+    fn test_replace_number() {
+        let mut rng = Rng::new(1);
+
+        for _ in 0..20 {
+            let line = replace_number(&mut rng, "ab 12 cd 345");
+            assert!(line.starts_with("ab ") && line.contains(" cd "), "{line}");
+        }
+
+        assert!(replace_number(&mut rng, "abc").starts_with("abc"));
+
+        // Numbers next to multibyte characters
+        let line = replace_number(&mut rng, "é12€");
+        assert!(line.starts_with('é') && line.ends_with('€'), "{line}");
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let corpus = vec![lines(&["x1 x2", "x3"])];
+
+        let failure = fuzz(parse, &corpus, 5, 10_000).unwrap_err();
+        assert!(failure.iteration > 0);
+        assert_eq!(failure.lines.len(), 1);
+        assert!(failure.lines[0].len() <= 2, "{failure}");
+
+        assert_eq!(fuzz(|_| Ok(()), &corpus, 5, 100), Ok(()));
+    }
+}
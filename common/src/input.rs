@@ -0,0 +1,172 @@
+use std::error::Error;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// Environment variable with directory containing day_XX.txt input files
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+// Value used instead of a path to read the input from the standard input
+pub const STDIN: &str = "-";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn from_arg(value: &str) -> Self {
+        match value {
+            STDIN => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read_lines(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        let content = match self {
+            InputSource::File(path) => std::fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read input file '{}' [{err}]", path.display()))?,
+            InputSource::Stdin => {
+                let mut content = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut content)
+                    .map_err(|err| format!("Failed to read input from stdin [{err}]"))?;
+                content
+            }
+        };
+
+        Ok(content.lines().map(String::from).collect())
+    }
+}
+
+// Environment variable overriding input of a single day, e.g. AOC_INPUT_DAY_07
+pub fn day_env(day: usize) -> String {
+    format!("AOC_INPUT_DAY_{day:02}")
+}
+
+pub fn file_name(day: usize) -> String {
+    format!("day_{day:02}.txt")
+}
+
+// Input file stored in the repository, i.e. <workspace>/input/day_XX.txt
+pub fn default_path(day: usize) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../input")
+        .join(file_name(day))
+}
+
+// Resolve input of the day from the environment, falls back to the repository input file
+pub fn resolve(day: usize) -> InputSource {
+    resolve_with(day, |name| std::env::var(name).ok())
+}
+
+// Path of the input file to be used by Puzzle::get_input_file_path(). Standard input has no
+// path, it is kept as '-' which is rejected by require_file() of per-day binaries.
+pub fn file_path(day: usize) -> PathBuf {
+    match resolve(day) {
+        InputSource::File(path) => path,
+        InputSource::Stdin => PathBuf::from(STDIN),
+    }
+}
+
+// Per-day binaries read the input from the file of the puzzle, only the runner reads stdin
+pub fn require_file(day: usize) -> Result<(), Box<dyn Error>> {
+    require_file_of(day, &resolve(day))
+}
+
+fn require_file_of(day: usize, source: &InputSource) -> Result<(), Box<dyn Error>> {
+    match source {
+        InputSource::File(_) => Ok(()),
+        InputSource::Stdin => Err(format!(
+            "Input from stdin ({}={STDIN}) is only supported by the runner",
+            day_env(day)
+        )
+        .into()),
+    }
+}
+
+fn resolve_with<F>(day: usize, lookup: F) -> InputSource
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if let Some(value) = lookup(&day_env(day)) {
+        return InputSource::from_arg(&value);
+    }
+
+    if let Some(dir) = lookup(INPUT_DIR_ENV) {
+        return InputSource::File(Path::new(&dir).join(file_name(day)));
+    }
+
+    InputSource::File(default_path(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn resolve_from(day: usize, vars: &[(&str, &str)]) -> InputSource {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        resolve_with(day, |name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("my.txt"),
+            InputSource::File(PathBuf::from("my.txt"))
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(resolve_from(7, &[]), InputSource::File(default_path(7)));
+
+        assert_eq!(
+            resolve_from(7, &[("AOC_INPUT_DIR", "/inputs")]),
+            InputSource::File(PathBuf::from("/inputs/day_07.txt"))
+        );
+
+        assert_eq!(
+            resolve_from(
+                7,
+                &[("AOC_INPUT_DIR", "/inputs"), ("AOC_INPUT_DAY_07", "a.txt")]
+            ),
+            InputSource::File(PathBuf::from("a.txt"))
+        );
+
+        assert_eq!(
+            resolve_from(7, &[("AOC_INPUT_DAY_07", "-")]),
+            InputSource::Stdin
+        );
+
+        // Override of other day is ignored
+        assert_eq!(
+            resolve_from(7, &[("AOC_INPUT_DAY_08", "-")]),
+            InputSource::File(default_path(7))
+        );
+    }
+
+    #[test]
+    fn test_require_file() {
+        assert!(require_file_of(7, &InputSource::File(default_path(7))).is_ok());
+
+        let err = require_file_of(7, &InputSource::Stdin).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Input from stdin (AOC_INPUT_DAY_07=-) is only supported by the runner"
+        );
+    }
+
+    #[test]
+    fn test_read_lines_missing_file() {
+        let source = InputSource::File(PathBuf::from("/non/existing/day_01.txt"));
+        assert!(source.read_lines().is_err());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod answer;
+pub mod budget;
+pub mod explain;
+pub mod fixtures;
+pub mod fuzz;
+pub mod input;
+pub mod log;
+pub mod parser;
+pub mod random;
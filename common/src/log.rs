@@ -0,0 +1,238 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::{Arguments, Display, Formatter};
+use std::str::FromStr;
+
+thread_local! {
+    static CURRENT: RefCell<Logger> = RefCell::new(Logger::default());
+}
+
+// Severity of a message, every level includes the levels above it
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    #[default]
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name().to_uppercase())
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.name().eq_ignore_ascii_case(value))
+            .ok_or_else(|| {
+                format!("Log level shall be error, warn, info, debug or trace, found '{value}'")
+            })
+    }
+}
+
+// Levels of the days as given on the command line, days without their own level use the
+// default one
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Filter {
+    pub default: Level,
+    pub days: BTreeMap<usize, Level>,
+}
+
+impl Filter {
+    pub fn level(&self, day: usize) -> Level {
+        self.days.get(&day).copied().unwrap_or(self.default)
+    }
+}
+
+// Messages of the current thread up to the level, labelled by the day which wrote them.
+// Captured messages are collected instead of being written to stderr.
+#[derive(Debug, Default, Clone)]
+pub struct Logger {
+    level: Level,
+    day: Option<usize>,
+    captured: Option<Vec<(Level, String)>>,
+}
+
+impl Logger {
+    pub fn new(level: Level) -> Self {
+        Self {
+            level,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_day(mut self, day: usize) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+// Messages of the level are written by the current thread, i.e. they are worth formatting
+pub fn enabled(level: Level) -> bool {
+    CURRENT.with(|current| level <= current.borrow().level)
+}
+
+// Use the log_*! macros instead, they skip formatting of disabled messages
+pub fn write(level: Level, args: Arguments) {
+    CURRENT.with(|current| {
+        let mut current = current.borrow_mut();
+
+        if level > current.level {
+            return;
+        }
+
+        let day = current.day;
+        match &mut current.captured {
+            Some(captured) => captured.push((level, args.to_string())),
+            None => match day {
+                Some(day) => eprintln!("[{level} day {day:02}] {args}"),
+                None => eprintln!("[{level}] {args}"),
+            },
+        }
+    })
+}
+
+// Run the function with the logger installed for the current thread, previous logger is
+// restored afterwards
+pub fn scoped<T, F>(logger: Logger, func: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let previous = CURRENT.with(|current| current.replace(logger));
+    let result = func();
+    CURRENT.with(|current| current.replace(previous));
+
+    result
+}
+
+// Run the function and collect its messages up to the level instead of writing them
+pub fn capture<T, F>(level: Level, func: F) -> (T, Vec<(Level, String)>)
+where
+    F: FnOnce() -> T,
+{
+    let logger = Logger {
+        captured: Some(Vec::new()),
+        ..Logger::new(level)
+    };
+
+    scoped(logger, || {
+        let result = func();
+        let captured = CURRENT.with(|current| current.borrow_mut().captured.take());
+
+        (result, captured.unwrap_or_default())
+    })
+}
+
+#[macro_export]
+macro_rules! log_at {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_warn {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_trace {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture() {
+        let (result, captured) = capture(Level::Debug, || {
+            log_error!("failed {}", 1);
+            log_debug!("step {}", 2);
+            log_trace!("state {}", 3);
+            42
+        });
+
+        assert_eq!(result, 42);
+        assert_eq!(
+            captured,
+            vec![
+                (Level::Error, String::from("failed 1")),
+                (Level::Debug, String::from("step 2"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scoped() {
+        assert!(enabled(Level::Warn));
+        assert!(!enabled(Level::Info));
+
+        let trace = scoped(Logger::new(Level::Trace).with_day(18), || {
+            enabled(Level::Trace)
+        });
+        assert!(trace);
+
+        // Logger is restored
+        assert!(!enabled(Level::Info));
+    }
+
+    #[test]
+    fn test_level() {
+        assert_eq!("TRACE".parse::<Level>(), Ok(Level::Trace));
+        assert_eq!("warn".parse::<Level>(), Ok(Level::Warn));
+        assert!("verbose".parse::<Level>().is_err());
+        assert_eq!(Level::Debug.to_string(), "DEBUG");
+
+        let filter = Filter {
+            default: Level::Info,
+            days: BTreeMap::from([(18, Level::Trace)]),
+        };
+        assert_eq!(filter.level(18), Level::Trace);
+        assert_eq!(filter.level(7), Level::Info);
+    }
+}
@@ -0,0 +1,286 @@
+use std::cmp::Reverse;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use regex::Regex;
+use regex_automata::hybrid::dfa::DFA;
+use regex_automata::{Anchored, Input};
+
+// Single line of the input which does not match expected format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidLine {
+    // 1-based number of the line
+    pub line: usize,
+    // 1-based column of the first character which breaks the pattern, points one character
+    // past the end of the text if the line is too short
+    pub column: usize,
+    pub text: String,
+}
+
+impl Display for InvalidLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+
+        writeln!(
+            f,
+            "Line {} has invalid format at column {}",
+            self.line, self.column
+        )?;
+        writeln!(f, " {number} | {}", self.text)?;
+        write!(f, " {margin} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+// All lines of the input which do not match expected format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    pub lines: Vec<InvalidLine>,
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, line) in self.lines.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            line.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for SyntaxError {}
+
+// Same as puzzler's Parser::parse_lines_with_regex(), but the callback receives 1-based number
+// of the line and lines which do not match the regex are reported as SyntaxError.
+pub fn parse_lines_with_regex<T, E, F>(
+    lines: &[String],
+    pattern: &str,
+    func: F,
+) -> Result<Vec<T>, E>
+where
+    F: Fn(usize, Vec<String>) -> Result<T, E>,
+    E: From<SyntaxError>,
+{
+    parse_lines_with_regex_offset(lines, 0, pattern, func)
+}
+
+// Variant of parse_lines_with_regex() for lines which do not start at the beginning of the
+// input file, e.g. second group of lines. Offset is the number of preceding lines.
+//
+// All lines are checked in a single pass. Lines which do not match the regex take precedence
+// and are reported together, otherwise the first error of the callback is returned.
+pub fn parse_lines_with_regex_offset<T, E, F>(
+    lines: &[String],
+    offset: usize,
+    pattern: &str,
+    func: F,
+) -> Result<Vec<T>, E>
+where
+    F: Fn(usize, Vec<String>) -> Result<T, E>,
+    E: From<SyntaxError>,
+{
+    let regex = Regex::new(pattern)
+        .unwrap_or_else(|err| panic!("Failed to build regex '{pattern}' [{err}]"));
+
+    let mut parsed = Vec::with_capacity(lines.len());
+    let mut invalid = Vec::new();
+    let mut first_error = None;
+
+    for (index, text) in lines.iter().enumerate() {
+        let line = offset + index + 1;
+
+        let Some(captures) = regex.captures(text) else {
+            invalid.push(InvalidLine {
+                line,
+                column: error_column(pattern, text),
+                text: text.clone(),
+            });
+            continue;
+        };
+
+        let params = captures
+            .iter()
+            .skip(1)
+            .map(|param| param.map(|p| p.as_str().to_string()).unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        match func(line, params) {
+            Ok(value) => parsed.push(value),
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+
+    if !invalid.is_empty() {
+        return Err(SyntaxError { lines: invalid }.into());
+    }
+
+    match first_error {
+        Some(err) => Err(err),
+        None => Ok(parsed),
+    }
+}
+
+// Finds 1-based column of the first character which can not be matched by the pattern. Text is
+// fed byte by byte into a lazy DFA until it reaches the dead state. Patterns which are not
+// anchored with '^' may start anywhere, so the start which matches the longest part wins.
+fn error_column(pattern: &str, text: &str) -> usize {
+    let Ok(dfa) = DFA::new(pattern) else {
+        return 1;
+    };
+    let mut cache = dfa.create_cache();
+
+    // Anchored pattern can start only at the beginning of the line
+    let anchored = pattern.starts_with('^');
+
+    let offset = text
+        .char_indices()
+        .map(|(start, _)| start)
+        .filter(|start| !anchored || *start == 0)
+        .map(|start| {
+            let input = Input::new(text).range(start..).anchored(Anchored::Yes);
+
+            let mut state = match dfa.start_state_forward(&mut cache, &input) {
+                Ok(state) if !state.is_dead() => state,
+                _ => return (start, start),
+            };
+
+            for (position, byte) in text.bytes().enumerate().skip(start) {
+                match dfa.next_state(&mut cache, state, byte) {
+                    Ok(next) if !next.is_dead() && !next.is_quit() => state = next,
+                    _ => return (start, position),
+                }
+            }
+
+            (start, text.len())
+        })
+        .max_by_key(|(start, end)| (end - start, Reverse(*start)))
+        .map_or(text.len(), |(_, end)| end);
+
+    // Column one past the end when the whole line is a valid prefix of the pattern
+    if offset >= text.len() {
+        return text.chars().count() + 1;
+    }
+
+    text.char_indices()
+        .take_while(|(start, _)| *start <= offset)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_lines_with_regex() {
+        let parsed: Result<Vec<(usize, String)>, SyntaxError> =
+            parse_lines_with_regex(&lines(&["a=1", "b=2"]), r"^(\w)=(\d)$", |line, params| {
+                Ok((line, params.join(",")))
+            });
+
+        assert_eq!(
+            parsed,
+            Ok(vec![(1, String::from("a,1")), (2, String::from("b,2"))])
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_with_regex_syntax_error() {
+        let parsed: Result<Vec<()>, SyntaxError> = parse_lines_with_regex_offset(
+            &lines(&["a=1", "b=x", "c=3", "d=", "ee=5"]),
+            3,
+            r"^(\w)=(\d)$",
+            |_, _| Ok(()),
+        );
+
+        assert_eq!(
+            parsed,
+            Err(SyntaxError {
+                lines: vec![
+                    InvalidLine {
+                        line: 5,
+                        column: 3,
+                        text: String::from("b=x")
+                    },
+                    InvalidLine {
+                        line: 7,
+                        column: 3,
+                        text: String::from("d=")
+                    },
+                    InvalidLine {
+                        line: 8,
+                        column: 2,
+                        text: String::from("ee=5")
+                    },
+                ]
+            })
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum TestError {
+        Syntax(SyntaxError),
+        Callback(usize),
+    }
+
+    impl From<SyntaxError> for TestError {
+        fn from(err: SyntaxError) -> Self {
+            Self::Syntax(err)
+        }
+    }
+
+    #[test]
+    fn test_parse_lines_with_regex_callback_error() {
+        let callback = |line, _| {
+            if line > 1 {
+                Err(TestError::Callback(line))
+            } else {
+                Ok(())
+            }
+        };
+
+        let parsed =
+            parse_lines_with_regex(&lines(&["a=1", "b=2", "c=3"]), r"^(\w)=(\d)$", callback);
+        assert_eq!(parsed, Err(TestError::Callback(2)));
+
+        // Lines with invalid format take precedence over errors of the callback
+        let parsed = parse_lines_with_regex(&lines(&["a=1", "b=2", "c"]), r"^(\w)=(\d)$", callback);
+        assert!(matches!(parsed, Err(TestError::Syntax(_))));
+    }
+
+    #[test]
+    fn test_error_column() {
+        assert_eq!(error_column(r"^(\d+)x(\d+)x(\d+)$", "1x2"), 4);
+        assert_eq!(error_column(r"^(\d+)x(\d+)x(\d+)$", "1x2y3"), 4);
+        assert_eq!(error_column(r"^(\d+)x(\d+)x(\d+)$", "1x2x3z"), 6);
+        assert_eq!(error_column(r"^turn (on|off)$", "turn onn"), 8);
+        assert_eq!(error_column(r"^é=(\d)$", "é=x"), 3);
+        assert_eq!(
+            error_column(r"row (\d+), column (\d+)\.$", "Enter row 2, column x."),
+            21
+        );
+    }
+
+    #[test]
+    fn test_syntax_error_display() {
+        let err = SyntaxError {
+            lines: vec![InvalidLine {
+                line: 12,
+                column: 4,
+                text: String::from("1x2y3"),
+            }],
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Line 12 has invalid format at column 4\n 12 | 1x2y3\n    |    ^"
+        );
+    }
+}
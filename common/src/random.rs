@@ -0,0 +1,157 @@
+use std::ops::RangeInclusive;
+
+// Consonants and vowels of generated names, e.g. 'Bakemo'
+const CONSONANTS: &[u8] = b"bcdfghjklmnprstvz";
+const VOWELS: &[u8] = b"aeiou";
+
+// Deterministic pseudo random generator (SplitMix64). Same seed produces the same sequence
+// on every platform and in every version, therefore generated inputs can be reproduced.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    }
+
+    // Uniformly distributed number of the range, range shall not be empty
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range {start}..={end}");
+
+        match (end - start).checked_add(1) {
+            Some(span) => start + (self.next_u64() % span as u64) as usize,
+            None => self.next_u64() as usize,
+        }
+    }
+
+    // Signed variant of range()
+    pub fn range_isize(&mut self, range: RangeInclusive<isize>) -> isize {
+        let (start, end) = range.into_inner();
+        let span = end.abs_diff(start);
+
+        start.wrapping_add(self.range(0..=span) as isize)
+    }
+
+    // True with the probability of numerator / denominator
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.range(1..=denominator) <= numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    // Fisher-Yates shuffle
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.range(0..=index));
+        }
+    }
+
+    // Random string of lowercase letters
+    pub fn lowercase(&mut self, length: usize) -> String {
+        (0..length)
+            .map(|_| (b'a' + self.range(0..=25) as u8) as char)
+            .collect()
+    }
+}
+
+// Capitalized name made of syllables, every index gives a different name
+pub fn name(index: usize) -> String {
+    let syllables = CONSONANTS.len() * VOWELS.len();
+
+    let mut name = String::new();
+    let mut index = index;
+
+    loop {
+        let syllable = index % syllables;
+        name.push(CONSONANTS[syllable / VOWELS.len()] as char);
+        name.push(VOWELS[syllable % VOWELS.len()] as char);
+
+        // Names of different lengths can not collide, hence the offset
+        index /= syllables;
+        if index == 0 {
+            break;
+        }
+        index -= 1;
+    }
+
+    let mut chars = name.chars();
+    let first = chars.next().unwrap().to_ascii_uppercase();
+
+    std::iter::once(first).chain(chars).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        let mut rng = Rng::new(42);
+        let sequence = (0..3).map(|_| rng.next_u64()).collect::<Vec<_>>();
+
+        let mut rng = Rng::new(42);
+        assert_eq!(sequence, (0..3).map(|_| rng.next_u64()).collect::<Vec<_>>());
+
+        let mut rng = Rng::new(43);
+        assert_ne!(sequence, (0..3).map(|_| rng.next_u64()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(7);
+
+        let values = (0..1_000).map(|_| rng.range(3..=5)).collect::<HashSet<_>>();
+        assert_eq!(values, HashSet::from([3, 4, 5]));
+
+        assert_eq!(rng.range(8..=8), 8);
+        rng.range(0..=usize::MAX);
+
+        let values = (0..1_000)
+            .map(|_| rng.range_isize(-2..=1))
+            .collect::<HashSet<_>>();
+        assert_eq!(values, HashSet::from([-2, -1, 0, 1]));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(1);
+        let mut items = (0..20).collect::<Vec<_>>();
+
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(name(0), "Ba");
+        assert_eq!(name(1), "Be");
+        assert_eq!(name(85), "Baba");
+
+        let names = (0..10_000).map(name).collect::<HashSet<_>>();
+        assert_eq!(names.len(), 10_000);
+        assert!(
+            names
+                .iter()
+                .all(|name| name.chars().all(char::is_alphabetic))
+        );
+    }
+}
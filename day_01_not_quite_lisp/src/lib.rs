@@ -0,0 +1,11 @@
+// Day 1: Not Quite Lisp. Santa walks floors by instructions, '(' goes one floor up and ')' one
+// floor down. Use Solution::parse_input() to validate instructions and the solver functions
+// of Solution to walk them, FloorTracker to walk streams of any length or ElevatorProgram to
+// trace programs with symbols of their own.
+pub mod puzzle;
+
+pub use puzzle::elevator::{ElevatorProgram, ElevatorTrace, Visit};
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
+pub use puzzle::tracker::{Extreme, FloorReport, FloorTracker};
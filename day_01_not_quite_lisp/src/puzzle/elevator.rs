@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+
+use super::error::PuzzleError;
+
+// Single instruction of the program at its character position (1-based) and the floor the
+// elevator moved to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visit {
+    pub position: usize,
+    pub symbol: char,
+    pub floor: isize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ElevatorTrace {
+    pub visits: Vec<Visit>,
+    // Positions of all instructions which moved the elevator to the target floor
+    pub targets: BTreeMap<isize, Vec<usize>>,
+}
+
+impl ElevatorTrace {
+    // Elevator starts at the ground floor
+    pub fn final_floor(&self) -> isize {
+        self.visits.last().map_or(0, |visit| visit.floor)
+    }
+
+    pub fn first_reached(&self, target: isize) -> Option<usize> {
+        self.targets
+            .get(&target)
+            .and_then(|positions| positions.first().copied())
+    }
+}
+
+// Interpreter of elevator programs, every symbol moves the elevator by its own delta, e.g.
+// '[' ten floors up and ']' ten floors down. Whitespace is skipped but counted in positions of
+// next instructions like by FloorTracker.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ElevatorProgram {
+    deltas: BTreeMap<char, isize>,
+    targets: Vec<isize>,
+}
+
+impl ElevatorProgram {
+    // Program without symbols nor targets
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Program of the puzzle, i.e. '(' one floor up, ')' one floor down and basement as target
+    pub fn santa() -> Self {
+        Self {
+            deltas: BTreeMap::from([('(', 1), (')', -1)]),
+            targets: vec![-1],
+        }
+    }
+
+    pub fn with_symbol(mut self, symbol: char, delta: isize) -> Result<Self, PuzzleError> {
+        if symbol.is_whitespace() || self.deltas.contains_key(&symbol) {
+            return Err(PuzzleError::InvalidSymbol { symbol });
+        }
+
+        self.deltas.insert(symbol, delta);
+        Ok(self)
+    }
+
+    // Pair of symbols moving the elevator by the delta up and down
+    pub fn with_pair(self, up: char, down: char, delta: isize) -> Result<Self, PuzzleError> {
+        let opposite = delta
+            .checked_neg()
+            .ok_or(PuzzleError::InvalidDelta { delta })?;
+
+        self.with_symbol(up, delta)?.with_symbol(down, opposite)
+    }
+
+    pub fn with_target(mut self, floor: isize) -> Result<Self, PuzzleError> {
+        if self.targets.contains(&floor) {
+            return Err(PuzzleError::DuplicateTarget { floor });
+        }
+
+        self.targets.push(floor);
+        Ok(self)
+    }
+
+    // Trace every instruction, starting floor is not a visit of the target
+    pub fn run(&self, instructions: &str) -> Result<ElevatorTrace, PuzzleError> {
+        let mut trace = ElevatorTrace {
+            visits: Vec::new(),
+            targets: self
+                .targets
+                .iter()
+                .map(|target| (*target, Vec::new()))
+                .collect(),
+        };
+
+        let mut floor: isize = 0;
+        let (mut line, mut column) = (1, 0);
+
+        for (index, symbol) in instructions.chars().enumerate() {
+            let position = index + 1;
+            column += 1;
+
+            if symbol == '\n' {
+                line += 1;
+                column = 0;
+                continue;
+            }
+            if symbol.is_whitespace() {
+                continue;
+            }
+
+            let delta = self
+                .deltas
+                .get(&symbol)
+                .ok_or(PuzzleError::InvalidInstruction {
+                    line,
+                    column,
+                    found: symbol,
+                })?;
+
+            floor = floor
+                .checked_add(*delta)
+                .ok_or(PuzzleError::FloorOverflow { position })?;
+
+            trace.visits.push(Visit {
+                position,
+                symbol,
+                floor,
+            });
+
+            if let Some(positions) = trace.targets.get_mut(&floor) {
+                positions.push(position);
+            }
+        }
+
+        Ok(trace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::solution::Solution;
+    use crate::puzzle::tracker::FloorTracker;
+
+    use super::*;
+
+    #[test]
+    fn test_santa() {
+        let program = ElevatorProgram::santa();
+
+        for instructions in ["(())", "))(((((", ")())())", "()())", "() ())", "(\r\n)) ("] {
+            let trace = program.run(instructions).unwrap();
+
+            assert_eq!(
+                Ok(trace.final_floor()),
+                Solution::calculate_floor(instructions)
+            );
+            assert_eq!(
+                trace.first_reached(-1),
+                Solution::find_floor_to_enter_basement(instructions).ok()
+            );
+
+            // Whitespace is counted in positions the same way
+            let report = FloorTracker::track(instructions.as_bytes()).unwrap();
+            assert_eq!(trace.first_reached(-1), report.basement);
+            assert_eq!(trace.final_floor(), report.floor);
+        }
+
+        let trace = program.run("() ())").unwrap();
+        assert_eq!(trace.first_reached(-1), Some(6));
+    }
+
+    #[test]
+    fn test_run() {
+        let program = ElevatorProgram::santa()
+            .with_pair('[', ']', 10)
+            .and_then(|program| program.with_target(10))
+            .unwrap();
+
+        let trace = program.run("[)(\n]) ()[").unwrap();
+
+        let floors = trace
+            .visits
+            .iter()
+            .map(|visit| visit.floor)
+            .collect::<Vec<_>>();
+        assert_eq!(floors, vec![10, 9, 10, 0, -1, 0, -1, 9]);
+        assert_eq!(
+            trace.visits[3],
+            Visit {
+                position: 5,
+                symbol: ']',
+                floor: 0
+            }
+        );
+
+        assert_eq!(trace.targets[&10], vec![1, 3]);
+        assert_eq!(trace.targets[&-1], vec![6, 9]);
+        assert_eq!(trace.first_reached(-1), Some(6));
+        assert_eq!(trace.first_reached(3), None);
+        assert_eq!(trace.final_floor(), 9);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            ElevatorProgram::santa().run("()\n(["),
+            Err(PuzzleError::InvalidInstruction {
+                line: 2,
+                column: 2,
+                found: '['
+            })
+        );
+        assert_eq!(
+            ElevatorProgram::santa().with_symbol('(', 5),
+            Err(PuzzleError::InvalidSymbol { symbol: '(' })
+        );
+        assert_eq!(
+            ElevatorProgram::new().with_symbol(' ', 1),
+            Err(PuzzleError::InvalidSymbol { symbol: ' ' })
+        );
+        assert_eq!(
+            ElevatorProgram::santa().with_target(-1),
+            Err(PuzzleError::DuplicateTarget { floor: -1 })
+        );
+        assert_eq!(
+            ElevatorProgram::new().with_pair('[', ']', isize::MIN),
+            Err(PuzzleError::InvalidDelta { delta: isize::MIN })
+        );
+    }
+
+    #[test]
+    fn test_floor_overflow() {
+        let program = ElevatorProgram::santa()
+            .with_pair('[', ']', isize::MAX)
+            .unwrap();
+
+        assert_eq!(program.run("[)").unwrap().final_floor(), isize::MAX - 1);
+        assert_eq!(
+            program.run("[ (("),
+            Err(PuzzleError::FloorOverflow { position: 3 })
+        );
+        assert_eq!(
+            program.run("]]"),
+            Err(PuzzleError::FloorOverflow { position: 2 })
+        );
+    }
+}
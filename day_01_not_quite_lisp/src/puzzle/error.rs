@@ -0,0 +1,67 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    LineCount {
+        found: usize,
+    },
+    InvalidInstruction {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    BasementNotReached,
+    ReadFailed {
+        reason: String,
+    },
+    InvalidSymbol {
+        symbol: char,
+    },
+    DuplicateTarget {
+        floor: isize,
+    },
+    FloorOverflow {
+        position: usize,
+    },
+    InvalidDelta {
+        delta: isize,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::LineCount { found } => write!(
+                f,
+                "Exactly one line is expected in input file, but '{found}' found"
+            ),
+            PuzzleError::InvalidInstruction {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "Line {line}, column {column}: unexpected instruction '{found}'"
+            ),
+            PuzzleError::BasementNotReached => write!(f, "Floor number not found"),
+            PuzzleError::ReadFailed { reason } => {
+                write!(f, "Failed to read instructions [{reason}]")
+            }
+            PuzzleError::InvalidSymbol { symbol } => {
+                write!(f, "Symbol '{symbol}' is whitespace or already defined")
+            }
+            PuzzleError::DuplicateTarget { floor } => {
+                write!(f, "Target floor '{floor}' is already defined")
+            }
+            PuzzleError::FloorOverflow { position } => {
+                write!(f, "Instruction {position} moves the elevator out of floors")
+            }
+            PuzzleError::InvalidDelta { delta } => {
+                write!(f, "Delta '{delta}' has no opposite delta for the pair")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
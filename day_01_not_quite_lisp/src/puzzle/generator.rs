@@ -0,0 +1,28 @@
+use common::random::Rng;
+
+// Single line of '(' and ')', size is the number of instructions
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    let instructions = (0..size)
+        .map(|_| if rng.chance(1, 2) { '(' } else { ')' })
+        .collect();
+
+    vec![instructions]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 100);
+        assert_eq!(lines, generate(7, 100));
+        assert_ne!(lines, generate(8, 100));
+
+        let instructions = Solution::parse_input(lines).unwrap();
+        assert_eq!(instructions.len(), 100);
+        assert!(Solution::calculate_floor(&instructions).is_ok());
+    }
+}
@@ -1 +1,5 @@
+pub mod elevator;
+pub mod error;
+pub mod generator;
 pub mod solution;
+pub mod tracker;
@@ -1,6 +1,11 @@
 use std::{error::Error, path::PathBuf};
 
-use puzzler::{env::project, puzzler::puzzle::Puzzle};
+use common::explain::Explain;
+use common::input;
+use puzzler::puzzler::puzzle::Puzzle;
+
+use super::error::PuzzleError;
+use super::tracker::FloorTracker;
 
 pub struct Solution {
     instructions: String,
@@ -12,39 +17,35 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<PathBuf> {
-        Some(
-            project::get_project_file("../input/day_01.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_01.txt [{err}]")),
-        )
+        Some(input::file_path(1))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        if lines.len() != 1 {
-            return Err(format!(
-                "Exactly one line is expected in input file, but '{}' found",
-                lines.len()
-            )
-            .into());
-        }
-
-        self.instructions = lines.into_iter().next().unwrap();
+        self.instructions = Self::parse_input(lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let floors = Self::calculate_floor(&self.instructions);
+        let floors = Self::calculate_floor(&self.instructions)?;
 
         Ok(floors.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let floor_number = Self::find_floor_to_enter_basement(&self.instructions)
-            .ok_or_else(|| -> Box<dyn Error> { "Floor number not found".into() })?;
+        let floor_number = Self::find_floor_to_enter_basement(&self.instructions)?;
 
         Ok(floor_number.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -52,84 +53,105 @@ impl Solution {
         }
     }
 
-    fn calculate_floor(instructions: &str) -> isize {
-        // Loop only once
-        let mut up = 0;
-        let mut down = 0;
-
-        for stair in instructions.bytes() {
-            match stair {
-                b'(' => up += 1,
-                b')' => down += 1,
-                x => panic!("Unxpected instruction '{x}'"),
-            }
+    // Instructions are a single line of parentheses
+    pub fn parse_input(lines: Vec<String>) -> Result<String, PuzzleError> {
+        if lines.len() != 1 {
+            return Err(PuzzleError::LineCount { found: lines.len() });
         }
 
-        up - down
-    }
-
-    fn find_floor_to_enter_basement(instructions: &str) -> Option<usize> {
-        // Loop only once
-        let mut delta = 0;
+        // Reject unknown instructions early
+        let instructions = lines.into_iter().next().unwrap();
+        Self::calculate_floor(&instructions)?;
 
-        for (i, stair) in instructions.bytes().enumerate() {
-            match stair {
-                b'(' => delta += 1,
-                b')' => delta -= 1,
-                x => panic!("Unxpected instruction '{x}'"),
-            }
+        Ok(instructions)
+    }
 
-            if delta < 0 {
-                return Some(i + 1);
-            }
-        }
+    // Floor where Santa ends, '(' goes one floor up and ')' one floor down
+    pub fn calculate_floor(instructions: &str) -> Result<isize, PuzzleError> {
+        FloorTracker::track(instructions.as_bytes()).map(|report| report.floor)
+    }
 
-        None
+    // Position (1-based) of the character which enters the basement first, whitespace is
+    // skipped but counted
+    pub fn find_floor_to_enter_basement(instructions: &str) -> Result<usize, PuzzleError> {
+        FloorTracker::track(instructions.as_bytes())?
+            .basement
+            .ok_or(PuzzleError::BasementNotReached)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
+    use crate::puzzle::error::PuzzleError;
     use crate::puzzle::solution::Solution;
 
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "280");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "280");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "1797");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "1797");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
     fn test_calculate_floor() {
-        assert_eq!(Solution::calculate_floor("(())"), 0);
-        assert_eq!(Solution::calculate_floor("()()"), 0);
-        assert_eq!(Solution::calculate_floor("((("), 3);
-        assert_eq!(Solution::calculate_floor("(()(()("), 3);
-        assert_eq!(Solution::calculate_floor("))((((("), 3);
-        assert_eq!(Solution::calculate_floor("())"), -1);
-        assert_eq!(Solution::calculate_floor("))("), -1);
-        assert_eq!(Solution::calculate_floor(")))"), -3);
-        assert_eq!(Solution::calculate_floor(")())())"), -3);
+        assert_eq!(Solution::calculate_floor("(())"), Ok(0));
+        assert_eq!(Solution::calculate_floor("()()"), Ok(0));
+        assert_eq!(Solution::calculate_floor("((("), Ok(3));
+        assert_eq!(Solution::calculate_floor("(()(()("), Ok(3));
+        assert_eq!(Solution::calculate_floor("))((((("), Ok(3));
+        assert_eq!(Solution::calculate_floor("())"), Ok(-1));
+        assert_eq!(Solution::calculate_floor("))("), Ok(-1));
+        assert_eq!(Solution::calculate_floor(")))"), Ok(-3));
+        assert_eq!(Solution::calculate_floor(")())())"), Ok(-3));
+        assert_eq!(Solution::calculate_floor("(()\n) (\r"), Ok(1));
     }
 
     #[test]
     fn find_floor_to_enter_basement() {
-        assert_eq!(Solution::find_floor_to_enter_basement(")"), Some(1));
-        assert_eq!(Solution::find_floor_to_enter_basement("()())"), Some(5));
+        assert_eq!(Solution::find_floor_to_enter_basement(")"), Ok(1));
+        assert_eq!(Solution::find_floor_to_enter_basement("()())"), Ok(5));
+        assert_eq!(Solution::find_floor_to_enter_basement("() ())"), Ok(6));
+        assert_eq!(
+            Solution::find_floor_to_enter_basement("(()"),
+            Err(PuzzleError::BasementNotReached)
+        );
+    }
+
+    #[test]
+    fn test_invalid_instruction() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![String::from("(()x")])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidInstruction {
+                line: 1,
+                column: 4,
+                found: 'x'
+            })
+        );
     }
 }
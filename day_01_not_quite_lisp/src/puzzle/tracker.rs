@@ -0,0 +1,227 @@
+use std::io::{ErrorKind, Read};
+
+use super::error::PuzzleError;
+
+// Instructions are read by chunks of this size, the stream is never held in memory
+const CHUNK_SIZE: usize = 64 * 1024;
+
+// Floor reached by the instruction at the position, position 0 is the start. Positions are
+// 1-based character positions in the stream, skipped whitespace included.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Extreme {
+    pub floor: isize,
+    pub position: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FloorReport {
+    pub floor: isize,
+    // Position of the instruction which enters the basement first
+    pub basement: Option<usize>,
+    // Lowest and highest floors, reached first at their positions
+    pub min: Extreme,
+    pub max: Extreme,
+    // Number of instructions, whitespace is not an instruction
+    pub instructions: usize,
+}
+
+// Walks the floors by a stream of instructions fed in chunks of any size. Whitespace, e.g.
+// new lines of wrapped streams, is skipped and does not count as an instruction, but it
+// still moves the position of next instructions.
+#[derive(Debug, Clone)]
+pub struct FloorTracker {
+    report: FloorReport,
+    position: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Default for FloorTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FloorTracker {
+    pub fn new() -> Self {
+        Self {
+            report: FloorReport::default(),
+            position: 0,
+            line: 1,
+            column: 0,
+        }
+    }
+
+    // Walk the whole stream and report it
+    pub fn track<R: Read>(reader: R) -> Result<FloorReport, PuzzleError> {
+        let mut tracker = Self::new();
+        tracker.read(reader)?;
+
+        Ok(tracker.report())
+    }
+
+    // Consume the reader until its end
+    pub fn read<R: Read>(&mut self, mut reader: R) -> Result<(), PuzzleError> {
+        let mut buffer = vec![0; CHUNK_SIZE];
+
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => return Ok(()),
+                Ok(count) => self.feed(&buffer[..count])?,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    return Err(PuzzleError::ReadFailed {
+                        reason: err.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    // Consume next chunk of the stream, chunk may end anywhere. Every byte is a single
+    // character since the stream is rejected at the first non-ASCII byte.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), PuzzleError> {
+        for &byte in chunk {
+            self.position += 1;
+            self.column += 1;
+
+            let delta = match byte {
+                b'(' => 1,
+                b')' => -1,
+                b'\n' => {
+                    self.line += 1;
+                    self.column = 0;
+                    continue;
+                }
+                _ if byte.is_ascii_whitespace() => continue,
+                _ => {
+                    return Err(PuzzleError::InvalidInstruction {
+                        line: self.line,
+                        column: self.column,
+                        // Byte of a multi-byte character is not a character on its own
+                        found: match byte.is_ascii() {
+                            true => char::from(byte),
+                            false => char::REPLACEMENT_CHARACTER,
+                        },
+                    });
+                }
+            };
+
+            let report = &mut self.report;
+            report.instructions += 1;
+            report.floor += delta;
+
+            let reached = Extreme {
+                floor: report.floor,
+                position: self.position,
+            };
+
+            if report.floor < report.min.floor {
+                report.min = reached;
+            }
+            if report.floor > report.max.floor {
+                report.max = reached;
+            }
+            if report.floor < 0 && report.basement.is_none() {
+                report.basement = Some(self.position);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Report of the instructions consumed so far
+    pub fn report(&self) -> FloorReport {
+        self.report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_track() {
+        let report = FloorTracker::track(Cursor::new("(()\n)))\r\n ((((\n")).unwrap();
+
+        assert_eq!(
+            report,
+            FloorReport {
+                floor: 2,
+                basement: Some(6),
+                min: Extreme {
+                    floor: -2,
+                    position: 7
+                },
+                max: Extreme {
+                    floor: 2,
+                    position: 2
+                },
+                instructions: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn test_positions() {
+        // Whitespace moves the positions, instructions do not count it
+        let report = FloorTracker::track(Cursor::new(
+            "( )
+)",
+        ))
+        .unwrap();
+
+        assert_eq!(report.basement, Some(5));
+        assert_eq!(report.instructions, 3);
+        assert_eq!(
+            report.min,
+            Extreme {
+                floor: -1,
+                position: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_feed_chunks() {
+        let instructions = "((())))()((())".repeat(1000);
+        let expected = FloorTracker::track(instructions.as_bytes()).unwrap();
+
+        // Chunk boundaries do not matter
+        for size in [1, 7, 4096] {
+            let mut tracker = FloorTracker::new();
+            for chunk in instructions.as_bytes().chunks(size) {
+                tracker.feed(chunk).unwrap();
+            }
+            assert_eq!(tracker.report(), expected);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let report = FloorTracker::track(Cursor::new(" \n")).unwrap();
+        assert_eq!(report, FloorReport::default());
+    }
+
+    #[test]
+    fn test_invalid_instruction() {
+        assert_eq!(
+            FloorTracker::track(Cursor::new("((\n()x")),
+            Err(PuzzleError::InvalidInstruction {
+                line: 2,
+                column: 3,
+                found: 'x'
+            })
+        );
+        assert_eq!(
+            FloorTracker::track(Cursor::new("(é")),
+            Err(PuzzleError::InvalidInstruction {
+                line: 1,
+                column: 2,
+                found: char::REPLACEMENT_CHARACTER
+            })
+        );
+    }
+}
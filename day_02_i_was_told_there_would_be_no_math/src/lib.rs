@@ -0,0 +1,11 @@
+// Day 2: I Was Told There Would Be No Math. Presents are boxes given by their dimensions, the
+// solver functions of Solution calculate wrapping paper and ribbon of a single present and
+// packing::pack() packs presents into cartons. OrderSheet prices the materials of all presents.
+pub mod puzzle;
+
+pub use puzzle::dimension::Dimensions;
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::order::{OrderLine, OrderSheet, PriceTable};
+pub use puzzle::packing::{Carton, PackingPlan, Placement, Position};
+pub use puzzle::solution::Solution;
@@ -1,5 +1,15 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Dimensions {
-    pub(crate) length: usize,
-    pub(crate) width: usize,
-    pub(crate) height: usize,
+    pub length: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+// Format of the input, e.g. '2x3x4'
+impl Display for Dimensions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}x{}", self.length, self.width, self.height)
+    }
 }
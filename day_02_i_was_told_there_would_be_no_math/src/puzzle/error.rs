@@ -0,0 +1,69 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::parser::SyntaxError;
+
+use crate::puzzle::dimension::Dimensions;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    Syntax(SyntaxError),
+    InvalidNumber {
+        line: usize,
+        field: &'static str,
+        text: String,
+    },
+    InvalidPrice {
+        field: &'static str,
+    },
+    EmptyCarton {
+        carton: Dimensions,
+    },
+    PresentTooLarge {
+        line: usize,
+        present: Dimensions,
+    },
+    CartonTooLarge {
+        carton: Dimensions,
+    },
+    DimensionsOverflow {
+        line: usize,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::InvalidNumber { line, field, text } => {
+                write!(f, "Line {line}: failed to parse '{field}' from '{text}'")
+            }
+            PuzzleError::InvalidPrice { field } => {
+                write!(f, "Price table has invalid '{field}'")
+            }
+            PuzzleError::EmptyCarton { carton } => {
+                write!(f, "Carton {carton} has no volume")
+            }
+            PuzzleError::PresentTooLarge { line, present } => {
+                write!(
+                    f,
+                    "Line {line}: present {present} does not fit into the carton"
+                )
+            }
+            PuzzleError::CartonTooLarge { carton } => {
+                write!(f, "Carton {carton} has too large volume")
+            }
+            PuzzleError::DimensionsOverflow { line } => {
+                write!(f, "Line {line}: dimensions of the present are too large")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
@@ -0,0 +1,28 @@
+use common::random::Rng;
+
+// Presents of random dimensions, size is the number of presents
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    (0..size)
+        .map(|_| {
+            let [length, width, height] = [(); 3].map(|_| rng.range(1..=30));
+            format!("{length}x{width}x{height}")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 50);
+        assert_eq!(lines, generate(7, 50));
+        assert_ne!(lines, generate(8, 50));
+
+        let dimensions = Solution::parse_input(&lines).unwrap();
+        assert_eq!(dimensions.len(), 50);
+    }
+}
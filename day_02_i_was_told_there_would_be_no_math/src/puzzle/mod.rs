@@ -1,2 +1,6 @@
 pub mod dimension;
+pub mod error;
+pub mod generator;
+pub mod order;
+pub mod packing;
 pub mod solution;
@@ -0,0 +1,273 @@
+use crate::puzzle::dimension::Dimensions;
+use crate::puzzle::error::PuzzleError;
+use crate::puzzle::solution::Solution;
+
+const CSV_HEADER: [&str; 8] = [
+    "line",
+    "dimensions",
+    "paper",
+    "slack",
+    "wrap",
+    "bow",
+    "ribbon",
+    "price",
+];
+
+// Prices of materials, dimensions of presents are in feet. Paper and ribbon are bought in
+// rolls, wastage is the share of every roll which can not be used, e.g. 0.05 for offcuts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceTable {
+    pub paper_per_square_foot: f64,
+    pub ribbon_per_foot: f64,
+    pub paper_roll: usize,
+    pub ribbon_roll: usize,
+    pub wastage: f64,
+}
+
+impl Default for PriceTable {
+    fn default() -> Self {
+        Self {
+            paper_per_square_foot: 0.05,
+            ribbon_per_foot: 0.1,
+            paper_roll: 100,
+            ribbon_roll: 50,
+            wastage: 0.05,
+        }
+    }
+}
+
+impl PriceTable {
+    fn validate(&self) -> Result<(), PuzzleError> {
+        let invalid = |field| Err(PuzzleError::InvalidPrice { field });
+
+        if !(self.paper_per_square_foot.is_finite() && self.paper_per_square_foot >= 0.0) {
+            return invalid("paper_per_square_foot");
+        }
+        if !(self.ribbon_per_foot.is_finite() && self.ribbon_per_foot >= 0.0) {
+            return invalid("ribbon_per_foot");
+        }
+        if self.paper_roll == 0 {
+            return invalid("paper_roll");
+        }
+        if self.ribbon_roll == 0 {
+            return invalid("ribbon_roll");
+        }
+        if !(0.0..1.0).contains(&self.wastage) {
+            return invalid("wastage");
+        }
+
+        Ok(())
+    }
+
+    // Whole rolls covering the length, only the part of the roll without wastage is usable
+    fn rolls(&self, roll: usize, length: usize) -> usize {
+        (length as f64 / (roll as f64 * (1.0 - self.wastage))).ceil() as usize
+    }
+}
+
+// Materials of a single present. Paper is the surface plus slack, i.e. the smallest side,
+// ribbon is the wrap around the smallest perimeter plus the bow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrderLine {
+    pub line: usize,
+    pub dimensions: Dimensions,
+    pub paper: usize,
+    pub slack: usize,
+    pub wrap: usize,
+    pub bow: usize,
+    pub price: f64,
+}
+
+impl OrderLine {
+    pub fn ribbon(&self) -> usize {
+        self.wrap + self.bow
+    }
+}
+
+// Bill of materials of all presents, lines are in the order of the input
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderSheet {
+    pub prices: PriceTable,
+    pub lines: Vec<OrderLine>,
+}
+
+impl OrderSheet {
+    pub fn new(presents: &[Dimensions], prices: PriceTable) -> Result<Self, PuzzleError> {
+        prices.validate()?;
+
+        let mut lines = Vec::with_capacity(presents.len());
+        let (mut paper_total, mut ribbon_total) = (0usize, 0usize);
+
+        for (index, dimensions) in presents.iter().enumerate() {
+            let Dimensions {
+                length,
+                width,
+                height,
+            } = *dimensions;
+            let overflow = PuzzleError::DimensionsOverflow { line: index + 1 };
+
+            // Sides and bow are never larger than the paper and the ribbon
+            let paper = Solution::calculate_area(dimensions).ok_or(overflow.clone())?;
+            let ribbon = Solution::calculate_ribbon(dimensions).ok_or(overflow.clone())?;
+            let bow = length * width * height;
+
+            // Totals of the sheet fit into usize as well
+            paper_total = paper_total.checked_add(paper).ok_or(overflow.clone())?;
+            ribbon_total = ribbon_total.checked_add(ribbon).ok_or(overflow)?;
+
+            lines.push(OrderLine {
+                line: index + 1,
+                dimensions: *dimensions,
+                paper,
+                slack: paper - 2 * (length * width + width * height + height * length),
+                wrap: ribbon - bow,
+                bow,
+                price: paper as f64 * prices.paper_per_square_foot
+                    + ribbon as f64 * prices.ribbon_per_foot,
+            });
+        }
+
+        Ok(Self { prices, lines })
+    }
+
+    pub fn paper(&self) -> usize {
+        self.lines.iter().map(|line| line.paper).sum()
+    }
+
+    pub fn ribbon(&self) -> usize {
+        self.lines.iter().map(OrderLine::ribbon).sum()
+    }
+
+    pub fn price(&self) -> f64 {
+        self.lines.iter().map(|line| line.price).sum()
+    }
+
+    pub fn paper_rolls(&self) -> usize {
+        self.prices.rolls(self.prices.paper_roll, self.paper())
+    }
+
+    pub fn ribbon_rolls(&self) -> usize {
+        self.prices.rolls(self.prices.ribbon_roll, self.ribbon())
+    }
+
+    // Single row per present with a header, prices are rounded to cents
+    pub fn to_csv(&self) -> String {
+        let rows = self.lines.iter().map(|line| {
+            [
+                line.line.to_string(),
+                line.dimensions.to_string(),
+                line.paper.to_string(),
+                line.slack.to_string(),
+                line.wrap.to_string(),
+                line.bow.to_string(),
+                line.ribbon().to_string(),
+                format!("{:.2}", line.price),
+            ]
+            .join(",")
+        });
+
+        std::iter::once(CSV_HEADER.join(","))
+            .chain(rows)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn presents() -> Vec<Dimensions> {
+        Solution::parse_input(&[String::from("2x3x4"), String::from("1x1x10")]).unwrap()
+    }
+
+    fn prices() -> PriceTable {
+        PriceTable {
+            paper_per_square_foot: 0.5,
+            ribbon_per_foot: 0.25,
+            paper_roll: 50,
+            ribbon_roll: 20,
+            wastage: 0.2,
+        }
+    }
+
+    #[test]
+    fn test_order_sheet() {
+        let sheet = OrderSheet::new(&presents(), prices()).unwrap();
+
+        assert_eq!(
+            sheet.lines[0],
+            OrderLine {
+                line: 1,
+                dimensions: presents()[0],
+                paper: 58,
+                slack: 6,
+                wrap: 10,
+                bow: 24,
+                price: 37.5,
+            }
+        );
+        assert_eq!(sheet.lines[1].slack, 1);
+        assert_eq!(sheet.lines[1].ribbon(), 14);
+
+        assert_eq!(sheet.paper(), 101);
+        assert_eq!(sheet.ribbon(), 48);
+        assert_eq!(sheet.price(), 62.5);
+
+        // 40 square feet and 16 feet of every roll are usable
+        assert_eq!(sheet.paper_rolls(), 3);
+        assert_eq!(sheet.ribbon_rolls(), 3);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let sheet = OrderSheet::new(&presents(), prices()).unwrap();
+
+        let expected = [
+            "line,dimensions,paper,slack,wrap,bow,ribbon,price",
+            "1,2x3x4,58,6,10,24,34,37.50",
+            "2,1x1x10,43,1,4,10,14,25.00",
+        ];
+        assert_eq!(sheet.to_csv(), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_invalid_prices() {
+        let table = PriceTable {
+            wastage: 1.0,
+            ..prices()
+        };
+        assert_eq!(
+            OrderSheet::new(&presents(), table),
+            Err(PuzzleError::InvalidPrice { field: "wastage" })
+        );
+
+        let table = PriceTable {
+            ribbon_roll: 0,
+            ..prices()
+        };
+        assert_eq!(
+            OrderSheet::new(&presents(), table),
+            Err(PuzzleError::InvalidPrice {
+                field: "ribbon_roll"
+            })
+        );
+
+        assert!(OrderSheet::new(&[], PriceTable::default()).is_ok());
+    }
+
+    #[test]
+    fn test_dimensions_overflow() {
+        let mut presents = presents();
+        presents.push(Dimensions {
+            length: usize::MAX,
+            width: 1,
+            height: 1,
+        });
+
+        assert_eq!(
+            OrderSheet::new(&presents, prices()),
+            Err(PuzzleError::DimensionsOverflow { line: 3 })
+        );
+    }
+}
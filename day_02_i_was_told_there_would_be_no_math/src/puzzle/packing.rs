@@ -0,0 +1,327 @@
+use crate::puzzle::dimension::Dimensions;
+use crate::puzzle::error::PuzzleError;
+
+// Corner of a placed present nearest to the origin of the carton, x runs along the length,
+// y along the width and z along the height
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+// Present given by its index in the list, size is the present rotated as placed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub present: usize,
+    pub position: Position,
+    pub size: Dimensions,
+}
+
+impl Placement {
+    fn overlaps(&self, position: &Position, size: &Dimensions) -> bool {
+        let apart =
+            |a: usize, a_len: usize, b: usize, b_len: usize| a + a_len <= b || b + b_len <= a;
+
+        !(apart(self.position.x, self.size.length, position.x, size.length)
+            || apart(self.position.y, self.size.width, position.y, size.width)
+            || apart(self.position.z, self.size.height, position.z, size.height))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Carton {
+    pub placements: Vec<Placement>,
+    // Candidate positions of next presents, i.e. corners of placed presents
+    corners: Vec<Position>,
+}
+
+impl Carton {
+    fn new() -> Self {
+        Self {
+            placements: Vec::new(),
+            corners: vec![Position { x: 0, y: 0, z: 0 }],
+        }
+    }
+
+    pub fn volume(&self) -> usize {
+        self.placements
+            .iter()
+            .filter_map(|placement| volume(&placement.size))
+            .sum()
+    }
+
+    // Place the present at the lowest free corner in any rotation, false if it does not fit
+    fn place(&mut self, present: usize, size: &Dimensions, carton: &Dimensions) -> bool {
+        for corner in self.corners.clone() {
+            for rotation in rotations(size) {
+                // Corners are always inside the carton
+                let fits = rotation.length <= carton.length - corner.x
+                    && rotation.width <= carton.width - corner.y
+                    && rotation.height <= carton.height - corner.z
+                    && !self
+                        .placements
+                        .iter()
+                        .any(|placement| placement.overlaps(&corner, &rotation));
+
+                if fits {
+                    self.add(Placement {
+                        present,
+                        position: corner,
+                        size: rotation,
+                    });
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    fn add(&mut self, placement: Placement) {
+        let Placement { position, size, .. } = placement;
+
+        self.corners.retain(|corner| *corner != position);
+        self.corners.extend([
+            Position {
+                x: position.x + size.length,
+                ..position
+            },
+            Position {
+                y: position.y + size.width,
+                ..position
+            },
+            Position {
+                z: position.z + size.height,
+                ..position
+            },
+        ]);
+
+        // Fill the carton from the bottom, back and left
+        self.corners
+            .sort_by_key(|corner| (corner.z, corner.y, corner.x));
+        self.corners.dedup();
+
+        self.placements.push(placement);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackingPlan {
+    pub carton: Dimensions,
+    pub cartons: Vec<Carton>,
+}
+
+impl PackingPlan {
+    // Share of the carton volume filled by presents, from 0.0 to 1.0
+    pub fn fill_ratio(&self, carton: &Carton) -> f64 {
+        let Dimensions {
+            length,
+            width,
+            height,
+        } = self.carton;
+
+        carton.volume() as f64 / (length as f64 * width as f64 * height as f64)
+    }
+
+    // No packing needs fewer cartons than the volume of all presents
+    pub fn lower_bound(&self) -> usize {
+        let presents = self
+            .cartons
+            .iter()
+            .map(|carton| carton.volume() as u128)
+            .sum::<u128>();
+
+        volume(&self.carton).map_or(1, |carton| presents.div_ceil(carton as u128) as usize)
+    }
+}
+
+// Pack presents into cartons of the same size by first fit decreasing, i.e. the largest
+// presents go first, each into the first carton with a free corner it fits in. Packing is a
+// heuristic, the number of cartons is not always the minimum.
+pub fn pack(carton: Dimensions, presents: &[Dimensions]) -> Result<PackingPlan, PuzzleError> {
+    // Volumes of placed presents never exceed the volume of the carton
+    match volume(&carton) {
+        Some(0) => return Err(PuzzleError::EmptyCarton { carton }),
+        None => return Err(PuzzleError::CartonTooLarge { carton }),
+        Some(_) => {}
+    }
+
+    // Too large presents go first, they fit into no carton
+    let mut order = (0..presents.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&index| std::cmp::Reverse(volume(&presents[index]).unwrap_or(usize::MAX)));
+
+    let mut cartons: Vec<Carton> = Vec::new();
+
+    for index in order {
+        let present = &presents[index];
+
+        if cartons
+            .iter_mut()
+            .any(|packed| packed.place(index, present, &carton))
+        {
+            continue;
+        }
+
+        let mut empty = Carton::new();
+        if !empty.place(index, present, &carton) {
+            return Err(PuzzleError::PresentTooLarge {
+                line: index + 1,
+                present: *present,
+            });
+        }
+
+        cartons.push(empty);
+    }
+
+    Ok(PackingPlan { carton, cartons })
+}
+
+// None if the volume does not fit into usize
+fn volume(dimensions: &Dimensions) -> Option<usize> {
+    dimensions
+        .length
+        .checked_mul(dimensions.width)?
+        .checked_mul(dimensions.height)
+}
+
+// All distinct orientations of the box
+fn rotations(size: &Dimensions) -> Vec<Dimensions> {
+    let Dimensions {
+        length: l,
+        width: w,
+        height: h,
+    } = *size;
+
+    let mut rotations = Vec::new();
+
+    // Boxes with equal sides have the same orientations more than once
+    for (length, width, height) in [
+        (l, w, h),
+        (l, h, w),
+        (w, l, h),
+        (w, h, l),
+        (h, l, w),
+        (h, w, l),
+    ] {
+        let rotation = Dimensions {
+            length,
+            width,
+            height,
+        };
+
+        if !rotations.contains(&rotation) {
+            rotations.push(rotation);
+        }
+    }
+
+    rotations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dimensions(length: usize, width: usize, height: usize) -> Dimensions {
+        Dimensions {
+            length,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_pack() {
+        // Large box goes first to the bottom, cubes fill the top of the first carton and
+        // half of the second one
+        let mut presents = vec![dimensions(1, 1, 1); 8];
+        presents.push(dimensions(2, 2, 1));
+
+        let plan = pack(dimensions(2, 2, 2), &presents).unwrap();
+
+        assert_eq!(plan.cartons.len(), 2);
+        assert_eq!(plan.lower_bound(), 2);
+        assert_eq!(plan.cartons[0].placements[0].present, 8);
+        assert_eq!(plan.fill_ratio(&plan.cartons[0]), 1.0);
+        assert_eq!(plan.fill_ratio(&plan.cartons[1]), 0.5);
+
+        // Every present is placed once, inside the carton and apart from the others
+        let mut placed = plan
+            .cartons
+            .iter()
+            .flat_map(|carton| carton.placements.iter().map(|p| p.present))
+            .collect::<Vec<_>>();
+        placed.sort();
+        assert_eq!(placed, (0..9).collect::<Vec<_>>());
+
+        for carton in &plan.cartons {
+            for (i, a) in carton.placements.iter().enumerate() {
+                assert!(a.position.x + a.size.length <= 2);
+                assert!(a.position.y + a.size.width <= 2);
+                assert!(a.position.z + a.size.height <= 2);
+
+                for b in &carton.placements[i + 1..] {
+                    assert!(!a.overlaps(&b.position, &b.size));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pack_rotated() {
+        // Tall presents fit only when laid down
+        let presents = [dimensions(1, 1, 4), dimensions(1, 1, 4)];
+        let plan = pack(dimensions(4, 2, 1), &presents).unwrap();
+
+        assert_eq!(plan.cartons.len(), 1);
+        assert_eq!(
+            plan.cartons[0].placements[1],
+            Placement {
+                present: 1,
+                position: Position { x: 0, y: 1, z: 0 },
+                size: dimensions(4, 1, 1)
+            }
+        );
+    }
+
+    #[test]
+    fn test_pack_invalid() {
+        assert_eq!(
+            pack(
+                dimensions(2, 2, 2),
+                &[dimensions(1, 1, 1), dimensions(1, 1, 3)]
+            ),
+            Err(PuzzleError::PresentTooLarge {
+                line: 2,
+                present: dimensions(1, 1, 3)
+            })
+        );
+        assert_eq!(
+            pack(dimensions(2, 0, 2), &[]),
+            Err(PuzzleError::EmptyCarton {
+                carton: dimensions(2, 0, 2)
+            })
+        );
+        assert_eq!(pack(dimensions(1, 1, 1), &[]).unwrap().cartons, vec![]);
+
+        let huge = dimensions(usize::MAX, usize::MAX, 2);
+        assert_eq!(
+            pack(huge, &[]),
+            Err(PuzzleError::CartonTooLarge { carton: huge })
+        );
+        assert_eq!(
+            pack(dimensions(2, 2, 2), &[dimensions(1, 1, 1), huge]),
+            Err(PuzzleError::PresentTooLarge {
+                line: 2,
+                present: huge
+            })
+        );
+    }
+
+    #[test]
+    fn test_rotations() {
+        assert_eq!(rotations(&dimensions(1, 2, 3)).len(), 6);
+        assert_eq!(rotations(&dimensions(2, 2, 3)).len(), 3);
+        assert_eq!(rotations(&dimensions(2, 2, 2)).len(), 1);
+    }
+}
@@ -1,11 +1,11 @@
-use core::panic;
 use std::error::Error;
 
-use puzzler::env::project;
-use puzzler::parsers::parser::Parser;
+use common::explain::Explain;
+use common::{input, parser};
 use puzzler::puzzler::puzzle::Puzzle;
 
 use crate::puzzle::dimension::Dimensions;
+use crate::puzzle::error::PuzzleError;
 
 pub struct Solution {
     dimensions: Vec<Dimensions>,
@@ -17,119 +17,149 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_02.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_02.txt [{}]", err)),
-        )
+        Some(input::file_path(2))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.dimensions = Parser::parse_lines_with_regex(lines, r"^(\d+)x(\d+)x(\d+)", |params| {
-            if params.len() != 3 {
-                return Err(
-                    format!("Exactly 3 parameters expected [found {}]", params.len()).into(),
-                );
-            }
-
-            let length = params[0]
-                .parse::<usize>()
-                .map_err(|e| format!("Failed to parse 'length' [{}] [{}]", params[0], e))?;
-
-            let width = params[1]
-                .parse::<usize>()
-                .map_err(|e| format!("Failed to parse 'width' [{}] [{}]", params[1], e))?;
-
-            let height = params[2]
-                .parse::<usize>()
-                .map_err(|e| format!("Failed to parse 'height' [{}] [{}]", params[2], e))?;
-
-            Ok(Dimensions {
-                length,
-                width,
-                height,
-            })
-        })?;
+        self.dimensions = Self::parse_input(&lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let total = self
-            .dimensions
-            .iter()
-            .map(Solution::calculate_area)
-            .sum::<usize>();
+        let total = Self::total(&self.dimensions, Solution::calculate_area)?;
 
         Ok(total.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let total = self
-            .dimensions
-            .iter()
-            .map(Solution::calculate_ribbon)
-            .sum::<usize>();
+        let total = Self::total(&self.dimensions, Solution::calculate_ribbon)?;
 
         Ok(total.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self { dimensions: vec![] }
     }
 
-    pub fn calculate_area(dimension: &Dimensions) -> usize {
+    // Every line holds dimensions of a single present, e.g. '2x3x4'
+    pub fn parse_input(lines: &[String]) -> Result<Vec<Dimensions>, PuzzleError> {
+        parser::parse_lines_with_regex(
+            lines,
+            r"^(\d+)x(\d+)x(\d+)$",
+            |line, params| -> Result<_, PuzzleError> {
+                Ok(Dimensions {
+                    length: Self::parse_number(line, "length", &params[0])?,
+                    width: Self::parse_number(line, "width", &params[1])?,
+                    height: Self::parse_number(line, "height", &params[2])?,
+                })
+            },
+        )
+    }
+
+    fn parse_number(line: usize, field: &'static str, text: &str) -> Result<usize, PuzzleError> {
+        text.parse::<usize>()
+            .map_err(|_| PuzzleError::InvalidNumber {
+                line,
+                field,
+                text: text.to_string(),
+            })
+    }
+
+    // Material of all presents, the line of the present which overflows the total
+    fn total(
+        dimensions: &[Dimensions],
+        material: fn(&Dimensions) -> Option<usize>,
+    ) -> Result<usize, PuzzleError> {
+        dimensions
+            .iter()
+            .enumerate()
+            .try_fold(0usize, |total, (index, dimension)| {
+                material(dimension)
+                    .and_then(|amount| total.checked_add(amount))
+                    .ok_or(PuzzleError::DimensionsOverflow { line: index + 1 })
+            })
+    }
+
+    // Wrapping paper of a present, surface of the box plus area of the smallest side. None if
+    // the area does not fit into usize.
+    pub fn calculate_area(dimension: &Dimensions) -> Option<usize> {
         let areas = [
-            dimension.length * dimension.width,
-            dimension.width * dimension.height,
-            dimension.height * dimension.length,
+            dimension.length.checked_mul(dimension.width)?,
+            dimension.width.checked_mul(dimension.height)?,
+            dimension.height.checked_mul(dimension.length)?,
         ];
+        let smallest = areas[0].min(areas[1]).min(areas[2]);
 
         // Areas of all + smallest area
-        2 * areas.iter().sum::<usize>()
-            + areas
-                .iter()
-                .min()
-                .unwrap_or_else(|| panic!("Failed to find minimum area in [{:?}]", areas))
+        areas.iter().try_fold(smallest, |total, area| {
+            total.checked_add(area.checked_mul(2)?)
+        })
     }
 
-    pub fn calculate_ribbon(dimension: &Dimensions) -> usize {
-        let sides = [dimension.length, dimension.width, dimension.height];
+    // Ribbon of a present, smallest perimeter of any side plus volume for the bow. None if
+    // the ribbon does not fit into usize.
+    pub fn calculate_ribbon(dimension: &Dimensions) -> Option<usize> {
+        let Dimensions {
+            length,
+            width,
+            height,
+        } = *dimension;
+        let largest = length.max(width).max(height);
 
-        // sum of two shortest sides + bow
-        2 * (sides.iter().sum::<usize>()
-            - sides
-                .iter()
-                .max()
-                .unwrap_or_else(|| panic!("Failed to find maximum side in [{:?}]", sides)))
-            + sides.iter().product::<usize>()
+        // Sum of two shortest sides + bow
+        let perimeter = length
+            .checked_add(width)?
+            .checked_add(height)?
+            .checked_sub(largest)?
+            .checked_mul(2)?;
+        let bow = length.checked_mul(width)?.checked_mul(height)?;
+
+        perimeter.checked_add(bow)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::{dimension::Dimensions, solution::Solution};
+    use common::parser::{InvalidLine, SyntaxError};
 
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
+    use crate::puzzle::{dimension::Dimensions, error::PuzzleError, solution::Solution};
 
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "1598415");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "1598415");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "3812909");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "3812909");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
@@ -140,7 +170,7 @@ mod tests {
                 width: 3,
                 height: 4
             }),
-            58
+            Some(58)
         );
         assert_eq!(
             Solution::calculate_area(&Dimensions {
@@ -148,7 +178,7 @@ mod tests {
                 width: 1,
                 height: 10
             }),
-            43
+            Some(43)
         );
     }
 
@@ -160,7 +190,7 @@ mod tests {
                 width: 3,
                 height: 4
             }),
-            34
+            Some(34)
         );
         assert_eq!(
             Solution::calculate_ribbon(&Dimensions {
@@ -168,7 +198,68 @@ mod tests {
                 width: 1,
                 height: 10
             }),
-            14
+            Some(14)
+        );
+    }
+
+    #[test]
+    fn test_dimensions_overflow() {
+        let huge = Dimensions {
+            length: 9999999999,
+            width: 9999999999,
+            height: 9,
+        };
+        assert_eq!(Solution::calculate_area(&huge), None);
+        assert_eq!(Solution::calculate_ribbon(&huge), None);
+
+        let mut solution = Solution::new();
+        solution
+            .parse_content(vec![
+                String::from("2x3x4"),
+                String::from("9999999999x9999999999x9"),
+            ])
+            .unwrap();
+
+        for err in [
+            solution.solve_part1().unwrap_err(),
+            solution.solve_part2().unwrap_err(),
+        ] {
+            assert_eq!(
+                err.downcast_ref::<PuzzleError>(),
+                Some(&PuzzleError::DimensionsOverflow { line: 2 })
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![String::from("1x2x3"), String::from("1x2")])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::Syntax(SyntaxError {
+                lines: vec![InvalidLine {
+                    line: 2,
+                    column: 4,
+                    text: String::from("1x2")
+                }]
+            }))
+        );
+
+        let err = solution
+            .parse_content(vec![String::from("1x99999999999999999999x3")])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidNumber {
+                line: 1,
+                field: "width",
+                text: String::from("99999999999999999999")
+            })
         );
     }
 }
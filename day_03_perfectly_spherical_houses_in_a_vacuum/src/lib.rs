@@ -0,0 +1,12 @@
+// Day 3: Perfectly Spherical Houses in a Vacuum. Santa delivers presents to an infinite grid of
+// houses by directions, the solver functions of Solution count houses which got a present.
+// DeliverySimulation shares directions by any number of agents and collects visit statistics,
+// Heatmap renders presents of the houses as PGM images.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::heatmap::Heatmap;
+pub use puzzle::simulation::{DeliverySimulation, Turns};
+pub use puzzle::solution::Solution;
+pub use puzzler::grids::direction::Direction;
@@ -1,12 +1,13 @@
 use std::error::Error;
 
+use common::input;
 use puzzler::puzzler::solver::Solver;
 
-use crate::puzzle::solution::Solution;
-
-mod puzzle;
+use day_03_perfectly_spherical_houses_in_a_vacuum::Solution;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    input::require_file(3)?;
+
     let puzzle = Box::new(Solution::new());
     let mut solver = Solver::new(puzzle, 2);
     solver.run()
@@ -0,0 +1,46 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    LineCount {
+        found: usize,
+    },
+    InvalidDirection {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    NoAgents,
+    ZeroWeight {
+        agent: usize,
+    },
+    ZeroFrameInterval,
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::LineCount { found } => {
+                write!(f, "Exactly one line expected in input file, found {found}")
+            }
+            PuzzleError::InvalidDirection {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "Line {line}, column {column}: invalid direction '{found}'"
+            ),
+            PuzzleError::NoAgents => write!(f, "At least one agent is required"),
+            PuzzleError::ZeroWeight { agent } => {
+                write!(f, "Agent {agent} has zero weight, it would never move")
+            }
+            PuzzleError::ZeroFrameInterval => {
+                write!(f, "Frame shall be rendered after at least one move")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
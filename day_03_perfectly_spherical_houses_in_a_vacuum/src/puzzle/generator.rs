@@ -0,0 +1,28 @@
+use common::random::Rng;
+
+// Single line of moves '^', 'v', '<' and '>', size is the number of moves
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    let moves = (0..size)
+        .map(|_| *rng.choose(&['^', 'v', '<', '>']))
+        .collect();
+
+    vec![moves]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 100);
+        assert_eq!(lines, generate(7, 100));
+        assert_ne!(lines, generate(8, 100));
+
+        let directions = Solution::parse_input(&lines).unwrap();
+        assert_eq!(directions.len(), 100);
+        assert!(Solution::deliver_presents_santa(&directions) <= 101);
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use puzzler::grids::direction::Direction;
+use puzzler::grids::point::Point;
+
+use crate::puzzle::error::PuzzleError;
+
+// Bounding box of houses, x of the point is the row and y the column of the image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds {
+    top: isize,
+    left: isize,
+    bottom: isize,
+    right: isize,
+}
+
+impl Bounds {
+    fn of<'a>(points: impl Iterator<Item = &'a Point>) -> Self {
+        points.fold(
+            Self {
+                top: 0,
+                left: 0,
+                bottom: 0,
+                right: 0,
+            },
+            |bounds, point| Self {
+                top: bounds.top.min(point.x),
+                left: bounds.left.min(point.y),
+                bottom: bounds.bottom.max(point.x),
+                right: bounds.right.max(point.y),
+            },
+        )
+    }
+}
+
+// Presents delivered to every house of the bounding box, the starting house is always
+// within the box
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    bounds: Bounds,
+    counts: Vec<usize>,
+}
+
+impl Heatmap {
+    // Bounding box is computed from the houses
+    pub fn new(visits: &HashMap<Point, usize>) -> Self {
+        Self::with_bounds(visits, Bounds::of(visits.keys()))
+    }
+
+    fn with_bounds(visits: &HashMap<Point, usize>, bounds: Bounds) -> Self {
+        let mut heatmap = Self {
+            bounds,
+            counts: Vec::new(),
+        };
+        heatmap.counts = vec![0; heatmap.width() * heatmap.height()];
+
+        for (point, count) in visits {
+            let index = heatmap.index(point);
+            heatmap.counts[index] = *count;
+        }
+
+        heatmap
+    }
+
+    // Single frame after every given number of moves and the last frame after all of them.
+    // All frames share the bounding box of the whole walk, i.e. they can be animated.
+    pub fn frames(directions: &[Direction], every: usize) -> Result<Vec<Self>, PuzzleError> {
+        if every == 0 {
+            return Err(PuzzleError::ZeroFrameInterval);
+        }
+
+        let mut point = Point::new(0, 0);
+        let mut visits = HashMap::from([(point, 1)]);
+        let mut snapshots = Vec::new();
+
+        for (index, dir) in directions.iter().enumerate() {
+            point = point.neighbor(dir);
+            *visits.entry(point).or_default() += 1;
+
+            if (index + 1) % every == 0 || index + 1 == directions.len() {
+                snapshots.push(visits.clone());
+            }
+        }
+
+        if snapshots.is_empty() {
+            snapshots.push(visits.clone());
+        }
+
+        // Last snapshot holds every house of the walk
+        let bounds = Bounds::of(visits.keys());
+
+        Ok(snapshots
+            .iter()
+            .map(|visits| Self::with_bounds(visits, bounds))
+            .collect())
+    }
+
+    pub fn width(&self) -> usize {
+        (self.bounds.right - self.bounds.left) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.bounds.bottom - self.bounds.top) as usize + 1
+    }
+
+    // Presents of the house, zero outside of the bounding box
+    pub fn count(&self, point: &Point) -> usize {
+        let inside = (self.bounds.top..=self.bounds.bottom).contains(&point.x)
+            && (self.bounds.left..=self.bounds.right).contains(&point.y);
+
+        match inside {
+            true => self.counts[self.index(point)],
+            false => 0,
+        }
+    }
+
+    // Binary PGM (P5) image, brightness of the house grows with its presents. Houses without
+    // presents are black, the most visited houses are white and any visited house is visible.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let max = self.counts.iter().copied().max().unwrap_or_default().max(1);
+
+        let mut image = format!("P5\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        image.extend(
+            self.counts
+                .iter()
+                .map(|count| (count * 255).div_ceil(max) as u8),
+        );
+
+        image
+    }
+
+    fn index(&self, point: &Point) -> usize {
+        let row = (point.x - self.bounds.top) as usize;
+        let col = (point.y - self.bounds.left) as usize;
+
+        row * self.width() + col
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::solution::Solution;
+
+    use super::*;
+
+    fn directions(text: &str) -> Vec<Direction> {
+        Solution::parse_input(&[String::from(text)]).unwrap()
+    }
+
+    #[test]
+    fn test_to_pgm() {
+        let visits = HashMap::from([
+            (Point::new(0, 0), 3),
+            (Point::new(-1, 0), 1),
+            (Point::new(-1, 2), 2),
+        ]);
+        let heatmap = Heatmap::new(&visits);
+
+        assert_eq!((heatmap.width(), heatmap.height()), (3, 2));
+        assert_eq!(heatmap.count(&Point::new(-1, 2)), 2);
+        assert_eq!(heatmap.count(&Point::new(5, 5)), 0);
+
+        let mut expected = b"P5\n3 2\n255\n".to_vec();
+        expected.extend([85, 0, 170, 255, 0, 0]);
+        assert_eq!(heatmap.to_pgm(), expected);
+    }
+
+    #[test]
+    fn test_frames() {
+        let directions = directions("^v^v>");
+        let frames = Heatmap::frames(&directions, 2).unwrap();
+
+        // After 2, 4 and all 5 moves
+        assert_eq!(frames.len(), 3);
+        assert!(
+            frames
+                .iter()
+                .all(|frame| frame.width() * frame.height() == 4)
+        );
+
+        let presents = |frame: &Heatmap| frame.counts.iter().sum::<usize>();
+        assert_eq!(frames.iter().map(presents).collect::<Vec<_>>(), [3, 5, 6]);
+
+        // Last frame matches the visits of the whole walk
+        let visits = Solution::visit_houses(&directions);
+        assert_eq!(frames[2], Heatmap::new(&visits));
+        assert_eq!(frames[2].count(&Point::new(0, 0)), 3);
+    }
+
+    #[test]
+    fn test_frames_empty() {
+        let frames = Heatmap::frames(&[], 10).unwrap();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].to_pgm(), b"P5\n1 1\n255\n\xff".to_vec());
+
+        assert_eq!(Heatmap::frames(&[], 0), Err(PuzzleError::ZeroFrameInterval));
+    }
+}
@@ -1 +1,5 @@
+pub mod error;
+pub mod generator;
+pub mod heatmap;
+pub mod simulation;
 pub mod solution;
@@ -0,0 +1,217 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use puzzler::grids::direction::Direction;
+use puzzler::grids::point::Point;
+
+use crate::puzzle::error::PuzzleError;
+
+// How a single list of directions is shared by the agents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Turns {
+    // Agents take one direction each in turns, e.g. Santa and Robo-Santa
+    RoundRobin { agents: usize },
+    // Agent takes the number of consecutive directions given by its weight
+    Weighted(Vec<usize>),
+}
+
+impl Turns {
+    pub fn agents(&self) -> usize {
+        match self {
+            Turns::RoundRobin { agents } => *agents,
+            Turns::Weighted(weights) => weights.len(),
+        }
+    }
+
+    // Agent of every direction in order
+    fn schedule(&self) -> Result<Vec<usize>, PuzzleError> {
+        match self {
+            Turns::RoundRobin { agents: 0 } => Err(PuzzleError::NoAgents),
+            Turns::RoundRobin { agents } => Ok((0..*agents).collect()),
+            Turns::Weighted(weights) if weights.is_empty() => Err(PuzzleError::NoAgents),
+            Turns::Weighted(weights) => {
+                if let Some(agent) = weights.iter().position(|weight| *weight == 0) {
+                    return Err(PuzzleError::ZeroWeight { agent });
+                }
+
+                Ok(weights
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(agent, weight)| std::iter::repeat_n(agent, *weight))
+                    .collect())
+            }
+        }
+    }
+}
+
+// Agents delivering presents from the same starting house, every agent delivers a present
+// to every house it enters including the starting one
+#[derive(Debug, Clone)]
+pub struct DeliverySimulation {
+    positions: Vec<Point>,
+    visits: HashMap<Point, usize>,
+    agent_houses: Vec<HashSet<Point>>,
+}
+
+impl DeliverySimulation {
+    pub fn new(agents: usize) -> Result<Self, PuzzleError> {
+        if agents == 0 {
+            return Err(PuzzleError::NoAgents);
+        }
+
+        let start = Point::new(0, 0);
+
+        Ok(Self {
+            positions: vec![start; agents],
+            visits: HashMap::from([(start, agents)]),
+            agent_houses: vec![HashSet::from([start]); agents],
+        })
+    }
+
+    // Share directions by the turns, the last turn may end early
+    pub fn run(turns: &Turns, directions: &[Direction]) -> Result<Self, PuzzleError> {
+        let schedule = turns.schedule()?;
+        let mut simulation = Self::new(turns.agents())?;
+
+        for (agent, direction) in schedule.iter().cycle().zip(directions) {
+            simulation.step(*agent, direction);
+        }
+
+        Ok(simulation)
+    }
+
+    // Every agent follows its own directions
+    pub fn run_streams(streams: &[Vec<Direction>]) -> Result<Self, PuzzleError> {
+        let mut simulation = Self::new(streams.len())?;
+
+        for (agent, directions) in streams.iter().enumerate() {
+            for direction in directions {
+                simulation.step(agent, direction);
+            }
+        }
+
+        Ok(simulation)
+    }
+
+    // Move the agent to the neighbor house and deliver a present there
+    pub fn step(&mut self, agent: usize, direction: &Direction) {
+        let point = self.positions[agent].neighbor(direction);
+
+        self.positions[agent] = point;
+        *self.visits.entry(point).or_default() += 1;
+        self.agent_houses[agent].insert(point);
+    }
+
+    pub fn agents(&self) -> usize {
+        self.positions.len()
+    }
+
+    // Number of presents delivered to every house
+    pub fn visits(&self) -> &HashMap<Point, usize> {
+        &self.visits
+    }
+
+    // Number of houses which got at least one present
+    pub fn houses(&self) -> usize {
+        self.visits.len()
+    }
+
+    pub fn unique_houses(&self, agent: usize) -> usize {
+        self.agent_houses[agent].len()
+    }
+
+    // House with the most presents, ties are broken by the lowest coordinates
+    pub fn most_visited(&self) -> Option<(Point, usize)> {
+        self.visits
+            .iter()
+            .min_by_key(|(point, count)| (Reverse(**count), point.x, point.y))
+            .map(|(point, count)| (*point, *count))
+    }
+
+    // Houses visited by more than one agent sorted by coordinates
+    pub fn shared_houses(&self) -> Vec<Point> {
+        let mut houses = self
+            .visits
+            .keys()
+            .filter(|point| {
+                self.agent_houses
+                    .iter()
+                    .filter(|houses| houses.contains(point))
+                    .count()
+                    > 1
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        houses.sort_by_key(|point| (point.x, point.y));
+        houses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::solution::Solution;
+
+    use super::*;
+
+    fn directions(text: &str) -> Vec<Direction> {
+        Solution::parse_input(&[String::from(text)]).unwrap()
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let turns = Turns::RoundRobin { agents: 2 };
+
+        for (text, houses) in [("^v", 3), ("^>v<", 3), ("^v^v^v^v^v", 11), ("^", 2)] {
+            let simulation = DeliverySimulation::run(&turns, &directions(text)).unwrap();
+            assert_eq!(simulation.houses(), houses);
+        }
+
+        // Both agents return to the start
+        let simulation = DeliverySimulation::run(&turns, &directions("^>v<")).unwrap();
+        assert_eq!(simulation.most_visited(), Some((Point::new(0, 0), 4)));
+        assert_eq!(simulation.shared_houses(), vec![Point::new(0, 0)]);
+        assert_eq!(simulation.unique_houses(0), 2);
+        assert_eq!(simulation.unique_houses(1), 2);
+    }
+
+    #[test]
+    fn test_weighted() {
+        // First agent takes two directions, the second one a single direction
+        let turns = Turns::Weighted(vec![2, 1]);
+        let simulation = DeliverySimulation::run(&turns, &directions(">>^<<")).unwrap();
+
+        assert_eq!(simulation.agents(), 2);
+        assert_eq!(simulation.unique_houses(0), 3);
+        assert_eq!(simulation.unique_houses(1), 2);
+        assert_eq!(simulation.shared_houses(), vec![Point::new(0, 0)]);
+        assert_eq!(simulation.houses(), 4);
+    }
+
+    #[test]
+    fn test_streams() {
+        let streams = [directions(">>"), directions(">"), directions("<")];
+        let simulation = DeliverySimulation::run_streams(&streams).unwrap();
+
+        assert_eq!(simulation.houses(), 4);
+        assert_eq!(simulation.shared_houses().len(), 2);
+        assert_eq!(simulation.most_visited(), Some((Point::new(0, 0), 3)));
+        assert_eq!(simulation.unique_houses(0), 3);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            DeliverySimulation::run(&Turns::RoundRobin { agents: 0 }, &[]),
+            Err(PuzzleError::NoAgents)
+        ));
+        assert!(matches!(
+            DeliverySimulation::run(&Turns::Weighted(vec![1, 0]), &[]),
+            Err(PuzzleError::ZeroWeight { agent: 1 })
+        ));
+        assert!(matches!(
+            DeliverySimulation::run_streams(&[]),
+            Err(PuzzleError::NoAgents)
+        ));
+    }
+}
@@ -1,11 +1,15 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::error::Error;
 
-use puzzler::env::project;
+use common::explain::Explain;
+use common::input;
 use puzzler::grids::direction::Direction;
 use puzzler::grids::point::Point;
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
+use crate::puzzle::simulation::{DeliverySimulation, Turns};
+
 pub struct Solution {
     directions: Vec<Direction>,
 }
@@ -16,28 +20,11 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_03.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_03.txt [{err}]")),
-        )
+        Some(input::file_path(3))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        // All content is on a single line
-        if lines.len() != 1 {
-            return Err(format!(
-                "Exactly one line expected in input file, found {}",
-                lines.len()
-            )
-            .into());
-        }
-
-        // Convert to direction
-        self.directions = lines[0]
-            .bytes()
-            .map(|b| Direction::try_from(b).map_err(|_| format!("Invalid direction '{b}'")))
-            .collect::<Result<Vec<_>, _>>()?;
-
+        self.directions = Self::parse_input(&lines)?;
         Ok(())
     }
 
@@ -49,78 +36,109 @@ impl Puzzle for Solution {
 
     // Solve second part of the puzzle
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let visited_houses = Self::deliver_presents_santa_and_robot(&self.directions);
+        let visited_houses = Self::deliver_presents_santa_and_robot(&self.directions)?;
         Ok(visited_houses.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self { directions: vec![] }
     }
 
-    fn deliver_presents_santa(directions: &[Direction]) -> usize {
-        // As a starting point use (0, 0)
-        let mut point = Point::new(0, 0);
-
-        // Use hashset to keep track of visited houses
-        let mut houses: HashSet<Point> = HashSet::new();
-        houses.insert(point);
-
-        for dir in directions {
-            point = point.neighbor(dir);
-            houses.insert(point);
+    // Directions are a single line of '^', 'v', '<' and '>'
+    pub fn parse_input(lines: &[String]) -> Result<Vec<Direction>, PuzzleError> {
+        // All content is on a single line
+        if lines.len() != 1 {
+            return Err(PuzzleError::LineCount { found: lines.len() });
         }
 
-        houses.len()
+        // Convert to direction
+        lines[0]
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                u8::try_from(c)
+                    .ok()
+                    .and_then(|b| Direction::try_from(b).ok())
+                    .ok_or(PuzzleError::InvalidDirection {
+                        line: 1,
+                        column: i + 1,
+                        found: c,
+                    })
+            })
+            .collect()
     }
 
-    fn deliver_presents_santa_and_robot(directions: &[Direction]) -> usize {
+    // Number of houses which receive at least one present from Santa
+    pub fn deliver_presents_santa(directions: &[Direction]) -> usize {
+        Self::visit_houses(directions).len()
+    }
+
+    // Number of presents Santa delivers to every house
+    pub fn visit_houses(directions: &[Direction]) -> HashMap<Point, usize> {
         // As a starting point use (0, 0)
         let mut point = Point::new(0, 0);
-        let mut point_robot = Point::new(0, 0);
-
-        // Use hashset to keep track of visited houses
-        let mut houses: HashSet<Point> = HashSet::new();
-        houses.insert(point);
-        houses.insert(point_robot);
 
-        for dir in directions.chunks(2) {
-            point = point.neighbor(&dir[0]);
-            houses.insert(point);
+        // Use hashmap to keep track of visited houses and their presents
+        let mut houses: HashMap<Point, usize> = HashMap::new();
+        houses.insert(point, 1);
 
-            point_robot = point_robot.neighbor(&dir[1]);
-            houses.insert(point_robot);
+        for dir in directions {
+            point = point.neighbor(dir);
+            *houses.entry(point).or_default() += 1;
         }
 
-        houses.len()
+        houses
+    }
+
+    // Number of houses which receive at least one present, Santa and Robo-Santa take turns
+    pub fn deliver_presents_santa_and_robot(
+        directions: &[Direction],
+    ) -> Result<usize, PuzzleError> {
+        let turns = Turns::RoundRobin { agents: 2 };
+        DeliverySimulation::run(&turns, directions).map(|simulation| simulation.houses())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::{grids::direction::Direction, puzzler::puzzle::Puzzle};
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "2572");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "2572");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "2631");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "2631");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
@@ -154,9 +172,13 @@ mod tests {
 
     #[test]
     fn test_deliver_presents_santa_and_robot() {
+        assert_eq!(
+            Solution::deliver_presents_santa_and_robot(&[Direction::North]),
+            Ok(2)
+        );
         assert_eq!(
             Solution::deliver_presents_santa_and_robot(&[Direction::North, Direction::South]),
-            3
+            Ok(3)
         );
         assert_eq!(
             Solution::deliver_presents_santa_and_robot(&[
@@ -165,7 +187,7 @@ mod tests {
                 Direction::South,
                 Direction::West
             ]),
-            3
+            Ok(3)
         );
         assert_eq!(
             Solution::deliver_presents_santa_and_robot(&[
@@ -180,7 +202,30 @@ mod tests {
                 Direction::North,
                 Direction::South,
             ]),
-            11
+            Ok(11)
+        );
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+
+        let err = solution.parse_content(vec![]).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::LineCount { found: 0 })
+        );
+
+        let err = solution
+            .parse_content(vec![String::from("^>x<")])
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidDirection {
+                line: 1,
+                column: 3,
+                found: 'x'
+            })
         );
     }
 }
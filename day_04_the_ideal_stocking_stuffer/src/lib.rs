@@ -0,0 +1,7 @@
+// Day 4: The Ideal Stocking Stuffer. Mining of AdventCoins, i.e. MD5 hashes of the secret key
+// followed by a number which start with zeros.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
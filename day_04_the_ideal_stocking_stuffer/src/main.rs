@@ -1,12 +1,13 @@
 use std::error::Error;
 
+use common::input;
 use puzzler::puzzler::solver::Solver;
 
-use crate::puzzle::solution::Solution;
-
-mod puzzle;
+use day_04_the_ideal_stocking_stuffer::Solution;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    input::require_file(4)?;
+
     let puzzle = Box::new(Solution::new());
     let mut solver = Solver::new(puzzle, 2);
     solver.run()
@@ -0,0 +1,33 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::budget::Exceeded;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    LineCount { found: usize },
+    EmptySecretKey { line: usize },
+    TooManyZeros { found: usize },
+    BudgetExceeded { exceeded: Exceeded, number: usize },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::LineCount { found } => write!(
+                f,
+                "Exactly one line is expected in input file, but '{found}' found"
+            ),
+            PuzzleError::EmptySecretKey { line } => write!(f, "Line {line}: secret key is empty"),
+            PuzzleError::TooManyZeros { found } => write!(
+                f,
+                "MD5 digest has at most 32 hexadecimal zeros, but '{found}' requested"
+            ),
+            PuzzleError::BudgetExceeded { exceeded, number } => {
+                write!(f, "{exceeded}, no digest found below number '{number}'")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
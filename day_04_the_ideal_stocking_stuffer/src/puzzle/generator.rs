@@ -0,0 +1,21 @@
+use common::random::Rng;
+
+// Secret key of lowercase letters, size is the length of the key
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    vec![Rng::new(seed).lowercase(size)]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 8);
+        assert_eq!(lines, generate(7, 8));
+        assert_ne!(lines, generate(8, 8));
+
+        let secret_key = Solution::parse_input(&lines).unwrap();
+        assert_eq!(secret_key.len(), 8);
+    }
+}
@@ -1,37 +1,98 @@
 use std::error::Error;
 use std::fmt::Write;
+use std::path::PathBuf;
 
+use common::budget::{self, Budget};
+use common::explain::Explain;
+use common::input;
 use md5::Digest;
 use puzzler::puzzler::puzzle::Puzzle;
 
-pub struct Solution {}
+use crate::puzzle::error::PuzzleError;
+
+// Every byte of the 16 byte digest holds two hexadecimal digits
+const MAX_ZEROS: usize = 32;
+
+pub struct Solution {
+    secret_key: String,
+}
 
 impl Puzzle for Solution {
     fn name(&self) -> &str {
         "--- Day 04: The Ideal Stocking Stuffer ---"
     }
 
+    fn get_input_file_path(&self) -> Option<PathBuf> {
+        Some(input::file_path(4))
+    }
+
+    fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
+        self.secret_key = Self::parse_input(&lines)?;
+        Ok(())
+    }
+
     // Solve first part of the puzzle
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let number = Solution::find_md5("yzbqklnj", 5);
+        let number = Solution::find_md5(&self.secret_key, 5)?;
         Ok(number.to_string())
     }
 
     // Solve second part of the puzzle
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let number = Solution::find_md5("yzbqklnj", 6);
+        let number = Solution::find_md5(&self.secret_key, 6)?;
         Ok(number.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            secret_key: String::new(),
+        }
     }
 
-    fn find_md5(secret_key: &str, zeros_count: usize) -> usize {
+    // Secret key is a single non-empty line
+    pub fn parse_input(lines: &[String]) -> Result<String, PuzzleError> {
+        if lines.len() != 1 {
+            return Err(PuzzleError::LineCount { found: lines.len() });
+        }
+
+        let secret_key = lines[0].trim();
+        if secret_key.is_empty() {
+            return Err(PuzzleError::EmptySecretKey { line: 1 });
+        }
+
+        Ok(secret_key.to_string())
+    }
+
+    // Lowest number which gives MD5 hash of the secret key and the number starting with
+    // zeros_count hexadecimal zeros. Search is limited by the budget of the thread.
+    pub fn find_md5(secret_key: &str, zeros_count: usize) -> Result<usize, PuzzleError> {
+        Self::search_md5(secret_key, zeros_count, &budget::current())
+    }
+
+    // Every number is a single step of the budget
+    fn search_md5(
+        secret_key: &str,
+        zeros_count: usize,
+        budget: &Budget,
+    ) -> Result<usize, PuzzleError> {
+        // MD5 digest has only 32 hexadecimal digits
+        if zeros_count > MAX_ZEROS {
+            return Err(PuzzleError::TooManyZeros { found: zeros_count });
+        }
+
         // Start with number 0
         let mut number = 0;
+        let mut meter = budget.meter();
 
         // Reusable buffer to avoid format!() in every loop which allocates a new String
         // Note: Magic number 12 is an estimation of maximum number, i.e., 999_999_999_999
@@ -39,6 +100,10 @@ impl Solution {
 
         // Loop until we find digest with leading zeros
         loop {
+            meter
+                .step()
+                .map_err(|exceeded| PuzzleError::BudgetExceeded { exceeded, number })?;
+
             // Fill in data for MD5
             data.clear();
             data.push_str(secret_key);
@@ -49,7 +114,7 @@ impl Solution {
 
             // Check for a solution
             if Self::all_zeros(&digest, zeros_count) {
-                return number;
+                return Ok(number);
             }
 
             number += 1;
@@ -81,27 +146,64 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::budget::{Budget, Exceeded, Limit, Limits};
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
+    use crate::puzzle::error::PuzzleError;
     use crate::puzzle::solution::Solution;
 
-    fn get_puzzle() -> Solution {
-        Solution::new()
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "282749");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "282749");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "9962624");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "9962624");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
     fn test_find_md5() {
-        assert_eq!(Solution::find_md5("abcdef", 5), 609043);
-        assert_eq!(Solution::find_md5("pqrstuv", 5), 1048970);
+        assert_eq!(Solution::find_md5("abcdef", 5), Ok(609043));
+        assert_eq!(Solution::find_md5("pqrstuv", 5), Ok(1048970));
+        assert_eq!(
+            Solution::find_md5("abcdef", 33),
+            Err(PuzzleError::TooManyZeros { found: 33 })
+        );
+    }
+
+    #[test]
+    fn test_search_md5_budget_exceeded() {
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(1000),
+        });
+
+        assert_eq!(
+            Solution::search_md5("abcdef", 5, &budget),
+            Err(PuzzleError::BudgetExceeded {
+                exceeded: Exceeded {
+                    limit: Limit::Steps(1000),
+                    steps: 1000
+                },
+                number: 1000
+            })
+        );
     }
 }
@@ -0,0 +1,7 @@
+// Day 5: Doesn't He Have Intern-Elves For This? Rules deciding whether a word is nice or
+// naughty.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
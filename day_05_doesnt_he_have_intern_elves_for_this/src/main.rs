@@ -1,12 +1,13 @@
 use std::error::Error;
 
+use common::input;
 use puzzler::puzzler::solver::Solver;
 
-use crate::puzzle::solution::Solution;
-
-mod puzzle;
+use day_05_doesnt_he_have_intern_elves_for_this::Solution;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    input::require_file(5)?;
+
     let puzzle = Box::new(Solution::new());
     let mut solver = Solver::new(puzzle, 2);
     solver.run()
@@ -0,0 +1,22 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    InvalidWord { line: usize, text: String },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::InvalidWord { line, text } => {
+                write!(
+                    f,
+                    "Line {line}: word shall contain only lowercase letters '{text}'"
+                )
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
@@ -0,0 +1,26 @@
+use common::random::Rng;
+
+// Length of every word, same as in the puzzle input
+const WORD_LENGTH: usize = 16;
+
+// Words of lowercase letters, size is the number of words
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    (0..size).map(|_| rng.lowercase(WORD_LENGTH)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 50);
+        assert_eq!(lines, generate(7, 50));
+        assert_ne!(lines, generate(8, 50));
+
+        let words = Solution::parse_input(lines).unwrap();
+        assert_eq!(words.len(), 50);
+    }
+}
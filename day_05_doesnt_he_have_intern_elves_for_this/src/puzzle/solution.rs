@@ -1,8 +1,11 @@
 use std::error::Error;
 
-use puzzler::env::project;
+use common::explain::Explain;
+use common::input;
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
+
 const VOWELS: [u8; 5] = [b'a', b'e', b'i', b'o', b'u'];
 
 pub struct Solution {
@@ -15,14 +18,11 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_05.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_05.txt [{err}]")),
-        )
+        Some(input::file_path(5))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.words = lines;
+        self.words = Self::parse_input(lines)?;
         Ok(())
     }
 
@@ -41,12 +41,37 @@ impl Puzzle for Solution {
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self { words: vec![] }
     }
 
-    fn is_nice(word: &str) -> bool {
+    // Every line is a single word of lowercase letters
+    pub fn parse_input(lines: Vec<String>) -> Result<Vec<String>, PuzzleError> {
+        if let Some((index, word)) = lines
+            .iter()
+            .enumerate()
+            .find(|(_, word)| !word.bytes().all(|b| b.is_ascii_lowercase()))
+        {
+            return Err(PuzzleError::InvalidWord {
+                line: index + 1,
+                text: word.clone(),
+            });
+        }
+
+        Ok(lines)
+    }
+
+    // Nice word by the rules of the first part
+    pub fn is_nice(word: &str) -> bool {
         // At least 3 vowels (aeiou)
         if word.bytes().filter(|c| VOWELS.contains(c)).count() < 3 {
             return false;
@@ -65,12 +90,13 @@ impl Solution {
         found == 0
     }
 
-    fn is_nice_advanced(word: &str) -> bool {
+    // Nice word by the better rules of the second part
+    pub fn is_nice_advanced(word: &str) -> bool {
         let mut valid = false;
         let bytes = word.as_bytes();
 
         // Contains a pair of any two letters that appears at least twice in the string without overlapping
-        for i in 0..bytes.len() - 2 {
+        for i in 0..bytes.len().saturating_sub(2) {
             let pair = &bytes[i..i + 2];
 
             if bytes[i + 2..].windows(2).any(|w| pair == w) {
@@ -86,7 +112,7 @@ impl Solution {
         // Contains at least one letter which repeats with exactly one letter between them
         valid = false;
 
-        for i in 0..bytes.len() - 2 {
+        for i in 0..bytes.len().saturating_sub(2) {
             if bytes[i] == bytes[i + 2] {
                 valid = true;
                 break;
@@ -99,28 +125,34 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "255");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "255");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "55");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "55");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
@@ -146,5 +178,23 @@ mod tests {
     fn test_is_naughty_advanced() {
         assert!(!Solution::is_nice_advanced("uurcxstgmygtbstg"));
         assert!(!Solution::is_nice_advanced("ieodomkazucvgmuy"));
+        assert!(!Solution::is_nice_advanced("a"));
+        assert!(!Solution::is_nice_advanced(""));
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![String::from("abc"), String::from("aBc")])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidWord {
+                line: 2,
+                text: String::from("aBc")
+            })
+        );
     }
 }
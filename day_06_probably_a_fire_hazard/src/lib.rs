@@ -0,0 +1,10 @@
+// Day 6: Probably a Fire Hazard. Instructions turn on, turn off or toggle rectangles of lights in
+// a GRID_SIZE x GRID_SIZE grid.
+pub mod puzzle;
+
+pub use puzzle::action::Action;
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::instruction::Instruction;
+pub use puzzle::solution::{GRID_SIZE, Solution};
+pub use puzzler::grids::point::Point;
@@ -0,0 +1,43 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::parser::SyntaxError;
+
+use crate::puzzle::solution::GRID_SIZE;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    Syntax(SyntaxError),
+    InvalidAction {
+        line: usize,
+        text: String,
+    },
+    InvalidCoordinate {
+        line: usize,
+        field: &'static str,
+        text: String,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::InvalidAction { line, text } => {
+                write!(f, "Line {line}: invalid action '{text}'")
+            }
+            PuzzleError::InvalidCoordinate { line, field, text } => write!(
+                f,
+                "Line {line}: '{field}' shall be within 0..{GRID_SIZE}, found '{text}'"
+            ),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
@@ -0,0 +1,36 @@
+use common::random::Rng;
+
+use crate::puzzle::solution::GRID_SIZE;
+
+// Instructions over random rectangles of the grid, size is the number of instructions
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    (0..size)
+        .map(|_| {
+            let action = rng.choose(&["turn on", "turn off", "toggle"]);
+
+            let x1 = rng.range(0..=GRID_SIZE - 1);
+            let x2 = rng.range(x1..=GRID_SIZE - 1);
+            let y1 = rng.range(0..=GRID_SIZE - 1);
+            let y2 = rng.range(y1..=GRID_SIZE - 1);
+
+            format!("{action} {x1},{y1} through {x2},{y2}")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 20);
+        assert_eq!(lines, generate(7, 20));
+        assert_ne!(lines, generate(8, 20));
+
+        let instructions = Solution::parse_input(&lines).unwrap();
+        assert_eq!(instructions.len(), 20);
+    }
+}
@@ -2,7 +2,7 @@ use puzzler::grids::point::Point;
 
 use crate::puzzle::action::Action;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Instruction {
     pub action: Action,
     pub from: Point,
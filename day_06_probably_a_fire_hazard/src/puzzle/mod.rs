@@ -1,3 +1,5 @@
 pub mod action;
+pub mod error;
+pub mod generator;
 pub mod instruction;
 pub mod solution;
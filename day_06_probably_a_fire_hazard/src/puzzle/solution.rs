@@ -1,13 +1,17 @@
 use std::error::Error;
 
-use puzzler::env::project;
+use common::explain::Explain;
+use common::{input, parser};
 use puzzler::grids::point::Point;
-use puzzler::parsers::parser::Parser;
 use puzzler::puzzler::puzzle::Puzzle;
 
 use crate::puzzle::action::Action;
+use crate::puzzle::error::PuzzleError;
 use crate::puzzle::instruction::Instruction;
 
+// Lights are in a square GRID_SIZE x GRID_SIZE
+pub const GRID_SIZE: usize = 1000;
+
 pub struct Solution {
     instructions: Vec<Instruction>,
 }
@@ -18,72 +22,80 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_06.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_06.txt [{err}]")),
-        )
+        Some(input::file_path(6))
     }
 
     // Parse the file content for the puzzle. It is typically used in solve_partX() methods
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.instructions = Parser::parse_lines_with_regex(
-            lines,
-            r"^(turn on|turn off|toggle)\s(\d+),(\d+)\sthrough\s(\w+),(\w+)",
-            |params| {
-                if params.len() != 5 {
-                    return Err(format!(
-                        "Invalid number of parts '{}', expected '5'",
-                        params.len()
-                    )
-                    .into());
-                }
+        self.instructions = Self::parse_input(&lines)?;
+        Ok(())
+    }
+
+    // Solve first part of the puzzle
+    fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
+        Ok(Self::count_lights_on(&self.instructions).to_string())
+    }
 
+    // Solve second part of the puzzle
+    fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
+        Ok(Self::total_brightness(&self.instructions).to_string())
+    }
+}
+
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solution {
+    pub fn new() -> Self {
+        Self {
+            instructions: vec![],
+        }
+    }
+
+    // Every line is a single instruction, e.g. 'toggle 0,0 through 999,0'
+    pub fn parse_input(lines: &[String]) -> Result<Vec<Instruction>, PuzzleError> {
+        parser::parse_lines_with_regex(
+            lines,
+            r"^(turn on|turn off|toggle)\s(\d+),(\d+)\sthrough\s(\d+),(\d+)$",
+            |line, params| -> Result<_, PuzzleError> {
                 let action = match params[0].as_str() {
                     "turn on" => Action::TurnOn,
                     "turn off" => Action::TurnOff,
                     "toggle" => Action::Toggle,
-                    a => return Err(format!("Invalid action [{a}]").into()),
+                    a => {
+                        return Err(PuzzleError::InvalidAction {
+                            line,
+                            text: a.to_string(),
+                        });
+                    }
                 };
 
-                let from_start = params[1]
-                    .parse::<isize>()
-                    .map_err(|e| format!("Failed to parse 'from start' [{e}]"))?;
-
-                let from_end = params[2]
-                    .parse::<isize>()
-                    .map_err(|e| format!("Failed to parse 'from end' [{e}]"))?;
-
-                let to_start = params[3]
-                    .parse::<isize>()
-                    .map_err(|e| format!("Failed to parse 'to start' [{e}]"))?;
-
-                let to_end = params[4]
-                    .parse::<isize>()
-                    .map_err(|e| format!("Failed to parse 'to end' [{e}]"))?;
-
                 Ok(Instruction {
                     action,
                     from: Point {
-                        x: from_start,
-                        y: from_end,
+                        x: Self::parse_coordinate(line, "from start", &params[1])?,
+                        y: Self::parse_coordinate(line, "from end", &params[2])?,
                     },
                     to: Point {
-                        x: to_start,
-                        y: to_end,
+                        x: Self::parse_coordinate(line, "to start", &params[3])?,
+                        y: Self::parse_coordinate(line, "to end", &params[4])?,
                     },
                 })
             },
-        )?;
-        Ok(())
+        )
     }
 
-    // Solve first part of the puzzle
-    fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        // Lights are in a square 1000x1000
-        let mut lights = vec![vec![false; 1000]; 1000];
+    // Number of lights lit after all instructions, lights are either on or off
+    pub fn count_lights_on(instructions: &[Instruction]) -> usize {
+        let mut lights = vec![vec![false; GRID_SIZE]; GRID_SIZE];
 
         // Go through all instructions
-        for instr in &self.instructions {
+        for instr in instructions {
             // For every area mark the lights
             for row in instr.from.x..=instr.to.x {
                 for col in instr.from.y..=instr.to.y {
@@ -98,21 +110,15 @@ impl Puzzle for Solution {
             }
         }
 
-        Ok(lights
-            .iter()
-            .flatten()
-            .filter(|light| **light)
-            .count()
-            .to_string())
+        lights.iter().flatten().filter(|light| **light).count()
     }
 
-    // Solve second part of the puzzle
-    fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        // Lights are in a square 1000x1000
-        let mut lights = vec![vec![0_usize; 1000]; 1000];
+    // Total brightness after all instructions, lights have brightness instead of on or off
+    pub fn total_brightness(instructions: &[Instruction]) -> usize {
+        let mut lights = vec![vec![0_usize; GRID_SIZE]; GRID_SIZE];
 
         // Go through all instructions
-        for instr in &self.instructions {
+        for instr in instructions {
             // For every area mark the lights
             for row in instr.from.x..=instr.to.x {
                 for col in instr.from.y..=instr.to.y {
@@ -128,41 +134,75 @@ impl Puzzle for Solution {
             }
         }
 
-        Ok(lights.iter().flatten().sum::<usize>().to_string())
+        lights.iter().flatten().sum::<usize>()
     }
-}
 
-impl Solution {
-    pub fn new() -> Self {
-        Self {
-            instructions: vec![],
-        }
+    fn parse_coordinate(
+        line: usize,
+        field: &'static str,
+        text: &str,
+    ) -> Result<isize, PuzzleError> {
+        text.parse::<usize>()
+            .ok()
+            .filter(|&value| value < GRID_SIZE)
+            .map(|value| value as isize)
+            .ok_or_else(|| PuzzleError::InvalidCoordinate {
+                line,
+                field,
+                text: text.to_string(),
+            })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "377891");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "377891");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "14110788");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "14110788");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![
+                String::from("turn on 0,0 through 999,999"),
+                String::from("toggle 0,0 through 1000,5"),
+            ])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidCoordinate {
+                line: 2,
+                field: "to start",
+                text: String::from("1000")
+            })
+        );
     }
 }
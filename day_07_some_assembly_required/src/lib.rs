@@ -0,0 +1,9 @@
+// Day 7: Some Assembly Required. Circuit of wires connected by bitwise logic gates, signals
+// of all wires are calculated by Solution::execute_blueprints().
+pub mod puzzle;
+
+pub use puzzle::blueprint::Blueprint;
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::instruction::Instruction;
+pub use puzzle::solution::Solution;
@@ -1,6 +1,6 @@
 use crate::puzzle::instruction::Instruction;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Blueprint {
     pub instruction: Instruction,
     pub wire: String,
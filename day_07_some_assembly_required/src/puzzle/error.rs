@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    InvalidBlueprint { line: usize, text: String },
+    UnresolvedWires { wires: Vec<String> },
+    MissingWire { wire: String },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::InvalidBlueprint { line, text } => {
+                write!(f, "Line {line}: unsupported blueprint '{text}'")
+            }
+            PuzzleError::UnresolvedWires { wires } => {
+                write!(f, "Signals of wires can not be resolved {wires:?}")
+            }
+            PuzzleError::MissingWire { wire } => write!(f, "Wire '{wire}' has no signal"),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
@@ -0,0 +1,71 @@
+use common::random::Rng;
+
+// Wiring diagram of size wires. Wire 'b' is a constant signal as it is overridden in part 2,
+// wire 'a' is the last one. Every wire is connected only to wires created before it,
+// therefore the circuit is acyclic and all signals can be calculated.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let size = size.max(2);
+
+    let names = (0..size)
+        .map(|index| match index {
+            0 => "b".to_string(),
+            _ if index == size - 1 => "a".to_string(),
+            _ => wire_name(index),
+        })
+        .collect::<Vec<_>>();
+
+    let mut lines = vec![format!("{} -> b", rng.range(0..=u16::MAX as usize))];
+
+    for index in 1..size {
+        let mut input = || names[rng.range(0..=index - 1)].clone();
+        let (a, b) = (input(), input());
+
+        let instruction = match rng.range(0..=5) {
+            0 => a,
+            1 => format!("NOT {a}"),
+            2 => format!("{a} AND {b}"),
+            3 => format!("{a} OR {b}"),
+            4 => format!("{a} LSHIFT {}", rng.range(1..=15)),
+            _ => format!("{a} RSHIFT {}", rng.range(1..=15)),
+        };
+
+        lines.push(format!("{instruction} -> {}", names[index]));
+    }
+
+    // Order of blueprints does not matter
+    rng.shuffle(&mut lines);
+    lines
+}
+
+// At least two lowercase letters, therefore it never clashes with wires 'a' and 'b'
+fn wire_name(index: usize) -> String {
+    let mut number = index + 26;
+    let mut name = Vec::new();
+
+    while number > 0 {
+        name.push(b'a' + (number % 26) as u8);
+        number /= 26;
+    }
+
+    name.iter().rev().map(|b| *b as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 200);
+        assert_eq!(lines, generate(7, 200));
+        assert_ne!(lines, generate(8, 200));
+
+        let blueprints = Solution::parse_input(&lines).unwrap();
+        assert_eq!(blueprints.len(), 200);
+
+        let mut solution = Solution::from_blueprints(blueprints);
+        solution.execute_blueprints().unwrap();
+        assert!(solution.get_wire_signal("a").is_some());
+    }
+}
@@ -1,3 +1,5 @@
 pub mod blueprint;
+pub mod error;
+pub mod generator;
 pub mod instruction;
 pub mod solution;
@@ -1,10 +1,13 @@
 use std::collections::{HashMap, VecDeque};
 use std::error::Error;
 
-use puzzler::env::project;
+use common::explain::Explain;
+use common::input;
+use common::log_trace;
 use puzzler::puzzler::puzzle::Puzzle;
 
 use crate::puzzle::blueprint::Blueprint;
+use crate::puzzle::error::PuzzleError;
 use crate::puzzle::instruction::Instruction;
 
 pub struct Solution {
@@ -18,41 +21,38 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_07.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_07.txt [{err}]")),
-        )
+        Some(input::file_path(7))
     }
 
     // Parse the file content for the puzzle
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.blueprints = lines
-            .iter()
-            .map(|line| Solution::parse_blueprint(line))
-            .collect::<Result<Vec<_>, _>>()?;
+        self.blueprints = Self::parse_input(&lines)?;
         Ok(())
     }
 
     // Solve first part of the puzzle
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        self.execute_blueprints();
+        self.execute_blueprints()?;
 
-        let signal_wire_a = self.get_wire_signal("a").unwrap();
+        let signal_wire_a = self.get_required_signal("a")?;
         Ok(signal_wire_a.to_string())
     }
 
     // Solve second part of the puzzle
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        // Prepare
-        self.wires.clear();
-        self.wires.insert("b".to_string(), 46065);
-        self.execute_blueprints();
-
-        let signal_wire_a = self.get_wire_signal("a").unwrap();
+        let signal_wire_a = self.execute_with_feedback("a", "b")?;
         Ok(signal_wire_a.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -61,72 +61,99 @@ impl Solution {
         }
     }
 
-    pub fn parse_blueprint(line: &str) -> Result<Blueprint, Box<dyn Error>> {
-        // Split line to seperate instruction and destination wire
-        let instructions = line.split_terminator("->").collect::<Vec<_>>();
-        if instructions.len() != 2 {
-            return Err(format!(
-                "Blueprint shall have exactly 2 parts, found {} '{line}'",
-                instructions.len(),
-            )
-            .into());
+    // Circuit of blueprints ready to be executed
+    pub fn from_blueprints(blueprints: Vec<Blueprint>) -> Self {
+        Self {
+            blueprints,
+            wires: HashMap::new(),
         }
+    }
+
+    // Every line is a single blueprint, e.g. 'x AND y -> d'
+    pub fn parse_input(lines: &[String]) -> Result<Vec<Blueprint>, PuzzleError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                Solution::parse_blueprint(line).ok_or_else(|| PuzzleError::InvalidBlueprint {
+                    line: index + 1,
+                    text: line.clone(),
+                })
+            })
+            .collect()
+    }
+
+    // Returns None for line which is not a valid blueprint
+    pub fn parse_blueprint(line: &str) -> Option<Blueprint> {
+        // Split line to seperate instruction and destination wire
+        let (instruction, wire) = line.split_once("->")?;
 
         // Fetch name of the destination wire
-        let wire = instructions[1].trim().to_string();
+        let wire = wire.trim().to_string();
+        if wire.is_empty() {
+            return None;
+        }
 
         // Arguments depends on instruction type
-        let args = instructions[0].split_terminator(" ").collect::<Vec<_>>();
-
-        let blueprint = if line.contains("AND") {
-            Blueprint {
-                instruction: Instruction::And(args[0].to_string(), args[2].to_string()),
-                wire,
-            }
-        } else if line.contains("OR") {
-            Blueprint {
-                instruction: Instruction::Or(args[0].to_string(), args[2].to_string()),
-                wire,
-            }
-        } else if line.contains("LSHIFT") {
-            Blueprint {
-                instruction: Instruction::Lshift(args[0].to_string(), args[2].to_string()),
-                wire,
-            }
-        } else if line.contains("RSHIFT") {
-            Blueprint {
-                instruction: Instruction::Rshift(args[0].to_string(), args[2].to_string()),
-                wire,
-            }
-        } else if line.contains("NOT") {
-            Blueprint {
-                instruction: Instruction::Not(args[1].to_string()),
-                wire,
-            }
-        } else if instructions.len() == 2 {
-            Blueprint {
-                instruction: Instruction::Signal(args[0].to_string()),
-                wire,
-            }
-        } else {
-            return Err(format!("Unsupported blueprint '{line}'").into());
+        let args = instruction.split_whitespace().collect::<Vec<_>>();
+
+        let instruction = match args.as_slice() {
+            [a, "AND", b] => Instruction::And(a.to_string(), b.to_string()),
+            [a, "OR", b] => Instruction::Or(a.to_string(), b.to_string()),
+            [a, "LSHIFT", b] => Instruction::Lshift(a.to_string(), b.to_string()),
+            [a, "RSHIFT", b] => Instruction::Rshift(a.to_string(), b.to_string()),
+            ["NOT", a] => Instruction::Not(a.to_string()),
+            [a] => Instruction::Signal(a.to_string()),
+            _ => return None,
         };
 
-        Ok(blueprint)
+        Some(Blueprint { instruction, wire })
     }
 
+    // Signal of the wire, available after execute_blueprints()
     pub fn get_wire_signal(&self, wire: &str) -> Option<&u16> {
         self.wires.get(wire)
     }
 
-    fn execute_blueprints(&mut self) {
+    // Provide signal of the wire up front, blueprints do not overwrite it
+    pub fn set_wire_signal(&mut self, wire: &str, signal: u16) {
+        self.wires.insert(wire.to_string(), signal);
+    }
+
+    // Signal of the output wire after the circuit is executed again with the input wire
+    // overridden by the signal of the output wire from the first run on fresh wires
+    pub fn execute_with_feedback(&mut self, output: &str, input: &str) -> Result<u16, PuzzleError> {
+        self.wires.clear();
+        self.execute_blueprints()?;
+        let signal = self.get_required_signal(output)?;
+
+        self.wires.clear();
+        self.set_wire_signal(input, signal);
+        self.execute_blueprints()?;
+
+        self.get_required_signal(output)
+    }
+
+    fn get_required_signal(&self, wire: &str) -> Result<u16, PuzzleError> {
+        self.get_wire_signal(wire)
+            .copied()
+            .ok_or_else(|| PuzzleError::MissingWire {
+                wire: wire.to_string(),
+            })
+    }
+
+    // Calculate signals of all wires
+    pub fn execute_blueprints(&mut self) -> Result<(), PuzzleError> {
         // Blueprint can be executed only of all input wires has signals.
         // Repeat the calculation until we have blueprints left.
         let mut queue = self.blueprints.iter().cloned().collect::<VecDeque<_>>();
 
+        // Number of blueprints postponed in a row, if all of them are postponed
+        // there is no way to make a progress
+        let mut postponed = 0;
+
         while let Some(blueprint) = queue.pop_front() {
-            // println!("len: {}", queue.len());
-            // println!("blueprint: {:?}", blueprint);
+            log_trace!("Blueprint {blueprint:?}, {} left in the queue", queue.len());
 
             let value: Option<u16> = match &blueprint.instruction {
                 Instruction::Signal(signal) => self.get_signal_value(signal),
@@ -156,7 +183,8 @@ impl Solution {
                     let shift = self.get_signal_value(shift);
 
                     if let (Some(a), Some(shift)) = (a, shift) {
-                        Some(a << shift)
+                        // Shifting out all bits yields zero
+                        Some(a.checked_shl(shift.into()).unwrap_or(0))
                     } else {
                         None
                     }
@@ -166,7 +194,7 @@ impl Solution {
                     let shift = self.get_signal_value(shift);
 
                     if let (Some(a), Some(shift)) = (a, shift) {
-                        Some(a >> shift)
+                        Some(a.checked_shr(shift.into()).unwrap_or(0))
                     } else {
                         None
                     }
@@ -177,10 +205,20 @@ impl Solution {
                 // Do not insert signal if it is already present - this trick will help to solve part 2,
                 // because it will skip overwrite of hardcoded value for signal b.
                 self.wires.entry(blueprint.wire).or_insert(number);
+                postponed = 0;
             } else {
                 queue.push_back(blueprint);
+                postponed += 1;
+
+                if postponed == queue.len() {
+                    return Err(PuzzleError::UnresolvedWires {
+                        wires: queue.into_iter().map(|b| b.wire).collect(),
+                    });
+                }
             }
         }
+
+        Ok(())
     }
 
     fn get_signal_value(&self, signal: &str) -> Option<u16> {
@@ -197,28 +235,57 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "46065");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "46065");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "14134");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "14134");
+    }
+
+    // Circuit of the puzzle statement has no wire 'a'
+    #[test]
+    fn test_examples() {
+        let lines = fixtures::read_example(env!("CARGO_MANIFEST_DIR"), "example_01.txt");
+        let mut solution = Solution::from_blueprints(Solution::parse_input(&lines).unwrap());
+
+        solution.execute_blueprints().unwrap();
+        let signals = ["d", "e", "f", "g", "h", "i", "x", "y"]
+            .map(|wire| solution.get_wire_signal(wire).copied());
+
+        assert_eq!(
+            signals,
+            [72, 507, 492, 114, 65412, 65079, 123, 456].map(Some)
+        );
+        assert_eq!(solution.get_wire_signal("a"), None);
+
+        // Signal of wire 'h' overrides wire 'x', i.e. 'NOT x' is the original signal of 'x'
+        assert_eq!(solution.execute_with_feedback("h", "x"), Ok(123));
+        assert_eq!(solution.get_wire_signal("x"), Some(&65412));
+        assert_eq!(solution.get_wire_signal("d"), Some(&(65412 & 456)));
+        assert_eq!(
+            solution.execute_with_feedback("a", "x"),
+            Err(PuzzleError::MissingWire {
+                wire: String::from("a")
+            })
+        );
     }
 
     #[test]
@@ -235,7 +302,7 @@ mod tests {
             Solution::parse_blueprint("NOT y -> i").unwrap(),
         ];
 
-        solution.execute_blueprints();
+        solution.execute_blueprints().unwrap();
         assert_eq!(solution.get_wire_signal("d"), Some(&72));
         assert_eq!(solution.get_wire_signal("e"), Some(&507));
         assert_eq!(solution.get_wire_signal("f"), Some(&492));
@@ -245,4 +312,36 @@ mod tests {
         assert_eq!(solution.get_wire_signal("x"), Some(&123));
         assert_eq!(solution.get_wire_signal("y"), Some(&456));
     }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![String::from("123 -> x"), String::from("x AND -> y")])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidBlueprint {
+                line: 2,
+                text: String::from("x AND -> y")
+            })
+        );
+    }
+
+    #[test]
+    fn test_execute_blueprint_unresolved() {
+        let mut solution = Solution::new();
+        solution.blueprints = vec![
+            Solution::parse_blueprint("123 -> x").unwrap(),
+            Solution::parse_blueprint("x AND z -> y").unwrap(),
+        ];
+
+        assert_eq!(
+            solution.execute_blueprints(),
+            Err(PuzzleError::UnresolvedWires {
+                wires: vec![String::from("y")]
+            })
+        );
+    }
 }
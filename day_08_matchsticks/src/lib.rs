@@ -0,0 +1,6 @@
+// Day 8: Matchsticks. Lengths of string literals in code, in memory and after encoding.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
@@ -0,0 +1,19 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    InvalidString { line: usize, text: String },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::InvalidString { line, text } => {
+                write!(f, "Line {line}: invalid string literal '{text}'")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
@@ -0,0 +1,37 @@
+use common::random::Rng;
+
+// String literals with escaped backslashes, quotes and hexadecimal characters, size is the
+// number of literals
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    (0..size)
+        .map(|_| {
+            let content = (0..rng.range(0..=30))
+                .map(|_| match rng.range(0..=9) {
+                    0 => r"\\".to_string(),
+                    1 => r#"\""#.to_string(),
+                    2 => format!(r"\x{:02x}", rng.range(0..=255)),
+                    _ => rng.lowercase(1),
+                })
+                .collect::<String>();
+
+            format!("\"{content}\"")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 50);
+        assert_eq!(lines, generate(7, 50));
+        assert_ne!(lines, generate(8, 50));
+
+        let words = Solution::parse_input(lines).unwrap();
+        assert_eq!(words.len(), 50);
+    }
+}
@@ -1,8 +1,11 @@
 use std::error::Error;
 
-use puzzler::env::project;
+use common::explain::Explain;
+use common::input;
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
+
 pub struct Solution {
     words: Vec<String>,
 }
@@ -13,23 +16,16 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_08.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_08.txt [{err}]")),
-        )
+        Some(input::file_path(8))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.words = lines;
+        self.words = Self::parse_input(lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let sum = self
-            .words
-            .iter()
-            .map(|word| Self::get_count(word))
-            .sum::<usize>();
+        let sum = Self::sum_counts(&self.words)?;
 
         Ok(sum.to_string())
     }
@@ -45,33 +41,77 @@ impl Puzzle for Solution {
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self { words: vec![] }
     }
 
-    fn get_count(value: &str) -> usize {
-        value.len() - Self::interpreted_len(value)
+    // Every line is a single string literal including the quotes
+    pub fn parse_input(lines: Vec<String>) -> Result<Vec<String>, PuzzleError> {
+        // Reject malformed string literals early
+        Self::sum_counts(&lines)?;
+
+        Ok(lines)
+    }
+
+    // Characters of code minus characters in memory of all the string literals
+    pub fn sum_counts(words: &[String]) -> Result<usize, PuzzleError> {
+        words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                Self::get_count(word).ok_or_else(|| PuzzleError::InvalidString {
+                    line: index + 1,
+                    text: word.clone(),
+                })
+            })
+            .sum()
     }
 
-    fn interpreted_len(value: &str) -> usize {
+    fn get_count(value: &str) -> Option<usize> {
+        Self::interpreted_len(value).map(|length| value.len() - length)
+    }
+
+    // Returns None for value which is not a valid string literal
+    fn interpreted_len(value: &str) -> Option<usize> {
+        // Do not count leading and trailing "
+        let bytes = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))?
+            .as_bytes();
+
         let mut length = 0;
         let mut i = 0;
-        let bytes = value.as_bytes();
 
         while i < bytes.len() {
             if bytes[i] == b'\\' {
                 // Two options
-                match bytes[i + 1] {
+                match bytes.get(i + 1)? {
                     b'x' => {
                         // hex value
+                        let hex = bytes.get(i + 2..i + 4)?;
+                        if !hex.iter().all(|b| b.is_ascii_hexdigit()) {
+                            return None;
+                        }
                         i += 4;
                     }
-                    _ => {
+                    b'\\' | b'"' => {
                         // escaped char
                         i += 2;
                     }
+                    _ => return None,
                 }
+            } else if bytes[i] == b'"' {
+                // unescaped quote
+                return None;
             } else {
                 // regular char
                 i += 1;
@@ -79,10 +119,11 @@ impl Solution {
             length += 1;
         }
 
-        length - 2 // Do not count leading and trailing "
+        Some(length)
     }
 
-    fn get_encoded_count(word: &str) -> usize {
+    // Characters of the encoded string literal minus characters of code of the original one
+    pub fn get_encoded_count(word: &str) -> usize {
         Self::encoded_len(word) - word.len()
     }
 
@@ -102,40 +143,74 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "1371");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "1371");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "2117");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "2117");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
     fn test_get_count() {
-        assert_eq!(Solution::get_count(r#""""#), 2);
-        assert_eq!(Solution::get_count(r#""abc""#), 2);
-        assert_eq!(Solution::get_count(r#""aaa\"aaa""#), 3);
-        assert_eq!(Solution::get_count(r#""\x27""#), 5);
-        assert_eq!(Solution::get_count(r#""qludrkkvljljd\\xvdeum\x4e""#), 6);
+        assert_eq!(Solution::get_count(r#""""#), Some(2));
+        assert_eq!(Solution::get_count(r#""abc""#), Some(2));
+        assert_eq!(Solution::get_count(r#""aaa\"aaa""#), Some(3));
+        assert_eq!(Solution::get_count(r#""\x27""#), Some(5));
+        assert_eq!(
+            Solution::get_count(r#""qludrkkvljljd\\xvdeum\x4e""#),
+            Some(6)
+        );
         assert_eq!(
             Solution::get_count(r#""\"ihjqlhtwbuy\"hdkiv\"mtiqacnf\\""#),
-            6
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn test_get_count_invalid() {
+        assert_eq!(Solution::get_count(r#""abc\""#), None);
+        assert_eq!(Solution::get_count(r#""\x2""#), None);
+        assert_eq!(Solution::get_count(r#""\xzz""#), None);
+        assert_eq!(Solution::get_count(r#"abc"#), None);
+        assert_eq!(Solution::get_count(r#"""#), None);
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![String::from(r#""abc""#), String::from(r#""abc\""#)])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidString {
+                line: 2,
+                text: String::from(r#""abc\""#)
+            })
         );
     }
 
@@ -0,0 +1,7 @@
+// Day 9: All in a Single Night. Shortest and longest route of Santa visiting all the cities,
+// routes are created by Solution::parse_input() and searched by Solution::from_routes().
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::{Routes, Solution};
@@ -0,0 +1,51 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::budget::Exceeded;
+use common::parser::SyntaxError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    Syntax(SyntaxError),
+    InvalidNumber {
+        line: usize,
+        field: &'static str,
+        text: String,
+    },
+    MissingRoute {
+        from: String,
+        to: String,
+    },
+    NoRoutes,
+    BudgetExceeded {
+        exceeded: Exceeded,
+        best: Option<usize>,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::InvalidNumber { line, field, text } => {
+                write!(f, "Line {line}: failed to parse '{field}' from '{text}'")
+            }
+            PuzzleError::MissingRoute { from, to } => {
+                write!(f, "Route ({from},{to}) not found in routes")
+            }
+            PuzzleError::NoRoutes => write!(f, "No routes found"),
+            PuzzleError::BudgetExceeded { exceeded, best } => match best {
+                Some(best) => write!(f, "{exceeded}, best distance found so far '{best}'"),
+                None => write!(f, "{exceeded}, no route found so far"),
+            },
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
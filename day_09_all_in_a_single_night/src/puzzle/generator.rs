@@ -0,0 +1,33 @@
+use common::random::{self, Rng};
+
+// Complete table of distances between size cities. Every route is tried by the solution,
+// therefore sizes above ten take long.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let cities = (0..size.max(2)).map(random::name).collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    for (index, from) in cities.iter().enumerate() {
+        for to in &cities[index + 1..] {
+            lines.push(format!("{from} to {to} = {}", rng.range(1..=200)));
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 6);
+        assert_eq!(lines, generate(7, 6));
+        assert_ne!(lines, generate(8, 6));
+        assert_eq!(lines.len(), 15);
+
+        let solution = Solution::from_routes(Solution::parse_input(&lines).unwrap());
+        assert!(solution.calculate_path_min().unwrap() <= solution.calculate_path_max().unwrap());
+    }
+}
@@ -1,13 +1,19 @@
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 
+use common::budget::{self, Budget};
+use common::explain::{Explain, Witness};
+use common::{input, parser};
 use itertools::Itertools;
-use puzzler::env::project;
-use puzzler::parsers::parser::Parser;
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
+
+// Distances between pairs of cities, stored in both directions
+pub type Routes = HashMap<(String, String), usize>;
+
 pub struct Solution {
-    routes: HashMap<(String, String), usize>,
+    routes: Routes,
 }
 
 impl Puzzle for Solution {
@@ -16,50 +22,56 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_09.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_09.txt [{err}]")),
-        )
+        Some(input::file_path(9))
     }
 
     // Parse the file content for the puzzle
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        let routes =
-            Parser::parse_lines_with_regex(lines, r"^(\w+)\s+to\s+(\w+)\s+=\s+(\d+)", |params| {
-                if params.len() != 3 {
-                    return Err(
-                        format!("Invalid number of params '{}', expected 3", params.len()).into(),
-                    );
-                }
-
-                let distance = params[2]
-                    .parse::<usize>()
-                    .map_err(|err| format!("Failed to parse distance '{}' [{err}]", params[2]))?;
-
-                Ok(((params[0].to_string(), params[1].to_string()), distance))
-            })?;
-
-        // Store both key combination
-        for ((a, b), distance) in routes {
-            self.routes.insert((a.clone(), b.clone()), distance);
-            self.routes.insert((b, a), distance);
-        }
+        self.routes = Self::parse_input(&lines)?;
         Ok(())
     }
 
     // Solve first part of the puzzle
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let min = self.calculate_path_min();
+        let min = self.calculate_path_min()?;
         Ok(min.to_string())
     }
 
     // Solve second part of the puzzle
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let max = self.calculate_path_max();
+        let max = self.calculate_path_max()?;
         Ok(max.to_string())
     }
 }
 
+impl Explain for Solution {
+    // Shortest route with its distance
+    fn explain_part1(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        Some(
+            self.find_route(false)
+                .map(route_witness)
+                .map_err(Into::into),
+        )
+    }
+
+    // Longest route with its distance
+    fn explain_part2(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        Some(self.find_route(true).map(route_witness).map_err(Into::into))
+    }
+}
+
+fn route_witness((route, distance): (Vec<String>, usize)) -> Witness {
+    Witness::new()
+        .with("route", route)
+        .with("distance", distance)
+}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -67,16 +79,95 @@ impl Solution {
         }
     }
 
-    fn calculate_path_min(&self) -> usize {
-        let cities = self.get_all_cities();
-        let paths = self.calculate_all_paths(&cities);
-        *paths.iter().min().unwrap()
+    pub fn from_routes(routes: Routes) -> Self {
+        Self { routes }
+    }
+
+    // Every line is a distance of two cities, e.g. 'London to Dublin = 464'
+    pub fn parse_input(lines: &[String]) -> Result<Routes, PuzzleError> {
+        let routes = parser::parse_lines_with_regex(
+            lines,
+            r"^(\w+)\s+to\s+(\w+)\s+=\s+(\d+)$",
+            |line, params| -> Result<_, PuzzleError> {
+                let distance =
+                    params[2]
+                        .parse::<usize>()
+                        .map_err(|_| PuzzleError::InvalidNumber {
+                            line,
+                            field: "distance",
+                            text: params[2].clone(),
+                        })?;
+
+                Ok(((params[0].to_string(), params[1].to_string()), distance))
+            },
+        )?;
+
+        // Store both key combination
+        let mut both = Routes::new();
+        for ((a, b), distance) in routes {
+            both.insert((a.clone(), b.clone()), distance);
+            both.insert((b, a), distance);
+        }
+        Ok(both)
+    }
+
+    // Length of the shortest route visiting every city exactly once
+    pub fn calculate_path_min(&self) -> Result<usize, PuzzleError> {
+        self.find_route(false).map(|(_, distance)| distance)
     }
 
-    fn calculate_path_max(&self) -> usize {
-        let cities = self.get_all_cities();
-        let paths = self.calculate_all_paths(&cities);
-        *paths.iter().max().unwrap()
+    // Length of the longest route visiting every city exactly once
+    pub fn calculate_path_max(&self) -> Result<usize, PuzzleError> {
+        self.find_route(true).map(|(_, distance)| distance)
+    }
+
+    // Shortest or longest route visiting every city exactly once together with its distance.
+    // Cities are permuted in sorted order and only a strictly better route replaces the best
+    // one, therefore the same route is returned for the same input. Search is limited by the
+    // budget of the thread.
+    pub fn find_route(&self, longest: bool) -> Result<(Vec<String>, usize), PuzzleError> {
+        self.search_route(longest, &budget::current())
+    }
+
+    // Every permutation of cities is a single step of the budget
+    fn search_route(
+        &self,
+        longest: bool,
+        budget: &Budget,
+    ) -> Result<(Vec<String>, usize), PuzzleError> {
+        let mut meter = budget.meter();
+        let mut cities = self.get_all_cities();
+        cities.sort();
+
+        if cities.is_empty() {
+            return Err(PuzzleError::NoRoutes);
+        }
+
+        let mut best: Option<(Vec<String>, usize)> = None;
+
+        for path in cities.iter().permutations(cities.len()) {
+            meter
+                .step()
+                .map_err(|exceeded| PuzzleError::BudgetExceeded {
+                    exceeded,
+                    best: best.as_ref().map(|(_, distance)| *distance),
+                })?;
+
+            let distance = self.calculate_distance(&path)?;
+
+            let better = match &best {
+                None => true,
+                Some((_, best)) if longest => distance > *best,
+                Some((_, best)) => distance < *best,
+            };
+
+            if better {
+                let route = path.iter().map(|city| city.to_string()).collect();
+                best = Some((route, distance));
+            }
+        }
+
+        best.ok_or(PuzzleError::NoRoutes)
     }
 
     fn get_all_cities(&self) -> Vec<&String> {
@@ -90,54 +181,53 @@ impl Solution {
         unique.into_iter().collect()
     }
 
-    fn calculate_all_paths(&self, cities: &[&String]) -> Vec<usize> {
-        // Generate all permutataions
-        let paths = cities.iter().permutations(cities.len()).collect::<Vec<_>>();
-
-        // Calculate distance for a given paths
-        paths
-            .iter()
-            .map(|path| self.calculate_distance(path))
-            .collect::<Vec<_>>()
-    }
-
-    fn calculate_distance(&self, path: &[&&String]) -> usize {
+    fn calculate_distance(&self, path: &[&&String]) -> Result<usize, PuzzleError> {
         path.windows(2)
             .map(|w| {
-                let dist = self
-                    .routes
+                self.routes
                     .get(&(w[0].to_string(), w[1].to_string()))
-                    .unwrap_or_else(|| panic!("Key ({},{}) not found in routes", w[0], w[1]));
-                *dist
+                    .copied()
+                    .ok_or_else(|| PuzzleError::MissingRoute {
+                        from: w[0].to_string(),
+                        to: w[1].to_string(),
+                    })
             })
-            .sum::<usize>()
+            .sum()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::budget::{Budget, Exceeded, Limit, Limits};
+    use common::explain::Explain;
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "207");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "207");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "804");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "804");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     fn build_routes() -> Vec<((String, String), usize)> {
@@ -157,7 +247,7 @@ mod tests {
         let mut solution = Solution::new();
         solution.routes = routes.into_iter().collect();
 
-        assert_eq!(solution.calculate_path_min(), 605);
+        assert_eq!(solution.calculate_path_min(), Ok(605));
     }
 
     #[test]
@@ -166,6 +256,68 @@ mod tests {
         let mut solution = Solution::new();
         solution.routes = routes.into_iter().collect();
 
-        assert_eq!(solution.calculate_path_max(), 982);
+        assert_eq!(solution.calculate_path_max(), Ok(982));
+    }
+
+    #[test]
+    fn test_find_route() {
+        let mut solution = Solution::from_routes(build_routes().into_iter().collect());
+
+        assert_eq!(
+            solution.find_route(false),
+            Ok((
+                vec![
+                    String::from("Belfast"),
+                    String::from("Dublin"),
+                    String::from("London")
+                ],
+                605
+            ))
+        );
+
+        let witness = solution.explain_part2().unwrap().unwrap();
+        assert_eq!(
+            witness.to_string(),
+            "route: [Belfast, London, Dublin]; distance: 982"
+        );
+        assert_eq!(Solution::new().find_route(true), Err(PuzzleError::NoRoutes));
+    }
+
+    #[test]
+    fn test_search_route_budget_exceeded() {
+        let solution = Solution::from_routes(build_routes().into_iter().collect());
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(2),
+        });
+
+        assert_eq!(
+            solution.search_route(false, &budget),
+            Err(PuzzleError::BudgetExceeded {
+                exceeded: Exceeded {
+                    limit: Limit::Steps(2),
+                    steps: 2
+                },
+                best: Some(605)
+            })
+        );
+    }
+
+    #[test]
+    fn test_calculate_path_missing_route() {
+        let mut routes = build_routes();
+        routes.truncate(4);
+
+        let mut solution = Solution::new();
+        solution.routes = routes.into_iter().collect();
+
+        assert!(matches!(
+            solution.calculate_path_min(),
+            Err(PuzzleError::MissingRoute { .. })
+        ));
+        assert_eq!(
+            Solution::new().calculate_path_max(),
+            Err(PuzzleError::NoRoutes)
+        );
     }
 }
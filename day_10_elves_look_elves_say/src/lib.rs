@@ -0,0 +1,6 @@
+// Day 10: Elves Look, Elves Say. Look-and-say sequences.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
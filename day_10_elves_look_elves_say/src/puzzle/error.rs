@@ -0,0 +1,27 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    LineCount { found: usize },
+    InvalidSequence { line: usize, text: String },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::LineCount { found } => write!(
+                f,
+                "Exactly one line is expected in input file, but '{found}' found"
+            ),
+            PuzzleError::InvalidSequence { line, text } => {
+                write!(
+                    f,
+                    "Line {line}: sequence shall contain only digits '{text}'"
+                )
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
@@ -0,0 +1,28 @@
+use common::random::Rng;
+
+// Sequence of digits 1 to 3 as in the puzzle input, size is the number of digits
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    let digits = (0..size.max(1))
+        .map(|_| *rng.choose(&['1', '2', '3']))
+        .collect();
+
+    vec![digits]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 10);
+        assert_eq!(lines, generate(7, 10));
+        assert_ne!(lines, generate(8, 10));
+
+        let word = Solution::parse_input(lines).unwrap();
+        assert_eq!(word.len(), 10);
+        assert!(Solution::repeat_look_and_say(&word, 5) > 0);
+    }
+}
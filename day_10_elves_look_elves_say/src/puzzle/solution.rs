@@ -1,33 +1,79 @@
 use std::error::Error;
+use std::path::PathBuf;
 
+use common::explain::Explain;
+use common::input;
 use puzzler::puzzler::puzzle::Puzzle;
 
-pub struct Solution {}
+use crate::puzzle::error::PuzzleError;
+
+pub struct Solution {
+    sequence: String,
+}
 
 impl Puzzle for Solution {
     fn name(&self) -> &str {
         "--- Day 10: Elves Look, Elves Say ---"
     }
 
+    fn get_input_file_path(&self) -> Option<PathBuf> {
+        Some(input::file_path(10))
+    }
+
+    fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
+        self.sequence = Self::parse_input(lines)?;
+        Ok(())
+    }
+
     // Solve first part of the puzzle
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let length = Self::repeat_look_and_say("1321131112", 40);
+        let length = Self::repeat_look_and_say(&self.sequence, 40);
         Ok(length.to_string())
     }
 
     // Solve second part of the puzzle
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let length = Self::repeat_look_and_say("1321131112", 50);
+        let length = Self::repeat_look_and_say(&self.sequence, 50);
         Ok(length.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            sequence: String::new(),
+        }
     }
 
-    fn repeat_look_and_say(word: &str, repetitions: usize) -> usize {
+    // Sequence is a single line of digits
+    pub fn parse_input(lines: Vec<String>) -> Result<String, PuzzleError> {
+        if lines.len() != 1 {
+            return Err(PuzzleError::LineCount { found: lines.len() });
+        }
+
+        let sequence = lines.into_iter().next().unwrap().trim().to_string();
+
+        // Look-and-say works only with digits
+        if sequence.is_empty() || !sequence.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(PuzzleError::InvalidSequence {
+                line: 1,
+                text: sequence,
+            });
+        }
+
+        Ok(sequence)
+    }
+
+    // Length of the sequence after the given number of look-and-say rounds
+    pub fn repeat_look_and_say(word: &str, repetitions: usize) -> usize {
         let mut word = word.to_string();
 
         for _ in 0..repetitions {
@@ -37,7 +83,8 @@ impl Solution {
         word.len()
     }
 
-    fn look_and_say(word: &str) -> String {
+    // Single look-and-say round, e.g. '1211' -> '111221'
+    pub fn look_and_say(word: &str) -> String {
         let bytes = word.as_bytes();
         let mut new_word = String::with_capacity(word.len() * 2);
         let mut start = 0;
@@ -64,28 +111,38 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "492982");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "492982");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "6989950");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "6989950");
+    }
+
+    // Puzzle statement applies only 5 repetitions, e.g. '1' becomes '312211'
+    #[test]
+    fn test_examples() {
+        let lines = fixtures::read_example(env!("CARGO_MANIFEST_DIR"), "example_01.txt");
+        let sequence = Solution::parse_input(lines).unwrap();
+
+        assert_eq!(Solution::repeat_look_and_say(&sequence, 5), 6);
     }
 
     #[test]
@@ -96,4 +153,20 @@ mod tests {
         assert_eq!(Solution::look_and_say("1211"), String::from("111221"));
         assert_eq!(Solution::look_and_say("111221"), String::from("312211"));
     }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![String::from("12a3")])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidSequence {
+                line: 1,
+                text: String::from("12a3")
+            })
+        );
+    }
 }
@@ -0,0 +1,7 @@
+// Day 11: Corporate Policy. Santa's next password is found by incrementing the current one
+// until it meets the security requirements.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    LineCount { found: usize },
+    InvalidPassword { line: usize, text: String },
+    PasswordExhausted { password: String },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::LineCount { found } => write!(
+                f,
+                "Exactly one line is expected in input file, but '{found}' found"
+            ),
+            PuzzleError::InvalidPassword { line, text } => write!(
+                f,
+                "Line {line}: password shall contain only lowercase letters '{text}'"
+            ),
+            PuzzleError::PasswordExhausted { password } => {
+                write!(f, "No secure password follows '{password}'")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
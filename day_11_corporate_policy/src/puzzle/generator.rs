@@ -0,0 +1,25 @@
+use common::random::Rng;
+
+// Passwords are exactly eight letters long
+const PASSWORD_LENGTH: usize = 8;
+
+// Current password of lowercase letters. Length is given by the puzzle, therefore size is
+// ignored.
+pub fn generate(seed: u64, _size: usize) -> Vec<String> {
+    vec![Rng::new(seed).lowercase(PASSWORD_LENGTH)]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 1);
+        assert_eq!(lines, generate(7, 100));
+        assert_ne!(lines, generate(8, 1));
+
+        let password = Solution::parse_input(lines).unwrap();
+        assert_eq!(password.len(), 8);
+    }
+}
@@ -1,48 +1,100 @@
 use std::error::Error;
+use std::path::PathBuf;
 
+use common::explain::Explain;
+use common::input;
 use puzzler::puzzler::puzzle::Puzzle;
 
-pub struct Solution {}
+use crate::puzzle::error::PuzzleError;
+
+pub struct Solution {
+    password: String,
+}
 
 impl Puzzle for Solution {
     fn name(&self) -> &str {
         "--- Day 11: Corporate Policy ---"
     }
 
+    fn get_input_file_path(&self) -> Option<PathBuf> {
+        Some(input::file_path(11))
+    }
+
+    fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
+        self.password = Self::parse_input(lines)?;
+        Ok(())
+    }
+
     // Solve first part of the puzzle
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let pwd = Solution::find_next_password("hepxcrrq");
+        let pwd = Solution::find_next_password(&self.password)?;
         Ok(pwd)
     }
 
     // Solve second part of the puzzle
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let pwd = Solution::find_next_password("hepxcrrq");
-        let pwd = Solution::find_next_password(&pwd);
+        let pwd = Solution::find_next_password(&self.password)?;
+        let pwd = Solution::find_next_password(&pwd)?;
         Ok(pwd)
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            password: String::new(),
+        }
+    }
+
+    // Password is a single line of lowercase letters
+    pub fn parse_input(lines: Vec<String>) -> Result<String, PuzzleError> {
+        if lines.len() != 1 {
+            return Err(PuzzleError::LineCount { found: lines.len() });
+        }
+
+        let password = lines.into_iter().next().unwrap().trim().to_string();
+
+        // Password is incremented as a sequence of lowercase letters
+        if password.is_empty() || !password.bytes().all(|b| b.is_ascii_lowercase()) {
+            return Err(PuzzleError::InvalidPassword {
+                line: 1,
+                text: password,
+            });
+        }
+
+        Ok(password)
     }
 
-    fn find_next_password(pwd: &str) -> String {
+    // Next password following the current one which meets the security requirements
+    pub fn find_next_password(pwd: &str) -> Result<String, PuzzleError> {
         let mut next_password = pwd.to_string();
 
         loop {
-            Self::increment_password(&mut next_password);
+            // All passwords of the same length were tried
+            if Self::increment_password(&mut next_password) {
+                return Err(PuzzleError::PasswordExhausted {
+                    password: pwd.to_string(),
+                });
+            }
 
             if Self::is_password_secure(&next_password) {
-                return next_password;
+                return Ok(next_password);
             }
         }
     }
 
-    fn increment_password(pwd: &mut str) {
+    // Returns true if the first character overflows, i.e. password wraps around to 'a..a'
+    fn increment_password(pwd: &mut str) -> bool {
         let bytes: &mut [u8] = unsafe { pwd.as_bytes_mut() };
-        let mut next_index = Some(bytes.len() - 1);
+        let mut next_index = bytes.len().checked_sub(1);
 
         while let Some(index) = next_index {
             // Increment current character
@@ -52,8 +104,13 @@ impl Solution {
             bytes[index] = incremented;
 
             // If overflow is detected continue with previous byte
-            next_index = if overflow { Some(index - 1) } else { None };
+            if !overflow {
+                return false;
+            }
+            next_index = index.checked_sub(1);
         }
+
+        true
     }
 
     // Increment single byte. Returns incremented byte and flag if overflow was detected
@@ -65,7 +122,8 @@ impl Solution {
         }
     }
 
-    fn is_password_secure(pwd: &str) -> bool {
+    // Password meets all security requirements of Santa's boss
+    pub fn is_password_secure(pwd: &str) -> bool {
         // Has to contain three consecutive letters
         if !Self::has_consecutive_letters(pwd) {
             return false;
@@ -83,13 +141,9 @@ impl Solution {
     fn has_consecutive_letters(pwd: &str) -> bool {
         let bytes = pwd.as_bytes();
 
-        for index in 0..bytes.len() - 3 {
-            if bytes[index] + 1 == bytes[index + 1] && bytes[index + 1] + 1 == bytes[index + 2] {
-                return true;
-            }
-        }
-
-        false
+        bytes
+            .windows(3)
+            .any(|w| w[0] + 1 == w[1] && w[1] + 1 == w[2])
     }
 
     fn count_non_overlapping_pairs(pwd: &str) -> usize {
@@ -97,7 +151,7 @@ impl Solution {
         let mut index = 0;
         let mut count = 0;
 
-        while index < bytes.len() - 1 {
+        while index + 1 < bytes.len() {
             if bytes[index] == bytes[index + 1] {
                 count += 1;
                 // Skip to next byte to avoid overlap
@@ -113,28 +167,34 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "hepxxyzz");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "hepxxyzz");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "heqaabcc");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "heqaabcc");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
@@ -163,6 +223,10 @@ mod tests {
 
         Solution::increment_password(&mut pwd);
         assert_eq!(&pwd, "baaaaab");
+
+        pwd = String::from("zz");
+        assert!(Solution::increment_password(&mut pwd));
+        assert_eq!(&pwd, "aa");
     }
 
     #[test]
@@ -178,6 +242,8 @@ mod tests {
         assert!(Solution::has_consecutive_letters("xabcy"));
         assert!(Solution::has_consecutive_letters("xabcdy"));
         assert!(Solution::has_consecutive_letters("xabcabcy"));
+        assert!(Solution::has_consecutive_letters("xyz"));
+        assert!(!Solution::has_consecutive_letters("ab"));
     }
 
     #[test]
@@ -194,11 +260,17 @@ mod tests {
     fn test_find_next_password() {
         assert_eq!(
             Solution::find_next_password("abcdefgh"),
-            String::from("abcdffaa")
+            Ok(String::from("abcdffaa"))
         );
         assert_eq!(
             Solution::find_next_password("ghijklmn"),
-            String::from("ghjaabcc")
+            Ok(String::from("ghjaabcc"))
+        );
+        assert_eq!(
+            Solution::find_next_password("zzzz"),
+            Err(PuzzleError::PasswordExhausted {
+                password: String::from("zzzz")
+            })
         );
     }
 }
@@ -0,0 +1,6 @@
+// Day 12: JSAbacusFramework.io. Sums of numbers in a JSON document.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
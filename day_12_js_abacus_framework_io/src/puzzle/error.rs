@@ -0,0 +1,43 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    LineCount {
+        found: usize,
+    },
+    InvalidNumber {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    InvalidJson {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::LineCount { found } => {
+                write!(f, "Input file shall have only one line, {found} found")
+            }
+            PuzzleError::InvalidNumber { line, column, text } => write!(
+                f,
+                "Line {line}, column {column}: failed to parse '{text}' to i64"
+            ),
+            PuzzleError::InvalidJson {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Line {line}, column {column}: failed to parse json [{message}]"
+            ),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
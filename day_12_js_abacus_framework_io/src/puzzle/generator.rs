@@ -0,0 +1,68 @@
+use common::random::Rng;
+
+// Colors used as string values, numbers would be counted even inside of strings
+const COLORS: [&str; 6] = ["red", "green", "blue", "orange", "yellow", "violet"];
+
+// Nesting of arrays and objects
+const MAX_DEPTH: usize = 5;
+
+// JSON document on a single line, size is the number of numbers and strings in it
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let mut remaining = size;
+
+    let mut values = Vec::new();
+    while remaining > 0 {
+        values.push(value(&mut rng, 1, &mut remaining));
+    }
+
+    vec![format!("[{}]", values.join(","))]
+}
+
+fn value(rng: &mut Rng, depth: usize, remaining: &mut usize) -> String {
+    if depth >= MAX_DEPTH || *remaining <= 1 || rng.chance(1, 2) {
+        *remaining = remaining.saturating_sub(1);
+
+        return match rng.chance(1, 2) {
+            true => rng.range_isize(-50..=200).to_string(),
+            false => format!("\"{}\"", rng.choose(&COLORS)),
+        };
+    }
+
+    let count = rng.range(1..=5);
+    let mut children = Vec::new();
+    while children.len() < count && *remaining > 0 {
+        children.push(value(rng, depth + 1, remaining));
+    }
+
+    match rng.chance(1, 2) {
+        true => format!("[{}]", children.join(",")),
+        false => {
+            // Keys are single letters, unique within the object
+            let fields = children
+                .iter()
+                .enumerate()
+                .map(|(index, child)| format!("\"{}\":{child}", (b'a' + index as u8) as char))
+                .collect::<Vec<_>>();
+
+            format!("{{{}}}", fields.join(","))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 200);
+        assert_eq!(lines, generate(7, 200));
+        assert_ne!(lines, generate(8, 200));
+
+        let json = Solution::parse_input(lines).unwrap();
+        let sum = Solution::sum_numbers(&json).unwrap();
+        let sum_red = Solution::sum_numbers_red(&json).unwrap();
+        assert!(sum != 0 || sum_red == 0);
+    }
+}
@@ -1,10 +1,13 @@
 use std::error::Error;
 
-use puzzler::env::project;
+use common::explain::Explain;
+use common::input;
 use puzzler::puzzler::puzzle::Puzzle;
 use regex::Regex;
 use serde_json::Value;
 
+use crate::puzzle::error::PuzzleError;
+
 pub struct Solution {
     json: String,
 }
@@ -15,40 +18,36 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_12.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_12.txt [{err}]")),
-        )
+        Some(input::file_path(12))
     }
 
     // Parse the file content for the puzzle
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        if lines.len() != 1 {
-            return Err(
-                format!("Input file shall have only one line, {} found", lines.len()).into(),
-            );
-        }
-
-        // Move huge string instead of clone
-        let mut lines = lines;
-        self.json = lines.remove(0);
-
+        self.json = Self::parse_input(lines)?;
         Ok(())
     }
 
     // Solve first part of the puzzle
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let sum = Self::sum_numbers(&self.json);
+        let sum = Self::sum_numbers(&self.json)?;
         Ok(sum.to_string())
     }
 
     // Solve second part of the puzzle
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let sum = Self::sum_numbers_red(&self.json);
+        let sum = Self::sum_numbers_red(&self.json)?;
         Ok(sum.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -56,7 +55,19 @@ impl Solution {
         }
     }
 
-    fn sum_numbers(json: &str) -> i64 {
+    // JSON document is on a single line
+    pub fn parse_input(lines: Vec<String>) -> Result<String, PuzzleError> {
+        if lines.len() != 1 {
+            return Err(PuzzleError::LineCount { found: lines.len() });
+        }
+
+        // Move huge string instead of clone
+        let mut lines = lines;
+        Ok(lines.remove(0))
+    }
+
+    // Sum of all numbers in the document
+    pub fn sum_numbers(json: &str) -> Result<i64, PuzzleError> {
         // Find all positive and negative numbers; they are not in strings ""
         let regex = Regex::new(r"(\-?\d+)").expect("Failed to build regex");
 
@@ -67,23 +78,36 @@ impl Solution {
                 value
                     .as_str()
                     .parse::<i64>()
-                    .unwrap_or_else(|_| panic!("Failed to parse '{}' to i64", value.as_str()))
+                    .map_err(|_| PuzzleError::InvalidNumber {
+                        line: 1,
+                        column: value.start() + 1,
+                        text: value.as_str().to_string(),
+                    })
             })
             .sum()
     }
 
-    fn sum_numbers_red(json: &str) -> i64 {
+    // Sum of all numbers in the document, objects with a "red" value are ignored
+    pub fn sum_numbers_red(json: &str) -> Result<i64, PuzzleError> {
         // Ignore red in objects
-        let value: Value =
-            serde_json::from_str(json).unwrap_or_else(|err| panic!("Failed to parse json [{err}]"));
+        let value: Value = serde_json::from_str(json).map_err(|err| PuzzleError::InvalidJson {
+            line: err.line(),
+            column: err.column(),
+            message: err.to_string(),
+        })?;
 
         Self::sum_non_red_only(&value)
     }
 
     // Use recursion to solve JSON tree
-    fn sum_non_red_only(value: &Value) -> i64 {
+    fn sum_non_red_only(value: &Value) -> Result<i64, PuzzleError> {
         match value {
-            Value::Number(number) => number.as_i64().unwrap(),
+            // Position is not available once json is parsed
+            Value::Number(number) => number.as_i64().ok_or_else(|| PuzzleError::InvalidNumber {
+                line: 1,
+                column: 0,
+                text: number.to_string(),
+            }),
             Value::Array(values) => {
                 // Sum all value in array
                 values.iter().map(Self::sum_non_red_only).sum()
@@ -91,63 +115,92 @@ impl Solution {
             Value::Object(objects) => {
                 // Do not count objects with red
                 if objects.values().any(|v| v.as_str() == Some("red")) {
-                    return 0;
+                    return Ok(0);
                 }
 
                 // Else count them
                 objects.values().map(Self::sum_non_red_only).sum()
             }
-            _ => 0,
+            _ => Ok(0),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "156366");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "156366");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "96852");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "96852");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
     fn test_sum_numbers() {
-        assert_eq!(Solution::sum_numbers(r#"[1,2,3]"#), 6);
-        assert_eq!(Solution::sum_numbers(r#"{"a":2,"b":4}"#), 6);
-        assert_eq!(Solution::sum_numbers(r#"[[[3]]]"#), 3);
-        assert_eq!(Solution::sum_numbers(r#"{"a":{"b":4},"c":-1}"#), 3);
-        assert_eq!(Solution::sum_numbers(r#"{"a":[-1,1]}"#), 0);
-        assert_eq!(Solution::sum_numbers(r#"[-1,{"a":1}]"#), 0);
-        assert_eq!(Solution::sum_numbers(r#"[]"#), 0);
-        assert_eq!(Solution::sum_numbers(r#"{}"#), 0);
+        assert_eq!(Solution::sum_numbers(r#"[1,2,3]"#), Ok(6));
+        assert_eq!(Solution::sum_numbers(r#"{"a":2,"b":4}"#), Ok(6));
+        assert_eq!(Solution::sum_numbers(r#"[[[3]]]"#), Ok(3));
+        assert_eq!(Solution::sum_numbers(r#"{"a":{"b":4},"c":-1}"#), Ok(3));
+        assert_eq!(Solution::sum_numbers(r#"{"a":[-1,1]}"#), Ok(0));
+        assert_eq!(Solution::sum_numbers(r#"[-1,{"a":1}]"#), Ok(0));
+        assert_eq!(Solution::sum_numbers(r#"[]"#), Ok(0));
+        assert_eq!(Solution::sum_numbers(r#"{}"#), Ok(0));
     }
 
     #[test]
     fn test_sum_numbers_red() {
-        assert_eq!(Solution::sum_numbers_red(r#"[1,2,3]"#), 6);
-        assert_eq!(Solution::sum_numbers_red(r#"[1,{"c":"red","b":2},3]"#), 4);
+        assert_eq!(Solution::sum_numbers_red(r#"[1,2,3]"#), Ok(6));
+        assert_eq!(
+            Solution::sum_numbers_red(r#"[1,{"c":"red","b":2},3]"#),
+            Ok(4)
+        );
         assert_eq!(
             Solution::sum_numbers_red(r#"{"d":"red","e":[1,2,3,4],"f":5}"#),
-            0
+            Ok(0)
+        );
+        assert_eq!(Solution::sum_numbers_red(r#"[1,"red",5]"#), Ok(6));
+    }
+
+    #[test]
+    fn test_sum_numbers_invalid() {
+        assert_eq!(
+            Solution::sum_numbers(r#"[1,99999999999999999999]"#),
+            Err(PuzzleError::InvalidNumber {
+                line: 1,
+                column: 4,
+                text: String::from("99999999999999999999")
+            })
         );
-        assert_eq!(Solution::sum_numbers_red(r#"[1,"red",5]"#), 6);
+        assert!(matches!(
+            Solution::sum_numbers_red(r#"[1,2"#),
+            Err(PuzzleError::InvalidJson { line: 1, .. })
+        ));
+        assert!(matches!(
+            Solution::sum_numbers_red(r#"[1.5]"#),
+            Err(PuzzleError::InvalidNumber { .. })
+        ));
     }
 }
@@ -0,0 +1,7 @@
+// Day 13: Knights of the Dinner Table. Optimal seating arrangement of guests around a round
+// table given the happiness rules of every pair of guests.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::{Rules, Solution};
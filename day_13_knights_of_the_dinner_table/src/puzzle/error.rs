@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::budget::Exceeded;
+use common::parser::SyntaxError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    Syntax(SyntaxError),
+    InvalidNumber {
+        line: usize,
+        text: String,
+    },
+    InvalidSign {
+        line: usize,
+        text: String,
+    },
+    MissingRule {
+        person: String,
+        neighbour: String,
+    },
+    NoGuests,
+    BudgetExceeded {
+        exceeded: Exceeded,
+        best: Option<isize>,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::InvalidNumber { line, text } => {
+                write!(f, "Line {line}: failed to parse 'hapiness' from '{text}'")
+            }
+            PuzzleError::InvalidSign { line, text } => {
+                write!(f, "Line {line}: unexpected sign marker '{text}'")
+            }
+            PuzzleError::MissingRule { person, neighbour } => {
+                write!(
+                    f,
+                    "Rule for '{person}' sitting next to '{neighbour}' not found"
+                )
+            }
+            PuzzleError::NoGuests => write!(f, "No guests found"),
+            PuzzleError::BudgetExceeded { exceeded, best } => match best {
+                Some(best) => write!(f, "{exceeded}, best happiness found so far '{best}'"),
+                None => write!(f, "{exceeded}, no seating found so far"),
+            },
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
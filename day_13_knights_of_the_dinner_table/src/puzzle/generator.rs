@@ -0,0 +1,41 @@
+use common::random::{self, Rng};
+
+// Happiness of every guest next to every other guest, size is the number of guests. Every
+// seating is tried by the solution, therefore sizes above ten take long.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let guests = (0..size.max(2)).map(random::name).collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    for guest in &guests {
+        for neighbour in guests.iter().filter(|neighbour| *neighbour != guest) {
+            let change = match rng.chance(1, 2) {
+                true => "gain",
+                false => "lose",
+            };
+
+            lines.push(format!(
+                "{guest} would {change} {} happiness units by sitting next to {neighbour}.",
+                rng.range(0..=100)
+            ));
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 5);
+        assert_eq!(lines, generate(7, 5));
+        assert_ne!(lines, generate(8, 5));
+        assert_eq!(lines.len(), 20);
+
+        let rules = Solution::parse_input(&lines).unwrap();
+        assert!(Solution::calculate_max_happiness(&rules).is_ok());
+    }
+}
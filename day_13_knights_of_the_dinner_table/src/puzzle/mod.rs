@@ -1 +1,3 @@
+pub mod error;
+pub mod generator;
 pub mod solution;
@@ -1,13 +1,19 @@
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 
+use common::budget::{self, Budget};
+use common::explain::{Explain, Witness};
+use common::{input, parser};
 use itertools::Itertools;
-use puzzler::env::project;
-use puzzler::parsers::parser::Parser;
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
+
+// Happiness of the person sitting next to the neighbour, keyed by (person, neighbour)
+pub type Rules = HashMap<(String, String), isize>;
+
 pub struct Solution {
-    rules: HashMap<(String, String), isize>,
+    rules: Rules,
 }
 
 impl Puzzle for Solution {
@@ -16,103 +22,161 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_13.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_13.txt [{err}]")),
-        )
+        Some(input::file_path(13))
     }
 
     // Parse the file content for the puzzle
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        let mappings: Vec<((String, String), isize)> = Parser::parse_lines_with_regex(
-            lines,
-            r"^(\w+) would (\w+) (\d+) happiness units by sitting next to (\w+).",
-            |params| {
-                if params.len() != 4 {
-                    return Err(
-                        format!("Exactly 4 parameters expected [found {}]", params.len()).into(),
-                    );
-                }
+        self.rules = Self::parse_input(&lines)?;
+        Ok(())
+    }
+
+    fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
+        let max_happiness = Self::calculate_max_happiness(&self.rules)?;
+        Ok(max_happiness.to_string())
+    }
+
+    fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
+        Self::add_neutral_guest(&mut self.rules, "me");
+
+        let max_happiness = Self::calculate_max_happiness(&self.rules)?;
+        Ok(max_happiness.to_string())
+    }
+}
+
+impl Explain for Solution {
+    // Optimal seating arrangement with its happiness
+    fn explain_part1(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        let seating = Self::find_best_seating(&self.rules);
+        Some(seating.map(seating_witness).map_err(Into::into))
+    }
+
+    // Optimal seating arrangement including me, rules of the puzzle are left untouched
+    fn explain_part2(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        let mut rules = self.rules.clone();
+        Self::add_neutral_guest(&mut rules, "me");
+
+        let seating = Self::find_best_seating(&rules);
+        Some(seating.map(seating_witness).map_err(Into::into))
+    }
+}
+
+fn seating_witness((seating, happiness): (Vec<String>, isize)) -> Witness {
+    Witness::new()
+        .with("seating", seating)
+        .with("happiness", happiness)
+}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solution {
+    pub fn new() -> Self {
+        Self {
+            rules: HashMap::new(),
+        }
+    }
 
+    // Every line is a single rule, e.g. 'Alice would gain 54 happiness units by sitting next
+    // to Bob.'
+    pub fn parse_input(lines: &[String]) -> Result<Rules, PuzzleError> {
+        let mappings: Vec<((String, String), isize)> = parser::parse_lines_with_regex(
+            lines,
+            r"^(\w+) would (\w+) (\d+) happiness units by sitting next to (\w+)\.$",
+            |line, params| -> Result<_, PuzzleError> {
                 let first = params[0].clone();
                 let sign = params[1].clone();
-                let mut hapiness = params[2]
-                    .parse::<isize>()
-                    .map_err(|err| format!("Failed to parse 'hapiness' to isize [{err}]"))?;
+                let mut hapiness =
+                    params[2]
+                        .parse::<isize>()
+                        .map_err(|_| PuzzleError::InvalidNumber {
+                            line,
+                            text: params[2].clone(),
+                        })?;
                 let neighbour = params[3].clone();
 
                 match sign.as_str() {
                     "gain" => {}
                     "lose" => hapiness *= -1,
-                    _ => {
-                        return Err(format!("Unexpected sign marker '{sign}'").into());
-                    }
+                    _ => return Err(PuzzleError::InvalidSign { line, text: sign }),
                 };
 
                 Ok(((first, neighbour), hapiness))
             },
         )?;
 
-        self.rules = mappings.into_iter().collect();
-        Ok(())
-    }
-
-    fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let max_happiness = Self::calculate_max_happiness(&self.rules);
-        Ok(max_happiness.to_string())
+        Ok(mappings.into_iter().collect())
     }
 
-    fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        // Add mayself to rules
-        let persons = Self::collect_persons(&self.rules);
+    // Add a guest indifferent to the neighbours, neighbours are indifferent to the guest too
+    pub fn add_neutral_guest(rules: &mut Rules, guest: &str) {
+        let persons = Self::collect_persons(rules);
         for person in persons {
-            self.rules.insert((person.clone(), String::from("me")), 0);
-            self.rules.insert((String::from("me"), person), 0);
+            rules.insert((person.clone(), guest.to_string()), 0);
+            rules.insert((guest.to_string(), person), 0);
         }
+    }
 
-        let max_happiness = Self::calculate_max_happiness(&self.rules);
-        Ok(max_happiness.to_string())
+    // Total change in happiness of the optimal seating arrangement around the round table
+    pub fn calculate_max_happiness(rules: &Rules) -> Result<isize, PuzzleError> {
+        Self::find_best_seating(rules).map(|(_, happiness)| happiness)
     }
-}
 
-impl Solution {
-    pub fn new() -> Self {
-        Self {
-            rules: HashMap::new(),
-        }
+    // Optimal seating arrangement around the round table together with its total change in
+    // happiness. Persons are permuted in sorted order and only a strictly better arrangement
+    // replaces the best one, therefore the same arrangement is returned for the same rules.
+    // Search is limited by the budget of the thread.
+    pub fn find_best_seating(rules: &Rules) -> Result<(Vec<String>, isize), PuzzleError> {
+        Self::search_seating(rules, &budget::current())
     }
 
-    fn calculate_max_happiness(rules: &HashMap<(String, String), isize>) -> isize {
-        let persons = Self::collect_persons(rules);
+    // Every permutation of persons is a single step of the budget
+    fn search_seating(rules: &Rules, budget: &Budget) -> Result<(Vec<String>, isize), PuzzleError> {
+        let mut meter = budget.meter();
+        let mut persons = Self::collect_persons(rules);
+        persons.sort();
 
-        let permutations = persons
-            .iter()
-            .permutations(persons.len())
-            .collect::<Vec<_>>();
+        let mut best: Option<(Vec<String>, isize)> = None;
 
-        permutations
-            .iter()
-            .map(|order| Self::calculate_happiness(rules, order))
-            .max()
-            .unwrap()
+        for order in persons.iter().permutations(persons.len()) {
+            meter
+                .step()
+                .map_err(|exceeded| PuzzleError::BudgetExceeded {
+                    exceeded,
+                    best: best.as_ref().map(|(_, happiness)| *happiness),
+                })?;
+
+            let happiness = Self::calculate_happiness(rules, &order)?;
+
+            if best.as_ref().is_none_or(|(_, best)| happiness > *best) {
+                let seating = order.into_iter().cloned().collect();
+                best = Some((seating, happiness));
+            }
+        }
+
+        best.ok_or(PuzzleError::NoGuests)
     }
 
-    fn collect_persons(rules: &HashMap<(String, String), isize>) -> Vec<String> {
+    fn collect_persons(rules: &Rules) -> Vec<String> {
         let unique: HashSet<String> = rules.keys().map(|(first, _)| first.clone()).collect();
         unique.into_iter().collect()
     }
 
-    fn calculate_happiness(
-        rules: &HashMap<(String, String), isize>,
-        order: &Vec<&String>,
-    ) -> isize {
+    fn calculate_happiness(rules: &Rules, order: &Vec<&String>) -> Result<isize, PuzzleError> {
+        let (Some(first), Some(last)) = (order.first(), order.last()) else {
+            return Err(PuzzleError::NoGuests);
+        };
+
         // Artifically hack order so that windows() generates triple also for first and last
         let mut order_new = Vec::new();
-        order_new.push(order[order.len() - 1].clone());
+        order_new.push((*last).clone());
         for o in order {
             order_new.push((**o).clone());
         }
-        order_new.push(order[0].clone());
+        order_new.push((*first).clone());
 
         // Middle in the tripple is the person for which we calculate happiness
         let triples: Vec<_> = order_new.windows(3).collect();
@@ -120,40 +184,58 @@ impl Solution {
         triples
             .iter()
             .map(|t| {
-                let left = rules.get(&(t[1].clone(), t[0].clone())).unwrap();
-                let right = rules.get(&(t[1].clone(), t[2].clone())).unwrap();
-                left + right
+                let left = Self::get_rule(rules, &t[1], &t[0])?;
+                let right = Self::get_rule(rules, &t[1], &t[2])?;
+                Ok(left + right)
             })
             .sum()
     }
+
+    fn get_rule(rules: &Rules, person: &str, neighbour: &str) -> Result<isize, PuzzleError> {
+        rules
+            .get(&(person.to_string(), neighbour.to_string()))
+            .copied()
+            .ok_or_else(|| PuzzleError::MissingRule {
+                person: person.to_string(),
+                neighbour: neighbour.to_string(),
+            })
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::budget::{Budget, Exceeded, Limit, Limits};
+    use common::explain::{Explain, Value};
+    use common::fixtures;
     use std::collections::HashMap;
 
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "664");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "664");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "640");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "640");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     fn construct_persons() -> HashMap<(String, String), isize> {
@@ -184,12 +266,65 @@ mod tests {
         ];
         let order: Vec<&String> = order.iter().collect();
 
-        assert_eq!(Solution::calculate_happiness(&persons, &order), 330);
+        assert_eq!(Solution::calculate_happiness(&persons, &order), Ok(330));
     }
 
     #[test]
     fn test_calucalate_max_happiness() {
         let persons = construct_persons();
-        assert_eq!(Solution::calculate_max_happiness(&persons), 330);
+        assert_eq!(Solution::calculate_max_happiness(&persons), Ok(330));
+    }
+
+    #[test]
+    fn test_find_best_seating() {
+        let persons = construct_persons();
+
+        let (seating, happiness) = Solution::find_best_seating(&persons).unwrap();
+        assert_eq!(seating, ["Alice", "Bob", "Carol", "David"]);
+        assert_eq!(happiness, 330);
+
+        let mut solution = Solution { rules: persons };
+        let witness = solution.explain_part2().unwrap().unwrap();
+        assert_eq!(witness.get("happiness"), Some(&Value::Number(286)));
+        assert_eq!(solution.rules.len(), 12);
+
+        assert_eq!(
+            Solution::find_best_seating(&HashMap::new()),
+            Err(PuzzleError::NoGuests)
+        );
+    }
+
+    #[test]
+    fn test_search_seating_budget_exceeded() {
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(1),
+        });
+
+        assert_eq!(
+            Solution::search_seating(&construct_persons(), &budget),
+            Err(PuzzleError::BudgetExceeded {
+                exceeded: Exceeded {
+                    limit: Limit::Steps(1),
+                    steps: 1
+                },
+                best: Some(330)
+            })
+        );
+    }
+
+    #[test]
+    fn test_calucalate_max_happiness_invalid() {
+        let mut persons = construct_persons();
+        persons.remove(&(String::from("Bob"), String::from("Carol")));
+
+        assert!(matches!(
+            Solution::calculate_max_happiness(&persons),
+            Err(PuzzleError::MissingRule { .. })
+        ));
+        assert_eq!(
+            Solution::calculate_max_happiness(&HashMap::new()),
+            Err(PuzzleError::NoGuests)
+        );
     }
 }
@@ -0,0 +1,7 @@
+// Day 14: Reindeer Olympics. Race of reindeers which alternate between flying and resting.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::reindeer::Reindeer;
+pub use puzzle::solution::{COMPETITION_SECONDS, Solution};
@@ -0,0 +1,50 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::parser::SyntaxError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    Syntax(SyntaxError),
+    InvalidNumber {
+        line: usize,
+        field: &'static str,
+        text: String,
+    },
+    ZeroCycle {
+        line: usize,
+        name: String,
+    },
+    CycleOverflow {
+        line: usize,
+        name: String,
+    },
+    NoReindeers,
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::InvalidNumber { line, field, text } => {
+                write!(f, "Line {line}: failed to convert '{field}' from '{text}'")
+            }
+            PuzzleError::ZeroCycle { line, name } => write!(
+                f,
+                "Line {line}: reindeer '{name}' shall fly or rest for at least one second"
+            ),
+            PuzzleError::CycleOverflow { line, name } => {
+                write!(f, "Line {line}: cycle of reindeer '{name}' is too long")
+            }
+            PuzzleError::NoReindeers => write!(f, "No reindeers found"),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
@@ -0,0 +1,33 @@
+use common::random::{self, Rng};
+
+// Reindeer with random speed, flight and rest times, size is the number of reindeer
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    (0..size)
+        .map(|index| {
+            format!(
+                "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.",
+                random::name(index),
+                rng.range(1..=30),
+                rng.range(1..=20),
+                rng.range(10..=200)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 10);
+        assert_eq!(lines, generate(7, 10));
+        assert_ne!(lines, generate(8, 10));
+
+        let reindeers = Solution::parse_input(&lines).unwrap();
+        assert_eq!(reindeers.len(), 10);
+    }
+}
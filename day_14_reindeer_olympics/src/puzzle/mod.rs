@@ -1,2 +1,4 @@
+pub mod error;
+pub mod generator;
 pub mod reindeer;
 pub mod solution;
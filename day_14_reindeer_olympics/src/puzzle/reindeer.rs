@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Reindeer {
     pub speed: usize,
     pub active: usize,
@@ -6,6 +6,7 @@ pub struct Reindeer {
 }
 
 impl Reindeer {
+    // Distance flown after the given number of seconds
     pub fn distance(&self, seconds: usize) -> usize {
         let intervals = seconds / (self.active + self.resting);
         let remaining = seconds % (self.active + self.resting);
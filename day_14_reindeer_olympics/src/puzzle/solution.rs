@@ -1,12 +1,14 @@
 use std::error::Error;
 
-use puzzler::env::project;
-use puzzler::parsers::parser::Parser;
+use common::explain::Explain;
+use common::{input, parser};
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
 use crate::puzzle::reindeer::Reindeer;
 
-const COMPETITION_SECONDS: usize = 2503;
+// Duration of the race
+pub const COMPETITION_SECONDS: usize = 2503;
 
 pub struct Solution {
     reindeers: Vec<Reindeer>,
@@ -18,73 +20,100 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_14.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_14.txt [{err}]")),
-        )
+        Some(input::file_path(14))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.reindeers = Parser::parse_lines_with_regex(
-            lines,
-            r"^(\w+) can fly (\d+) km/s for (\d+) seconds, but then must rest for (\d+) seconds.",
-            |params| {
-                if params.len() != 4 {
-                    return Err(format!(
-                        "Expected exactly 4 parameters, found '{}' [{:?}]",
-                        params.len(),
-                        params
-                    )
-                    .into());
-                }
-
-                // let name = params[0].clone();
-
-                let speed = params[1]
-                    .parse::<usize>()
-                    .map_err(|err| format!("Failed to convert 'speed' to usize [{err}]"))?;
-
-                let active = params[2]
-                    .parse::<usize>()
-                    .map_err(|err| format!("Failed to convert 'active' to usize [{err}]"))?;
-
-                let resting = params[3]
-                    .parse::<usize>()
-                    .map_err(|err| format!("Failed to convert 'resting' to usize [{err}]"))?;
-
-                Ok(Reindeer {
-                    speed,
-                    active,
-                    resting,
-                })
-            },
-        )?;
+        self.reindeers = Self::parse_input(&lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let max = self
-            .reindeers
-            .iter()
-            .map(|r| r.distance(COMPETITION_SECONDS))
-            .max()
-            .unwrap();
+        let max = Solution::max_distance(&self.reindeers, COMPETITION_SECONDS)?;
 
         Ok(max.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let max = Solution::run_race(&self.reindeers, COMPETITION_SECONDS);
+        let max = Solution::run_race(&self.reindeers, COMPETITION_SECONDS)?;
         Ok(max.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self { reindeers: vec![] }
     }
 
-    fn run_race(reindeers: &[Reindeer], seconds: usize) -> usize {
+    // Every line describes a single reindeer, e.g. 'Comet can fly 14 km/s for 10 seconds, but
+    // then must rest for 127 seconds.'
+    pub fn parse_input(lines: &[String]) -> Result<Vec<Reindeer>, PuzzleError> {
+        parser::parse_lines_with_regex(
+            lines,
+            r"^(\w+) can fly (\d+) km/s for (\d+) seconds, but then must rest for (\d+) seconds\.$",
+            |line, params| -> Result<_, PuzzleError> {
+                let speed = Self::parse_number(line, "speed", &params[1])?;
+                let active = Self::parse_number(line, "active", &params[2])?;
+                let resting = Self::parse_number(line, "resting", &params[3])?;
+
+                // Distance is calculated per cycle of flying and resting
+                match active.checked_add(resting) {
+                    Some(0) => {
+                        return Err(PuzzleError::ZeroCycle {
+                            line,
+                            name: params[0].clone(),
+                        });
+                    }
+                    None => {
+                        return Err(PuzzleError::CycleOverflow {
+                            line,
+                            name: params[0].clone(),
+                        });
+                    }
+                    Some(_) => {}
+                }
+
+                Ok(Reindeer {
+                    speed,
+                    active,
+                    resting,
+                })
+            },
+        )
+    }
+
+    fn parse_number(line: usize, field: &'static str, text: &str) -> Result<usize, PuzzleError> {
+        text.parse::<usize>()
+            .map_err(|_| PuzzleError::InvalidNumber {
+                line,
+                field,
+                text: text.to_string(),
+            })
+    }
+
+    // Distance of the winning reindeer after the given number of seconds
+    pub fn max_distance(reindeers: &[Reindeer], seconds: usize) -> Result<usize, PuzzleError> {
+        reindeers
+            .iter()
+            .map(|r| r.distance(seconds))
+            .max()
+            .ok_or(PuzzleError::NoReindeers)
+    }
+
+    // Points of the winning reindeer, every second the leading reindeers get one point
+    pub fn run_race(reindeers: &[Reindeer], seconds: usize) -> Result<usize, PuzzleError> {
+        if reindeers.is_empty() {
+            return Err(PuzzleError::NoReindeers);
+        }
+
         // Populate zero scores for everyone
         let mut scores = vec![0_usize; reindeers.len()];
 
@@ -97,43 +126,54 @@ impl Solution {
                 .collect::<Vec<_>>();
 
             // Find maximum distance
-            let max = distances.iter().max().unwrap();
+            let max = distances.iter().max().copied().unwrap_or_default();
 
             for (index, dist) in distances.iter().enumerate() {
-                if dist == max {
+                if *dist == max {
                     scores[index] += 1;
                 }
             }
         }
 
-        *scores.iter().max().unwrap()
+        Ok(scores.into_iter().max().unwrap_or_default())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::{reindeer::Reindeer, solution::Solution};
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{error::PuzzleError, reindeer::Reindeer, solution::Solution};
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "2660");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "2660");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "1256");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "1256");
+    }
+
+    // Race of the puzzle statement lasts 1000 seconds
+    #[test]
+    fn test_examples() {
+        let lines = fixtures::read_example(env!("CARGO_MANIFEST_DIR"), "example_01.txt");
+        let reindeers = Solution::parse_input(&lines).unwrap();
+
+        assert_eq!(Solution::max_distance(&reindeers, 1000), Ok(1120));
+        assert_eq!(Solution::run_race(&reindeers, 1000), Ok(689));
     }
 
     #[test]
@@ -151,6 +191,39 @@ mod tests {
             },
         ];
 
-        assert_eq!(Solution::run_race(&reindeers, 1000), 689);
+        assert_eq!(Solution::run_race(&reindeers, 1000), Ok(689));
+        assert_eq!(Solution::run_race(&[], 1000), Err(PuzzleError::NoReindeers));
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![String::from(
+                "Comet can fly 14 km/s for 0 seconds, but then must rest for 0 seconds.",
+            )])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::ZeroCycle {
+                line: 1,
+                name: String::from("Comet")
+            })
+        );
+
+        let err = solution
+            .parse_content(vec![String::from(
+                "Comet can fly 14 km/s for 18446744073709551615 seconds, but then must rest for 1 seconds.",
+            )])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::CycleOverflow {
+                line: 1,
+                name: String::from("Comet")
+            })
+        );
     }
 }
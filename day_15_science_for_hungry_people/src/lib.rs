@@ -0,0 +1,7 @@
+// Day 15: Science for Hungry People. Best cookie recipe made of teaspoons of ingredients.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::ingredient::Ingredient;
+pub use puzzle::solution::Solution;
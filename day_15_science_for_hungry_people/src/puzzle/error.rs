@@ -0,0 +1,41 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::parser::SyntaxError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    Syntax(SyntaxError),
+    InvalidNumber {
+        line: usize,
+        field: &'static str,
+        text: String,
+    },
+    NoIngredients,
+    NoRecipe {
+        calories: usize,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::InvalidNumber { line, field, text } => {
+                write!(f, "Line {line}: failed to convert '{field}' from '{text}'")
+            }
+            PuzzleError::NoIngredients => write!(f, "No ingredients found"),
+            PuzzleError::NoRecipe { calories } => {
+                write!(f, "No cookie recipe with '{calories}' calories found")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
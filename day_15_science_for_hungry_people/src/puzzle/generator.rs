@@ -0,0 +1,42 @@
+use common::random::{self, Rng};
+
+// Ingredients with random properties, size is the number of ingredients. Every split of
+// teaspoons is tried by the solution, therefore sizes above five take long.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    // Cookie made of this ingredient only has 500 calories, part 2 always has a solution
+    let balanced = rng.range(0..=size - 1);
+
+    (0..size)
+        .map(|index| {
+            let [capacity, durability, flavor, texture] = [(); 4].map(|_| rng.range_isize(-5..=5));
+            let calories = match index == balanced {
+                true => 5,
+                false => rng.range(1..=9),
+            };
+
+            format!(
+                "{}: capacity {capacity}, durability {durability}, flavor {flavor}, texture {texture}, calories {calories}",
+                random::name(index)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 3);
+        assert_eq!(lines, generate(7, 3));
+        assert_ne!(lines, generate(8, 3));
+
+        let ingredients = Solution::parse_input(&lines).unwrap();
+        assert_eq!(ingredients.len(), 3);
+        assert!(Solution::find_best_cookie_calories(&ingredients, 100, 500).is_ok());
+    }
+}
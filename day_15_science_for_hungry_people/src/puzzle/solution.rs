@@ -1,9 +1,12 @@
 use std::error::Error;
 
-use puzzler::env::project;
-use puzzler::parsers::parser::Parser;
+use std::str::FromStr;
+
+use common::explain::Explain;
+use common::{input, parser};
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
 use crate::puzzle::ingredient::Ingredient;
 
 pub struct Solution {
@@ -16,64 +19,33 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_15.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_15.txt [{err}]")),
-        )
+        Some(input::file_path(15))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.ingredients = Parser::parse_lines_with_regex(
-            lines,
-            r"^.*: capacity (-?\d+), durability (-?\d+), flavor (-?\d+), texture (-?\d+), calories (\d+)",
-            |params| {
-                if params.len() != 5 {
-                    return Err(format!("Expected 5 parameters [{params:?}]").into());
-                }
-
-                let capacity = params[0]
-                    .parse::<isize>()
-                    .map_err(|err| format!("Failed to convert 'capacity' to isize [{err}]"))?;
-
-                let durability = params[1]
-                    .parse::<isize>()
-                    .map_err(|err| format!("Failed to convert 'durability' to isize [{err}]"))?;
-
-                let flavor = params[2]
-                    .parse::<isize>()
-                    .map_err(|err| format!("Failed to convert 'flavor' to isize [{err}]"))?;
-
-                let texture = params[3]
-                    .parse::<isize>()
-                    .map_err(|err| format!("Failed to convert 'texture' to isize [{err}]"))?;
-
-                let calories = params[4]
-                    .parse::<usize>()
-                    .map_err(|err| format!("Failed to convert 'calories' to usize [{err}]"))?;
-
-                Ok(Ingredient {
-                    capacity,
-                    durability,
-                    flavor,
-                    texture,
-                    calories,
-                })
-            },
-        )?;
+        self.ingredients = Self::parse_input(&lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let best_score = Self::find_best_cookie(&self.ingredients, 100);
+        let best_score = Self::find_best_cookie(&self.ingredients, 100)?;
         Ok(best_score.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let best_score = Self::find_best_cookie_calories(&self.ingredients, 100, 500);
+        let best_score = Self::find_best_cookie_calories(&self.ingredients, 100, 500)?;
         Ok(best_score.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -81,7 +53,40 @@ impl Solution {
         }
     }
 
-    fn find_best_cookie(ingredients: &[Ingredient], total: usize) -> usize {
+    // Every line describes a single ingredient with its properties per teaspoon
+    pub fn parse_input(lines: &[String]) -> Result<Vec<Ingredient>, PuzzleError> {
+        parser::parse_lines_with_regex(
+            lines,
+            r"^.*: capacity (-?\d+), durability (-?\d+), flavor (-?\d+), texture (-?\d+), calories (\d+)$",
+            |line, params| -> Result<_, PuzzleError> {
+                Ok(Ingredient {
+                    capacity: Self::parse_number(line, "capacity", &params[0])?,
+                    durability: Self::parse_number(line, "durability", &params[1])?,
+                    flavor: Self::parse_number(line, "flavor", &params[2])?,
+                    texture: Self::parse_number(line, "texture", &params[3])?,
+                    calories: Self::parse_number(line, "calories", &params[4])?,
+                })
+            },
+        )
+    }
+
+    fn parse_number<T: FromStr>(
+        line: usize,
+        field: &'static str,
+        text: &str,
+    ) -> Result<T, PuzzleError> {
+        text.parse::<T>().map_err(|_| PuzzleError::InvalidNumber {
+            line,
+            field,
+            text: text.to_string(),
+        })
+    }
+
+    // Highest score of a cookie made of total teaspoons of the ingredients
+    pub fn find_best_cookie(
+        ingredients: &[Ingredient],
+        total: usize,
+    ) -> Result<usize, PuzzleError> {
         // Use brute force
         let combinations = Self::generate_combinations(ingredients.len(), total);
 
@@ -89,14 +94,16 @@ impl Solution {
             .iter()
             .map(|counts| Self::calculate_score_and_calories(ingredients, counts).0)
             .max()
-            .unwrap()
+            .ok_or(PuzzleError::NoIngredients)
     }
 
-    fn find_best_cookie_calories(
+    // Highest score of a cookie made of total teaspoons of the ingredients with exactly the
+    // given calories
+    pub fn find_best_cookie_calories(
         ingredients: &[Ingredient],
         total: usize,
         calories: usize,
-    ) -> usize {
+    ) -> Result<usize, PuzzleError> {
         // Use brute force
         let combinations = Self::generate_combinations(ingredients.len(), total);
 
@@ -107,7 +114,7 @@ impl Solution {
                 if calor == calories { Some(score) } else { None }
             })
             .max()
-            .unwrap()
+            .ok_or(PuzzleError::NoRecipe { calories })
     }
 
     // Return (score, calories)
@@ -148,8 +155,7 @@ impl Solution {
             .iter()
             .zip(counts)
             .map(|(ingredient, size)| ingredient.calories * (*size))
-            .sum::<usize>()
-            .max(0);
+            .sum::<usize>();
 
         (
             (capacity * durability * flavor * texture) as usize,
@@ -166,6 +172,11 @@ impl Solution {
     }
 
     fn generator(len: usize, total: usize, current: &mut Vec<usize>, result: &mut Vec<Vec<usize>>) {
+        // Nothing to combine
+        if len == 0 {
+            return;
+        }
+
         if len == 1 {
             // Append last element to the current and push it to results
             current.push(total);
@@ -193,28 +204,34 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::{ingredient::Ingredient, solution::Solution};
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
+    use crate::puzzle::{error::PuzzleError, ingredient::Ingredient, solution::Solution};
 
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "222870");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "222870");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "117936");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "117936");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     fn construct_cookies() -> [Ingredient; 2] {
@@ -240,14 +257,22 @@ mod tests {
     fn test_find_best_cookie() {
         let ingredients = construct_cookies();
         let score = Solution::find_best_cookie(&ingredients, 100);
-        assert_eq!(score, 62842880);
+        assert_eq!(score, Ok(62842880));
+        assert_eq!(
+            Solution::find_best_cookie(&[], 100),
+            Err(PuzzleError::NoIngredients)
+        );
     }
 
     #[test]
     fn test_find_best_cookie_calories() {
         let ingredients = construct_cookies();
         let score = Solution::find_best_cookie_calories(&ingredients, 100, 500);
-        assert_eq!(score, 57600000);
+        assert_eq!(score, Ok(57600000));
+        assert_eq!(
+            Solution::find_best_cookie_calories(&ingredients, 100, 1),
+            Err(PuzzleError::NoRecipe { calories: 1 })
+        );
     }
 
     #[test]
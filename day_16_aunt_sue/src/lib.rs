@@ -0,0 +1,7 @@
+// Day 16: Aunt Sue. Find the aunt who sent the gift by the compounds detected in it.
+pub mod puzzle;
+
+pub use puzzle::aunt::Aunt;
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::{Readout, Solution};
@@ -0,0 +1,47 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::parser::SyntaxError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    GroupCount {
+        found: usize,
+    },
+    Syntax(SyntaxError),
+    InvalidNumber {
+        line: usize,
+        field: &'static str,
+        text: String,
+    },
+    AuntNotFound,
+    AmbiguousAunt {
+        ids: Vec<usize>,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::GroupCount { found } => {
+                write!(f, "Exactly two groups are expected, found '{found}'")
+            }
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::InvalidNumber { line, field, text } => {
+                write!(f, "Line {line}: failed to convert '{field}' from '{text}'")
+            }
+            PuzzleError::AuntNotFound => write!(f, "No aunt matches the gift"),
+            PuzzleError::AmbiguousAunt { ids } => {
+                write!(f, "More than one aunt matches the gift {ids:?}")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
@@ -0,0 +1,105 @@
+use common::random::Rng;
+
+// Compounds detected exactly by the MFCSAM, i.e. in both parts
+const EXACT: [&str; 6] = [
+    "children", "samoyeds", "akitas", "vizslas", "cars", "perfumes",
+];
+
+// Upper bound of a compound count
+const MAX_COUNT: usize = 10;
+
+// MFCSAM readout and size aunts with three compounds each. Exactly one aunt matches the
+// readout in part 1 and another one in part 2, all others differ in an exact compound.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    let mut readout = EXACT
+        .iter()
+        .map(|compound| (*compound, rng.range(0..=MAX_COUNT)))
+        .collect::<Vec<_>>();
+    readout.extend([
+        ("cats", rng.range(0..=MAX_COUNT - 1)),
+        ("trees", rng.range(0..=MAX_COUNT - 1)),
+        ("pomeranians", rng.range(1..=MAX_COUNT)),
+        ("goldfish", rng.range(1..=MAX_COUNT)),
+    ]);
+    let count = |compound: &str| {
+        readout
+            .iter()
+            .find(|(name, _)| *name == compound)
+            .unwrap()
+            .1
+    };
+
+    let size = size.max(2);
+    let part1 = rng.range(0..=size - 1);
+    let part2 = (part1 + rng.range(1..=size - 1)) % size;
+
+    let mut lines = readout
+        .iter()
+        .map(|(compound, count)| format!("{compound}: {count}"))
+        .collect::<Vec<_>>();
+    lines.push(String::new());
+
+    for index in 0..size {
+        let exact = *rng.choose(&EXACT);
+
+        let mut compounds = match index {
+            _ if index == part1 => vec![("cats", count("cats")), (exact, count(exact))],
+            // Cats are greater in part 2, goldfish fewer
+            _ if index == part2 => vec![
+                ("cats", count("cats") + 1),
+                ("goldfish", count("goldfish") - 1),
+                (exact, count(exact)),
+            ],
+            _ => {
+                let other = (count(exact) + rng.range(1..=MAX_COUNT)) % (MAX_COUNT + 1);
+                vec![(exact, other)]
+            }
+        };
+
+        // Remaining compounds are random, but different from those already present
+        while compounds.len() < 3 {
+            let (compound, _) = *rng.choose(&readout);
+            if compound == exact || compounds.iter().any(|(name, _)| *name == compound) {
+                continue;
+            }
+
+            let value = match index == part1 {
+                true => count(compound),
+                false => rng.range(0..=MAX_COUNT),
+            };
+            compounds.push((compound, value));
+        }
+
+        rng.shuffle(&mut compounds);
+        let compounds = compounds
+            .iter()
+            .map(|(compound, count)| format!("{compound}: {count}"))
+            .collect::<Vec<_>>();
+
+        lines.push(format!("Sue {}: {}", index + 1, compounds.join(", ")));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 500);
+        assert_eq!(lines, generate(7, 500));
+        assert_ne!(lines, generate(8, 500));
+
+        let (readout, aunts) = Solution::parse_input(lines).unwrap();
+        assert_eq!(aunts.len(), 500);
+
+        let solution = Solution::from_aunts(aunts);
+        let part1 = solution.find_aunt_with_gift(&readout).unwrap();
+        let part2 = solution.find_aunt_with_gift_real(&readout).unwrap();
+        assert_ne!(part1, part2);
+    }
+}
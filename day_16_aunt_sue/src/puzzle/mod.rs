@@ -1,2 +1,4 @@
 pub mod aunt;
+pub mod error;
+pub mod generator;
 pub mod solution;
@@ -1,14 +1,19 @@
 use std::error::Error;
 
-use puzzler::env::project;
+use common::explain::Explain;
+use common::{input, parser};
 use puzzler::parsers::parser::Parser;
 use puzzler::puzzler::puzzle::Puzzle;
 
 use crate::puzzle::aunt::Aunt;
+use crate::puzzle::error::PuzzleError;
+
+// Compounds detected in the gift with their counts
+pub type Readout = Vec<(String, usize)>;
 
 pub struct Solution {
     aunts: Vec<Aunt>,
-    gifts: Vec<(String, usize)>,
+    gifts: Readout,
 }
 
 impl Puzzle for Solution {
@@ -17,81 +22,107 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<std::path::PathBuf> {
-        Some(
-            project::get_project_file("../input/day_16.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_16.txt [{err}]")),
-        )
+        Some(input::file_path(16))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.aunts = Parser::parse_lines_with_regex(
-            lines,
-            r"^Sue (\d+): (\w+): (\d+), (\w+): (\d+), (\w+): (\d+)",
-            |params| {
-                if params.len() != 7 {
-                    return Err(format!("Expected exactly 7 parameters '{params:?}'").into());
-                }
-
-                let mut params_iter = params.into_iter();
-
-                let id = params_iter
-                    .next()
-                    .unwrap()
-                    .parse::<usize>()
-                    .map_err(|err| format!("Failed to convert 'id' to usize [{err:?}]"))?;
-
-                let mut compounds = Vec::new();
-
-                for _ in 0..3 {
-                    let comp = params_iter.next().unwrap();
-
-                    let count = params_iter
-                        .next()
-                        .unwrap()
-                        .parse::<usize>()
-                        .map_err(|err| format!("Failed to convert 'count' to usize [{err:?}]"))?;
-
-                    compounds.push((comp, count));
-                }
-
-                Ok(Aunt::new(id, compounds))
-            },
-        )?;
-
+        (self.gifts, self.aunts) = Self::parse_input(lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let aunt_nr = self.find_aunt_with_gift(&self.gifts).unwrap();
+        let aunt_nr = self.find_aunt_with_gift(&self.gifts)?;
         Ok(aunt_nr.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let aunt_nr = self.find_aunt_with_gift_real(&self.gifts).unwrap();
+        let aunt_nr = self.find_aunt_with_gift_real(&self.gifts)?;
         Ok(aunt_nr.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
             aunts: Vec::new(),
-            gifts: vec![
-                (String::from("children"), 3),
-                (String::from("cats"), 7),
-                (String::from("samoyeds"), 2),
-                (String::from("pomeranians"), 3),
-                (String::from("akitas"), 0),
-                (String::from("vizslas"), 0),
-                (String::from("goldfish"), 5),
-                (String::from("trees"), 3),
-                (String::from("cars"), 2),
-                (String::from("perfumes"), 1),
-            ],
+            gifts: Vec::new(),
+        }
+    }
+
+    pub fn from_aunts(aunts: Vec<Aunt>) -> Self {
+        Self {
+            aunts,
+            gifts: Vec::new(),
         }
     }
 
-    fn find_aunt_with_gift(&self, gifts: &[(String, usize)]) -> Option<usize> {
+    // MFCSAM readout of the gift followed by an empty line and the list of aunts
+    pub fn parse_input(lines: Vec<String>) -> Result<(Readout, Vec<Aunt>), PuzzleError> {
+        // Split input file by empty line
+        let mut groups = Parser::group_lines(lines);
+
+        if groups.len() != 2 {
+            return Err(PuzzleError::GroupCount {
+                found: groups.len(),
+            });
+        }
+
+        // First part is MFCSAM readout of the gift
+        let readout = groups.remove(0);
+        let gifts = parser::parse_lines_with_regex(
+            &readout,
+            r"^(\w+): (\d+)$",
+            |line, params| -> Result<_, PuzzleError> {
+                let count = Self::parse_number(line, "count", &params[1])?;
+                Ok((params[0].clone(), count))
+            },
+        )?;
+
+        // Second part are aunts, separated by an empty line
+        let aunts = groups.remove(0);
+        let aunts = parser::parse_lines_with_regex_offset(
+            &aunts,
+            readout.len() + 1,
+            r"^Sue (\d+): (\w+): (\d+), (\w+): (\d+), (\w+): (\d+)$",
+            |line, params| -> Result<_, PuzzleError> {
+                let id = Self::parse_number(line, "id", &params[0])?;
+
+                let compounds = params[1..]
+                    .chunks(2)
+                    .map(|comp| {
+                        Ok((
+                            comp[0].clone(),
+                            Self::parse_number(line, "count", &comp[1])?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, PuzzleError>>()?;
+
+                Ok(Aunt::new(id, compounds))
+            },
+        )?;
+
+        Ok((gifts, aunts))
+    }
+
+    fn parse_number(line: usize, field: &'static str, text: &str) -> Result<usize, PuzzleError> {
+        text.parse::<usize>()
+            .map_err(|_| PuzzleError::InvalidNumber {
+                line,
+                field,
+                text: text.to_string(),
+            })
+    }
+
+    // Number of the only aunt whose known compounds match the readout exactly
+    pub fn find_aunt_with_gift(&self, gifts: &[(String, usize)]) -> Result<usize, PuzzleError> {
         let aunts = self
             .aunts
             .iter()
@@ -105,14 +136,15 @@ impl Solution {
             })
             .collect::<Vec<_>>();
 
-        if aunts.len() != 1 {
-            panic!("More than one aunt found '{aunts:?}'");
-        }
-
-        Some(aunts[0].get_id())
+        Self::single_aunt(&aunts)
     }
 
-    fn find_aunt_with_gift_real(&self, gifts: &[(String, usize)]) -> Option<usize> {
+    // Number of the only aunt matching the readout of the outdated retroencabulator, cats and
+    // trees are greater than the readout, pomeranians and goldfish are fewer
+    pub fn find_aunt_with_gift_real(
+        &self,
+        gifts: &[(String, usize)],
+    ) -> Result<usize, PuzzleError> {
         let aunts = self
             .aunts
             .iter()
@@ -130,37 +162,96 @@ impl Solution {
             })
             .collect::<Vec<_>>();
 
-        if aunts.len() != 1 {
-            panic!("More than one aunt found '{aunts:?}'");
-        }
+        Self::single_aunt(&aunts)
+    }
 
-        Some(aunts[0].get_id())
+    fn single_aunt(aunts: &[&Aunt]) -> Result<usize, PuzzleError> {
+        match aunts {
+            [] => Err(PuzzleError::AuntNotFound),
+            [aunt] => Ok(aunt.get_id()),
+            _ => Err(PuzzleError::AmbiguousAunt {
+                ids: aunts.iter().map(|aunt| aunt.get_id()).collect(),
+            }),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
+    use common::parser::{InvalidLine, SyntaxError};
 
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "213");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "213");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "323");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "323");
+    }
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_find_aunt_ambiguous() {
+        let mut solution = Solution::new();
+        solution
+            .parse_content(lines(&[
+                "cats: 7",
+                "",
+                "Sue 1: trees: 1, cars: 2, akitas: 3",
+                "Sue 2: trees: 1, cars: 2, akitas: 3",
+            ]))
+            .unwrap();
+
+        assert_eq!(
+            solution.find_aunt_with_gift(&solution.gifts),
+            Err(PuzzleError::AmbiguousAunt { ids: vec![1, 2] })
+        );
+        assert_eq!(
+            solution.find_aunt_with_gift(&[(String::from("trees"), 5)]),
+            Err(PuzzleError::AuntNotFound)
+        );
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(lines(&[
+                "cats: 7",
+                "",
+                "Sue 1: trees: 1, cars: 2, akitas: 3",
+                "Sue 2: trees: 1, cars: 2",
+            ]))
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::Syntax(SyntaxError {
+                lines: vec![InvalidLine {
+                    line: 4,
+                    column: 25,
+                    text: String::from("Sue 2: trees: 1, cars: 2")
+                }]
+            }))
+        );
     }
 }
@@ -0,0 +1,6 @@
+// Day 17: No Such Thing as Too Much. Combinations of containers which fit the eggnog exactly.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
@@ -0,0 +1,19 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    InvalidNumber { line: usize, text: String },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::InvalidNumber { line, text } => {
+                write!(f, "Line {line}: failed to parse container size '{text}'")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
@@ -0,0 +1,24 @@
+use common::random::Rng;
+
+// Containers of random capacity, size is the number of containers. Every combination is
+// tried by the solution, therefore sizes above twenty-five take long.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    (0..size).map(|_| rng.range(5..=50).to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 15);
+        assert_eq!(lines, generate(7, 15));
+        assert_ne!(lines, generate(8, 15));
+
+        let containers = Solution::parse_input(&lines).unwrap();
+        assert_eq!(containers.len(), 15);
+    }
+}
@@ -1,10 +1,12 @@
 use std::error::Error;
 use std::path::PathBuf;
 
-use puzzler::env::project;
-use puzzler::parsers::parser::Parser;
+use common::explain::Explain;
+use common::input;
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
+
 pub struct Solution {
     numbers: Vec<usize>,
 }
@@ -15,14 +17,11 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<PathBuf> {
-        Some(
-            project::get_project_file("../input/day_17.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_17.txt [{err}]")),
-        )
+        Some(input::file_path(17))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.numbers = Parser::parse_lines_to_unsigned_integer(lines)?;
+        self.numbers = Self::parse_input(&lines)?;
         Ok(())
     }
 
@@ -37,6 +36,14 @@ impl Puzzle for Solution {
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -44,7 +51,24 @@ impl Solution {
         }
     }
 
-    fn count_combinations(containers: &[usize], total: usize) -> usize {
+    // Every line is a capacity of a single container
+    pub fn parse_input(lines: &[String]) -> Result<Vec<usize>, PuzzleError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, text)| {
+                text.trim()
+                    .parse::<usize>()
+                    .map_err(|_| PuzzleError::InvalidNumber {
+                        line: index + 1,
+                        text: text.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    // Number of combinations of containers which hold exactly total liters
+    pub fn count_combinations(containers: &[usize], total: usize) -> usize {
         let mut solutions = Vec::new();
 
         // Sort containers from smallest to highest. This should speed up discarding dead ends.
@@ -55,7 +79,9 @@ impl Solution {
         solutions.len()
     }
 
-    fn count_minimum_different_ways(containers: &[usize], total: usize) -> usize {
+    // Number of combinations with the minimum number of containers which hold exactly total
+    // liters
+    pub fn count_minimum_different_ways(containers: &[usize], total: usize) -> usize {
         let mut solutions = Vec::new();
 
         // Sort containers from smallest to highest. This should speed up discarding dead ends.
@@ -64,8 +90,10 @@ impl Solution {
 
         Self::dfs(&containers, total, &mut solutions);
 
-        // Find minimum number of containers
-        let min = solutions.iter().map(|c| c.len()).min().unwrap();
+        // Find minimum number of containers, no solution means no way
+        let Some(min) = solutions.iter().map(|c| c.len()).min() else {
+            return 0;
+        };
 
         let filtered = solutions
             .iter()
@@ -107,28 +135,39 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "1638");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "1638");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "17");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "17");
+    }
+
+    // Containers of the puzzle statement hold 25 liters
+    #[test]
+    fn test_examples() {
+        let lines = fixtures::read_example(env!("CARGO_MANIFEST_DIR"), "example_01.txt");
+        let containers = Solution::parse_input(&lines).unwrap();
+
+        assert_eq!(Solution::count_combinations(&containers, 25), 4);
+        assert_eq!(Solution::count_minimum_different_ways(&containers, 25), 3);
     }
 
     #[test]
@@ -166,5 +205,22 @@ mod tests {
             Solution::count_minimum_different_ways(&[20, 15, 10, 5, 5], 25),
             3
         );
+        assert_eq!(Solution::count_minimum_different_ways(&[20, 15], 25), 0);
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![String::from("20"), String::from("-5")])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidNumber {
+                line: 2,
+                text: String::from("-5")
+            })
+        );
     }
 }
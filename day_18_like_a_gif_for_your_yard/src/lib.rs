@@ -0,0 +1,7 @@
+// Day 18: Like a GIF For Your Yard. Game of life animation of a grid of lights.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::{LIGHT_OFF, LIGHT_ON, Solution};
+pub use puzzler::grids::grid::Grid;
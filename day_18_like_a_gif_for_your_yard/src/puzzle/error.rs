@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    EmptyGrid,
+    RaggedRow {
+        line: usize,
+        text: String,
+    },
+    InvalidLight {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::EmptyGrid => write!(f, "Grid of lights is empty"),
+            PuzzleError::RaggedRow { line, text } => write!(
+                f,
+                "Line {line}: row length differs from the first row '{text}'"
+            ),
+            PuzzleError::InvalidLight {
+                line,
+                column,
+                found,
+            } => write!(f, "Line {line}, column {column}: invalid light '{found}'"),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
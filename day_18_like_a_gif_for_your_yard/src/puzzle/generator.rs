@@ -0,0 +1,38 @@
+use common::random::Rng;
+
+use crate::puzzle::solution::{LIGHT_OFF, LIGHT_ON};
+
+// Square grid of random lights, size is the length of its side
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.chance(1, 2) {
+                        LIGHT_ON
+                    } else {
+                        LIGHT_OFF
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 10);
+        assert_eq!(lines, generate(7, 10));
+        assert_ne!(lines, generate(8, 10));
+
+        let grid = Solution::parse_input(lines).unwrap();
+        assert!(Solution::count_lights_on(&grid) <= 100);
+    }
+}
@@ -1,15 +1,19 @@
 use std::error::Error;
 use std::path::PathBuf;
 
-use puzzler::env::project;
+use common::explain::Explain;
+use common::input;
+use common::{log_debug, log_trace};
 use puzzler::grids::direction::Direction;
 use puzzler::grids::grid::Grid;
 use puzzler::grids::point::Point;
 use puzzler::parsers::parser::Parser;
 use puzzler::puzzler::puzzle::Puzzle;
 
-const LIGHT_ON: char = '#';
-const LIGHT_OFF: char = '.';
+use crate::puzzle::error::PuzzleError;
+
+pub const LIGHT_ON: char = '#';
+pub const LIGHT_OFF: char = '.';
 
 pub struct Solution {
     grid: Grid,
@@ -21,27 +25,30 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<PathBuf> {
-        Some(
-            project::get_project_file("../input/day_18.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_18.txt [{err}]")),
-        )
+        Some(input::file_path(18))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.grid = Parser::parse_lines_to_grid(lines)?;
+        self.grid = Self::parse_input(lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
         let grid = Solution::run_steps(&self.grid, 100, false);
-        let lights_on = grid.get_value(LIGHT_ON).len();
-        Ok(lights_on.to_string())
+        Ok(Self::count_lights_on(&grid).to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
         let grid = Solution::run_steps(&self.grid, 100, true);
-        let lights_on = grid.get_value(LIGHT_ON).len();
-        Ok(lights_on.to_string())
+        Ok(Self::count_lights_on(&grid).to_string())
+    }
+}
+
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -52,7 +59,52 @@ impl Solution {
         }
     }
 
-    fn run_steps(grid: &Grid, steps: usize, stuck: bool) -> Grid {
+    // Grid of lights, LIGHT_ON or LIGHT_OFF
+    pub fn parse_input(lines: Vec<String>) -> Result<Grid, Box<dyn Error>> {
+        Self::validate_lines(&lines)?;
+
+        Parser::parse_lines_to_grid(lines)
+    }
+
+    // Number of lights which are on
+    pub fn count_lights_on(grid: &Grid) -> usize {
+        grid.get_value(LIGHT_ON).len()
+    }
+
+    // Grid shall be a non-empty rectangle of lights
+    fn validate_lines(lines: &[String]) -> Result<(), PuzzleError> {
+        let cols = lines
+            .first()
+            .map(|line| line.chars().count())
+            .filter(|&cols| cols > 0)
+            .ok_or(PuzzleError::EmptyGrid)?;
+
+        for (index, text) in lines.iter().enumerate() {
+            if text.chars().count() != cols {
+                return Err(PuzzleError::RaggedRow {
+                    line: index + 1,
+                    text: text.clone(),
+                });
+            }
+
+            if let Some((col, found)) = text
+                .chars()
+                .enumerate()
+                .find(|(_, c)| *c != LIGHT_ON && *c != LIGHT_OFF)
+            {
+                return Err(PuzzleError::InvalidLight {
+                    line: index + 1,
+                    column: col + 1,
+                    found,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // Animate the grid by the given number of steps, corner lights are always on if stuck
+    pub fn run_steps(grid: &Grid, steps: usize, stuck: bool) -> Grid {
         // Next grid will hold new value. We need it because all point shall be investigates
         // simultaneuosly using previous grid.
         let mut next_grid = grid.clone();
@@ -61,10 +113,14 @@ impl Solution {
             Self::stuck_corners_light_on(&mut next_grid);
         }
 
-        for _ in 0..steps {
+        for step in 0..steps {
             let current_grid = next_grid.clone();
 
-            next_grid.print();
+            log_trace!(
+                "Grid before step {}:\n{}",
+                step + 1,
+                Self::format_grid(&current_grid)
+            );
 
             for r in 0..current_grid.rows() {
                 for c in 0..current_grid.cols() {
@@ -80,16 +136,16 @@ impl Solution {
                         .filter(|(pt, _direction)| current_grid[*pt] == LIGHT_ON)
                         .count();
 
+                    // Lights are validated when parsed, anything else is off
                     let new_light = match current_grid[point] {
-                        '#' => match neighbors_on {
+                        LIGHT_ON => match neighbors_on {
                             2 | 3 => LIGHT_ON,
                             _ => LIGHT_OFF,
                         },
-                        '.' => match neighbors_on {
+                        _ => match neighbors_on {
                             3 => LIGHT_ON,
                             _ => LIGHT_OFF,
                         },
-                        c => panic!("Invalid character in grid '{c}'"),
                     };
 
                     next_grid[point] = new_light;
@@ -101,13 +157,36 @@ impl Solution {
             }
         }
 
-        println!();
-        next_grid.print();
+        log_debug!(
+            "Grid after {steps} step(s):\n{}",
+            Self::format_grid(&next_grid)
+        );
         next_grid
     }
 
+    // Rows of the grid joined by new lines, e.g. for logging
+    pub fn format_grid(grid: &Grid) -> String {
+        (0..grid.rows())
+            .map(|r| {
+                (0..grid.cols())
+                    .map(|c| {
+                        grid[Point {
+                            x: r as isize,
+                            y: c as isize,
+                        }]
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     // All four courners are stuck, i.e. always lighted on
     fn stuck_corners_light_on(grid: &mut Grid) {
+        if grid.rows() == 0 || grid.cols() == 0 {
+            return;
+        }
+
         let positions = [
             Point { x: 0, y: 0 },
             Point {
@@ -132,28 +211,30 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
+    use common::log::{self, Level};
     use puzzler::{grids::grid::Grid, parsers::parser::Parser, puzzler::puzzle::Puzzle};
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "768");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "768");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "781");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "781");
     }
 
     fn construct_grid() -> Grid {
@@ -163,6 +244,19 @@ mod tests {
         .unwrap()
     }
 
+    // Grid of the puzzle statement is animated by 4 steps, or 5 steps with stuck corners
+    #[test]
+    fn test_examples() {
+        let lines = fixtures::read_example(env!("CARGO_MANIFEST_DIR"), "example_01.txt");
+        let grid = Solution::parse_input(lines).unwrap();
+
+        let animated = Solution::run_steps(&grid, 4, false);
+        assert_eq!(Solution::count_lights_on(&animated), 4);
+
+        let animated = Solution::run_steps(&grid, 5, true);
+        assert_eq!(Solution::count_lights_on(&animated), 17);
+    }
+
     #[test]
     fn test_run_steps_not_stuck() {
         let grid = construct_grid();
@@ -184,4 +278,55 @@ mod tests {
         // let grid = Solution::run_steps(&grid, 4, true);
         // assert!(grid.equals(&["##.###", ".##..#", ".##...", ".##...", "#.#...", "##...#",]));
     }
+
+    #[test]
+    fn test_run_steps_logged() {
+        let grid = construct_grid();
+
+        // Silent unless the level is raised
+        let (_, captured) = log::capture(Level::Warn, || Solution::run_steps(&grid, 2, false));
+        assert!(captured.is_empty());
+
+        let (_, captured) = log::capture(Level::Trace, || Solution::run_steps(&grid, 2, false));
+        let messages = captured.iter().map(|(level, _)| *level).collect::<Vec<_>>();
+        assert_eq!(messages, vec![Level::Trace, Level::Trace, Level::Debug]);
+        assert_eq!(
+            captured[0].1,
+            "Grid before step 1:\n.#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####.."
+        );
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+
+        let err = solution.parse_content(vec![]).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::EmptyGrid)
+        );
+
+        let err = solution
+            .parse_content(vec![String::from(".#."), String::from(".#")])
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::RaggedRow {
+                line: 2,
+                text: String::from(".#")
+            })
+        );
+
+        let err = solution
+            .parse_content(vec![String::from(".#."), String::from(".o.")])
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidLight {
+                line: 2,
+                column: 2,
+                found: 'o'
+            })
+        );
+    }
 }
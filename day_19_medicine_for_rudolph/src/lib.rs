@@ -0,0 +1,8 @@
+// Day 19: Medicine for Rudolph. Molecule replacements of the Red-Nosed Reindeer nuclear
+// fusion/fission plant.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::recipes::Recipes;
+pub use puzzle::solution::Solution;
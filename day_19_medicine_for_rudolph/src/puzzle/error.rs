@@ -0,0 +1,61 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::budget::Exceeded;
+use common::parser::SyntaxError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    GroupCount {
+        found: usize,
+    },
+    Syntax(SyntaxError),
+    MoleculeLineCount {
+        line: usize,
+        found: usize,
+    },
+    Stuck {
+        molecule: String,
+        steps: usize,
+    },
+    BudgetExceeded {
+        exceeded: Exceeded,
+        molecule: String,
+        steps: usize,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::GroupCount { found } => {
+                write!(f, "Exactly two groups are expected, found '{found}'")
+            }
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::MoleculeLineCount { line, found } => write!(
+                f,
+                "Line {line}: molecule shall be on exactly one line, found '{found}'"
+            ),
+            PuzzleError::Stuck { molecule, steps } => write!(
+                f,
+                "Molecule '{molecule}' can not be reduced after {steps} step(s)"
+            ),
+            PuzzleError::BudgetExceeded {
+                exceeded,
+                molecule,
+                steps,
+            } => write!(
+                f,
+                "{exceeded}, molecule reduced to '{molecule}' in {steps} replacement(s)"
+            ),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
@@ -0,0 +1,94 @@
+use common::random::Rng;
+
+// Elements expanded by the recipes
+const ELEMENTS: [&str; 6] = ["Al", "Ca", "Mg", "Si", "Th", "Ti"];
+
+// Every element ends as its own final element, e.g. 'Al' as 'Li'
+const FINALS: [&str; 6] = ["Li", "Na", "Ne", "He", "Zn", "Fe"];
+
+// Elements enclosing every expansion
+const OPEN: &str = "Rn";
+const CLOSE: &str = "Ar";
+
+// Recipes and a molecule derived from the electron, size is the number of expansions.
+// Expansion replaces an element by two elements enclosed by 'Rn' and 'Ar' and every pair of
+// elements belongs to a single recipe. No replacement overlaps another one, therefore the
+// molecule is reduced back to the electron in any order of replacements.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    // Deal pairs of elements between the electron and the elements
+    let mut pairs = ELEMENTS
+        .iter()
+        .flat_map(|first| ELEMENTS.iter().map(move |second| [*first, *second]))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut pairs);
+
+    let mut expansions = vec![("e", pairs.split_off(pairs.len() - 2))];
+    for element in ELEMENTS {
+        let count = rng.range(1..=4);
+        expansions.push((element, pairs.split_off(pairs.len() - count)));
+    }
+
+    // Electron is expanded always
+    let (_, electron) = &expansions[0];
+    let [first, second] = *rng.choose(electron);
+    let mut molecule = vec![OPEN, first, second, CLOSE];
+
+    for _ in 1..size {
+        let positions = (0..molecule.len())
+            .filter(|index| ELEMENTS.contains(&molecule[*index]))
+            .collect::<Vec<_>>();
+        let position = *rng.choose(&positions);
+
+        let (_, pairs) = expansions
+            .iter()
+            .find(|(element, _)| *element == molecule[position])
+            .unwrap();
+        let [first, second] = *rng.choose(pairs);
+
+        molecule.splice(position..=position, [OPEN, first, second, CLOSE]);
+    }
+
+    let finals = molecule
+        .iter()
+        .map(|element| match ELEMENTS.iter().position(|e| e == element) {
+            Some(index) => FINALS[index],
+            None => element,
+        })
+        .collect::<String>();
+
+    let mut lines = Vec::new();
+    for (element, pairs) in &expansions {
+        for [first, second] in pairs {
+            lines.push(format!("{element} => {OPEN}{first}{second}{CLOSE}"));
+        }
+    }
+    for (element, last) in ELEMENTS.iter().zip(FINALS) {
+        lines.push(format!("{element} => {last}"));
+    }
+
+    lines.push(String::new());
+    lines.push(finals);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use common::budget::Budget;
+
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 50);
+        assert_eq!(lines, generate(7, 50));
+        assert_ne!(lines, generate(8, 50));
+
+        // Every expansion adds an element, all elements end as final elements
+        let (recipes, molecule) = Solution::parse_input(lines).unwrap();
+        let steps =
+            Solution::min_steps_fabricate_molecule(&recipes, &molecule, &Budget::unlimited());
+        assert_eq!(steps, Ok(50 + 51));
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::{HashMap, hash_map::Iter};
 
-#[derive(Default)]
+// Replacements of molecules keyed by the replaced molecule
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Recipes {
     recipes: HashMap<String, Vec<String>>,
 }
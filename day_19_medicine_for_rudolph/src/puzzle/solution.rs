@@ -2,10 +2,13 @@ use std::collections::HashSet;
 use std::error::Error;
 use std::path::PathBuf;
 
-use puzzler::env::project;
+use common::budget::{self, Budget};
+use common::explain::Explain;
+use common::{input, parser};
 use puzzler::parsers::parser::Parser;
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
 use crate::puzzle::recipes::Recipes;
 
 pub struct Solution {
@@ -19,44 +22,11 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<PathBuf> {
-        Some(
-            project::get_project_file("../input/day_19.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_19.txt [{err}]")),
-        )
+        Some(input::file_path(19))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        // Split input file by empty line
-        let mut groups = Parser::group_lines(lines);
-
-        if groups.len() != 2 {
-            return Err(
-                format!("Exactly two groups are expected, found '{}'", groups.len()).into(),
-            );
-        }
-
-        // First part are recipes
-        let recipes = groups.remove(0);
-        let recipes = Parser::parse_lines_with_regex(recipes, r"^(\w+) => (\w+)", |mut params| {
-            if params.len() != 2 {
-                return Err(
-                    format!("Expected exactly 2 parameters, found '{}'", params.len()).into(),
-                );
-            }
-
-            let key = params.remove(0);
-            let replacement = params.remove(0);
-
-            Ok((key, replacement))
-        })?;
-
-        for (key, replacement) in recipes {
-            self.recipes.insert(&key, &[replacement.as_str()]);
-        }
-
-        // Second part is molecule
-        self.molecule = groups.remove(0).remove(0);
-
+        (self.recipes, self.molecule) = Self::parse_input(lines)?;
         Ok(())
     }
 
@@ -66,11 +36,23 @@ impl Puzzle for Solution {
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let count = Solution::min_steps_fabricate_molecule(&self.recipes, &self.molecule);
+        let count = Solution::min_steps_fabricate_molecule(
+            &self.recipes,
+            &self.molecule,
+            &budget::current(),
+        )?;
         Ok(count.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -79,7 +61,49 @@ impl Solution {
         }
     }
 
-    fn count_generated_molecules(recipes: &Recipes, molecule: &str) -> usize {
+    // Replacement recipes followed by an empty line and the medicine molecule
+    pub fn parse_input(lines: Vec<String>) -> Result<(Recipes, String), PuzzleError> {
+        // Split input file by empty line
+        let mut groups = Parser::group_lines(lines);
+
+        if groups.len() != 2 {
+            return Err(PuzzleError::GroupCount {
+                found: groups.len(),
+            });
+        }
+
+        // First part are recipes
+        let lines = groups.remove(0);
+        let mappings = parser::parse_lines_with_regex(
+            &lines,
+            r"^(\w+) => (\w+)$",
+            |_, mut params| -> Result<_, PuzzleError> {
+                let key = params.remove(0);
+                let replacement = params.remove(0);
+
+                Ok((key, replacement))
+            },
+        )?;
+
+        let mut recipes = Recipes::new();
+        for (key, replacement) in mappings {
+            recipes.insert(&key, &[replacement.as_str()]);
+        }
+
+        // Second part is molecule, separated by an empty line
+        let mut molecule = groups.remove(0);
+        if molecule.len() != 1 {
+            return Err(PuzzleError::MoleculeLineCount {
+                line: lines.len() + 2,
+                found: molecule.len(),
+            });
+        }
+
+        Ok((recipes, molecule.remove(0)))
+    }
+
+    // Number of distinct molecules created by a single replacement
+    pub fn count_generated_molecules(recipes: &Recipes, molecule: &str) -> usize {
         let mut unique_molecules = HashSet::new();
 
         for (key, replacements) in recipes.iter() {
@@ -102,16 +126,30 @@ impl Solution {
         unique_molecules.len()
     }
 
-    fn min_steps_fabricate_molecule(recipes: &Recipes, molecule: &str) -> usize {
+    // Fewest replacements which fabricate the molecule starting from a single electron 'e'
+    pub fn min_steps_fabricate_molecule(
+        recipes: &Recipes,
+        molecule: &str,
+        budget: &Budget,
+    ) -> Result<usize, PuzzleError> {
         // Let's use a trick. Reverse the order from the recipes (key and replacements) and
         // replace backwards. Count each replacement until we will find e (electron).
         let recipes = recipes.reverse();
         let keys = recipes.get_keys_descending_len();
         let mut new_molecule = molecule.to_string();
         let mut count = 0;
+        let mut meter = budget.meter();
 
         // Repeat until all molecule elements are electorns
         while !new_molecule.as_str().chars().all(|b| b == 'e') {
+            meter
+                .step()
+                .map_err(|exceeded| PuzzleError::BudgetExceeded {
+                    exceeded,
+                    molecule: new_molecule.clone(),
+                    steps: count,
+                })?;
+
             let issue_detector = count;
 
             // Go through all keys
@@ -130,44 +168,52 @@ impl Solution {
                 }
             }
 
-            // Check for infinite loop
+            // Molecule was not changed during this round, this would lead to infinite loop
             if issue_detector == count {
-                panic!(
-                    "Molecule was not changed during this round. This would lead to infinite loop :-("
-                )
+                return Err(PuzzleError::Stuck {
+                    molecule: new_molecule,
+                    steps: count,
+                });
             }
         }
 
-        count
+        Ok(count)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::budget::{Exceeded, Limit, Limits};
+    use common::fixtures;
 
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "518");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "518");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "200");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "200");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
@@ -187,10 +233,55 @@ mod tests {
         recipes.insert("H", &["HO", "OH"]);
         recipes.insert("O", &["HH"]);
 
-        assert_eq!(Solution::min_steps_fabricate_molecule(&recipes, "HOH"), 3);
         assert_eq!(
-            Solution::min_steps_fabricate_molecule(&recipes, "HOHOHO"),
-            6
+            Solution::min_steps_fabricate_molecule(&recipes, "HOH", &Budget::unlimited()),
+            Ok(3)
+        );
+        assert_eq!(
+            Solution::min_steps_fabricate_molecule(&recipes, "HOHOHO", &Budget::unlimited()),
+            Ok(6)
+        );
+        assert_eq!(
+            Solution::min_steps_fabricate_molecule(&recipes, "HX", &Budget::unlimited()),
+            Err(PuzzleError::Stuck {
+                molecule: String::from("eX"),
+                steps: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_min_steps_fabricate_molecule_budget_exceeded() {
+        let mut recipes = Recipes::default();
+        recipes.insert("e", &["H", "O"]);
+        recipes.insert("H", &["HO", "OH"]);
+        recipes.insert("O", &["HH"]);
+
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(2),
+        });
+
+        // Replacements of the same length are tried in arbitrary order, only the length of the
+        // reduced molecule is known
+        let err = Solution::min_steps_fabricate_molecule(&recipes, "HOHOHO", &budget).unwrap_err();
+        let PuzzleError::BudgetExceeded {
+            exceeded,
+            molecule,
+            steps,
+        } = err
+        else {
+            panic!("Unexpected error '{err}'");
+        };
+
+        assert_eq!(
+            exceeded,
+            Exceeded {
+                limit: Limit::Steps(2),
+                steps: 2
+            }
         );
+        assert_eq!(molecule.len(), 4);
+        assert_eq!(steps, 2);
     }
 }
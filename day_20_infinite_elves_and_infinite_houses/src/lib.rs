@@ -0,0 +1,6 @@
+// Day 20: Infinite Elves and Infinite Houses. Lowest house number which gets enough presents.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
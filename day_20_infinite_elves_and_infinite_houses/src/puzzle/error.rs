@@ -0,0 +1,30 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::budget::Exceeded;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    LineCount { found: usize },
+    InvalidNumber { line: usize, text: String },
+    BudgetExceeded { exceeded: Exceeded, house: usize },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::LineCount { found } => write!(
+                f,
+                "Exactly one line is expected in input file, but '{found}' found"
+            ),
+            PuzzleError::InvalidNumber { line, text } => {
+                write!(f, "Line {line}: failed to parse 'presents' from '{text}'")
+            }
+            PuzzleError::BudgetExceeded { exceeded, house } => {
+                write!(f, "{exceeded}, no house found below house '{house}'")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
@@ -0,0 +1,25 @@
+use common::random::Rng;
+
+// Number of presents, size is the upper bound of the lowest house number. House gets at
+// least ten presents per its number from its own elf.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    vec![rng.range(size * 5..=size * 10).to_string()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 1_000);
+        assert_eq!(lines, generate(7, 1_000));
+        assert_ne!(lines, generate(8, 1_000));
+
+        let presents = Solution::parse_input(&lines).unwrap();
+        assert!((5_000..=10_000).contains(&presents));
+    }
+}
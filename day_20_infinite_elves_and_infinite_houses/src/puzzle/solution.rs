@@ -1,43 +1,86 @@
 use std::error::Error;
+use std::path::PathBuf;
 
+use common::budget::{self, Budget};
+use common::explain::Explain;
+use common::input;
 use divisors_fixed::Divisors;
 use puzzler::puzzler::puzzle::Puzzle;
 
-const PRESENTS: usize = 36_000_000;
+use crate::puzzle::error::PuzzleError;
 
-pub struct Solution {}
+pub struct Solution {
+    presents: usize,
+}
 
 impl Puzzle for Solution {
     fn name(&self) -> &str {
         "--- Day 20: Infinite Elves and Infinite Houses ---"
     }
 
+    fn get_input_file_path(&self) -> Option<PathBuf> {
+        Some(input::file_path(20))
+    }
+
+    fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
+        self.presents = Self::parse_input(&lines)?;
+        Ok(())
+    }
+
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let house = Self::find_house_number_infinite(PRESENTS);
+        let house = Self::find_house_number_infinite(self.presents)?;
         Ok(house.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let house = Self::find_house_number_finite(PRESENTS);
+        let house = Self::find_house_number_finite(self.presents)?;
         Ok(house.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
-        Self {}
+        Self { presents: 0 }
+    }
+
+    // Number of presents is a single line
+    pub fn parse_input(lines: &[String]) -> Result<usize, PuzzleError> {
+        if lines.len() != 1 {
+            return Err(PuzzleError::LineCount { found: lines.len() });
+        }
+
+        lines[0]
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| PuzzleError::InvalidNumber {
+                line: 1,
+                text: lines[0].clone(),
+            })
     }
 
     // Generic function is used to distinguish part 1 and part 2 of the puzzle. It is
     // sufficient as only computation of presents per house differs, i.e., the loop and
-    // summing algorithms are the same.
-    fn find_house_number<F>(presents: usize, func: F) -> usize
+    // summing algorithms are the same. Every house is a single step of the budget.
+    fn find_house_number<F>(presents: usize, func: F, budget: &Budget) -> Result<usize, PuzzleError>
     where
         F: Fn(usize) -> usize,
     {
         let mut house = 1;
+        let mut meter = budget.meter();
 
         loop {
+            meter
+                .step()
+                .map_err(|exceeded| PuzzleError::BudgetExceeded { exceeded, house })?;
+
             let count = func(house);
 
             if count >= presents {
@@ -46,7 +89,7 @@ impl Solution {
             house += 1;
         }
 
-        house
+        Ok(house)
     }
 
     fn count_presents_infinite(house: usize) -> usize {
@@ -57,8 +100,10 @@ impl Solution {
         elfs.iter().map(|nr| nr * 10).sum()
     }
 
-    fn find_house_number_infinite(presents: usize) -> usize {
-        Self::find_house_number(presents, Self::count_presents_infinite)
+    // Lowest house which gets at least the given number of presents, every elf delivers ten
+    // presents to every house. Search is limited by the budget of the thread.
+    pub fn find_house_number_infinite(presents: usize) -> Result<usize, PuzzleError> {
+        Self::find_house_number(presents, Self::count_presents_infinite, &budget::current())
     }
 
     fn count_presents_finite(house: usize) -> usize {
@@ -78,35 +123,44 @@ impl Solution {
         elfs.iter().map(|nr| nr * 11).sum()
     }
 
-    fn find_house_number_finite(presents: usize) -> usize {
-        Self::find_house_number(presents, Self::count_presents_finite)
+    // Lowest house which gets at least the given number of presents, every elf delivers eleven
+    // presents to 50 houses only. Search is limited by the budget of the thread.
+    pub fn find_house_number_finite(presents: usize) -> Result<usize, PuzzleError> {
+        Self::find_house_number(presents, Self::count_presents_finite, &budget::current())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::budget::{Budget, Exceeded, Limit, Limits};
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "831600");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "831600");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "884520");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "884520");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
@@ -124,9 +178,28 @@ mod tests {
 
     #[test]
     fn test_find_house_number() {
-        assert_eq!(Solution::find_house_number_infinite(120), 6);
-        assert_eq!(Solution::find_house_number_infinite(80), 6);
-        assert_eq!(Solution::find_house_number_infinite(150), 8);
-        assert_eq!(Solution::find_house_number_infinite(130), 8);
+        assert_eq!(Solution::find_house_number_infinite(120), Ok(6));
+        assert_eq!(Solution::find_house_number_infinite(80), Ok(6));
+        assert_eq!(Solution::find_house_number_infinite(150), Ok(8));
+        assert_eq!(Solution::find_house_number_infinite(130), Ok(8));
+    }
+
+    #[test]
+    fn test_find_house_number_budget_exceeded() {
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(5),
+        });
+
+        assert_eq!(
+            Solution::find_house_number(120, Solution::count_presents_infinite, &budget),
+            Err(PuzzleError::BudgetExceeded {
+                exceeded: Exceeded {
+                    limit: Limit::Steps(5),
+                    steps: 5
+                },
+                house: 6
+            })
+        );
     }
 }
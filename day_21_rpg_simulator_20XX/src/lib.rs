@@ -0,0 +1,11 @@
+// Day 21: RPG Simulator 20XX. Player buys equipment in the shop and fights the boss.
+#![allow(non_snake_case)]
+
+pub mod puzzle;
+
+pub use puzzle::character::Character;
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::item::Item;
+pub use puzzle::shop::Shop;
+pub use puzzle::solution::{PLAYER_HIT_POINT, Solution};
@@ -0,0 +1,69 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::parser::SyntaxError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    GroupCount {
+        found: usize,
+    },
+    StatCount {
+        found: usize,
+    },
+    InvalidStat {
+        line: usize,
+        text: String,
+    },
+    InvalidHeader {
+        line: usize,
+        category: &'static str,
+        text: String,
+    },
+    Syntax(SyntaxError),
+    InvalidNumber {
+        line: usize,
+        field: &'static str,
+        text: String,
+    },
+    NoWinningEquipment,
+    NoLosingEquipment,
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::GroupCount { found } => {
+                write!(f, "Exactly four groups are expected, found '{found}'")
+            }
+            PuzzleError::StatCount { found } => {
+                write!(f, "Expected 3 lines of boss stats, found '{found}'")
+            }
+            PuzzleError::InvalidStat { line, text } => {
+                write!(f, "Line {line}: invalid boss stat '{text}'")
+            }
+            PuzzleError::InvalidHeader {
+                line,
+                category,
+                text,
+            } => write!(
+                f,
+                "Line {line}: expected '{category}' header, found '{text}'"
+            ),
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::InvalidNumber { line, field, text } => {
+                write!(f, "Line {line}: failed to parse '{field}' from '{text}'")
+            }
+            PuzzleError::NoWinningEquipment => write!(f, "No equipment wins the fight"),
+            PuzzleError::NoLosingEquipment => write!(f, "No equipment loses the fight"),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
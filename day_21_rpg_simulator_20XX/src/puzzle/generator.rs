@@ -0,0 +1,82 @@
+use common::random::{self, Rng};
+
+// Boss with stats close to the puzzle input and a shop, size is the number of items of every
+// category
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    let mut lines = vec![
+        format!("Hit Points: {}", rng.range(90..=120)),
+        format!("Damage: {}", rng.range(7..=10)),
+        format!("Armor: {}", rng.range(1..=3)),
+        String::new(),
+        "Weapons:    Cost  Damage  Armor".to_string(),
+    ];
+
+    for index in 0..size {
+        let damage = rng.range(3..=10);
+        lines.push(item(
+            &random::name(index),
+            damage * 10 + rng.range(0..=10),
+            damage,
+            0,
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("Armor:      Cost  Damage  Armor".to_string());
+
+    for index in 0..size {
+        let armor = rng.range(1..=6);
+        lines.push(item(
+            &random::name(size + index),
+            armor * 20 + rng.range(0..=10),
+            0,
+            armor,
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("Rings:      Cost  Damage  Armor".to_string());
+
+    for _ in 0..size {
+        let bonus = rng.range(1..=3);
+        let cost = bonus * 25 + rng.range(0..=50);
+
+        lines.push(match rng.chance(1, 2) {
+            true => item(&format!("Damage +{bonus}"), cost, bonus, 0),
+            false => item(&format!("Defense +{bonus}"), cost, 0, bonus),
+        });
+    }
+
+    lines
+}
+
+fn item(name: &str, cost: usize, damage: usize, armor: usize) -> String {
+    format!("{name:<11}{cost:>4}{damage:>6}{armor:>8}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 5);
+        assert_eq!(lines, generate(7, 5));
+        assert_ne!(lines, generate(8, 5));
+
+        let (boss, shop) = Solution::parse_input(lines).unwrap();
+        assert_eq!(shop.weapons.len(), 5);
+        assert_eq!(shop.armors.len(), 5);
+        assert_eq!(shop.rings.len(), 5);
+
+        // Player shall be able to buy a weapon at least
+        let solution = Solution::from_boss_and_shop(boss, shop);
+        assert!(
+            solution.fight_win_with_minimum_gold().is_some()
+                || solution.fight_loose_with_maximum_gold().is_some()
+        );
+    }
+}
@@ -1,6 +1,6 @@
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Item {
-    pub _name: String,
+    pub name: String,
     pub cost: usize,
     pub damage: usize,
     pub armor: usize,
@@ -1,5 +1,7 @@
 pub mod character;
 pub mod equipment;
+pub mod error;
+pub mod generator;
 pub mod item;
 pub mod shop;
 pub mod solution;
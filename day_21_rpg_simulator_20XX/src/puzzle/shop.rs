@@ -1,5 +1,6 @@
 use crate::puzzle::item::Item;
 
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Shop {
     pub weapons: Vec<Item>,
     pub armors: Vec<Item>,
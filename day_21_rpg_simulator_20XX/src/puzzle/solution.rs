@@ -2,17 +2,20 @@ use std::error::Error;
 use std::ops::Range;
 use std::path::PathBuf;
 
+use common::explain::{Explain, Witness};
+use common::{input, parser};
 use itertools::Itertools;
-use puzzler::env::project;
 use puzzler::parsers::parser::Parser;
 use puzzler::puzzler::puzzle::Puzzle;
 
 use crate::puzzle::character::Character;
 use crate::puzzle::equipment::Equipment;
+use crate::puzzle::error::PuzzleError;
 use crate::puzzle::item::Item;
 use crate::puzzle::shop::Shop;
 
-const PLAYER_HIT_POINT: usize = 100;
+// Hit points of the player at the start of every fight
+pub const PLAYER_HIT_POINT: usize = 100;
 
 pub struct Solution {
     shop: Shop,
@@ -25,160 +28,165 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<PathBuf> {
-        Some(
-            project::get_project_file("../input/day_21.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_21.txt [{err}]")),
-        )
+        Some(input::file_path(21))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        // Parse Boss stats form the file
-        if lines.len() != 3 {
-            return Err(format!("Expected 3 lines, found '{}'", lines.len()).into());
-        }
-
-        let hit_points = Parser::decode_line_to_unsigned_integer(&lines[0], "Hit Points:")?;
-        let damage = Parser::decode_line_to_unsigned_integer(&lines[1], "Damage:")?;
-        let armor = Parser::decode_line_to_unsigned_integer(&lines[2], "Armor:")?;
-
-        self.boss = Character {
-            hit_points,
-            damage,
-            armor,
-        };
-
+        (self.boss, self.shop) = Self::parse_input(lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let min_gold = self.fight_win_with_minimum_gold();
+        let min_gold = self
+            .fight_win_with_minimum_gold()
+            .ok_or(PuzzleError::NoWinningEquipment)?;
         Ok(min_gold.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let max_gold = self.fight_loose_with_maximum_gold();
+        let max_gold = self
+            .fight_loose_with_maximum_gold()
+            .ok_or(PuzzleError::NoLosingEquipment)?;
         Ok(max_gold.to_string())
     }
 }
 
-impl Solution {
-    pub fn new() -> Self {
-        let weapons = vec![
-            Item {
-                _name: String::from("Dagger"),
-                cost: 8,
-                damage: 4,
-                armor: 0,
-            },
-            Item {
-                _name: String::from("Shortsword"),
-                cost: 10,
-                damage: 5,
-                armor: 0,
-            },
-            Item {
-                _name: String::from("Warhammer"),
-                cost: 25,
-                damage: 6,
-                armor: 0,
-            },
-            Item {
-                _name: String::from("Longsword"),
-                cost: 40,
-                damage: 7,
-                armor: 0,
-            },
-            Item {
-                _name: String::from("Greataxe"),
-                cost: 74,
-                damage: 8,
-                armor: 0,
-            },
-        ];
+impl Explain for Solution {
+    // Cheapest winning loadout
+    fn explain_part1(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        let loadout = self
+            .find_loadout(true)
+            .ok_or(PuzzleError::NoWinningEquipment);
+        Some(loadout.map(loadout_witness).map_err(Into::into))
+    }
 
-        let armors = vec![
-            Item {
-                _name: String::from("Leather"),
-                cost: 13,
-                damage: 0,
-                armor: 1,
-            },
-            Item {
-                _name: String::from("Chainmail"),
-                cost: 31,
-                damage: 0,
-                armor: 2,
-            },
-            Item {
-                _name: String::from("Splintmail"),
-                cost: 53,
-                damage: 0,
-                armor: 3,
-            },
-            Item {
-                _name: String::from("Bandedmail"),
-                cost: 75,
-                damage: 0,
-                armor: 4,
-            },
-            Item {
-                _name: String::from("Platemail"),
-                cost: 102,
-                damage: 0,
-                armor: 5,
-            },
-        ];
+    // Most expensive losing loadout
+    fn explain_part2(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        let loadout = self
+            .find_loadout(false)
+            .ok_or(PuzzleError::NoLosingEquipment);
+        Some(loadout.map(loadout_witness).map_err(Into::into))
+    }
+}
 
-        let rings = vec![
-            Item {
-                _name: String::from("Damage +1"),
-                cost: 25,
-                damage: 1,
-                armor: 0,
-            },
-            Item {
-                _name: String::from("Damage +2"),
-                cost: 50,
-                damage: 2,
-                armor: 0,
-            },
-            Item {
-                _name: String::from("Damage +3"),
-                cost: 100,
-                damage: 3,
-                armor: 0,
-            },
-            Item {
-                _name: String::from("Defense +1"),
-                cost: 20,
-                damage: 0,
-                armor: 1,
-            },
-            Item {
-                _name: String::from("Defense +2"),
-                cost: 40,
-                damage: 0,
-                armor: 2,
-            },
-            Item {
-                _name: String::from("Defense +3"),
-                cost: 80,
-                damage: 0,
-                armor: 3,
-            },
-        ];
+fn loadout_witness((items, player, gold): (Vec<String>, Character, usize)) -> Witness {
+    Witness::new()
+        .with("items", items)
+        .with("damage", player.damage)
+        .with("armor", player.armor)
+        .with("gold", gold)
+}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
+impl Solution {
+    pub fn new() -> Self {
         Self {
-            shop: Shop {
-                weapons,
-                armors,
-                rings,
-            },
+            shop: Shop::default(),
             boss: Character::default(),
         }
     }
 
-    fn player_wins_fight(player: &Character, boss: &Character) -> bool {
+    pub fn from_boss_and_shop(boss: Character, shop: Shop) -> Self {
+        Self { shop, boss }
+    }
+
+    // Stats of the boss followed by weapons, armor and rings of the shop, all separated by
+    // an empty line
+    pub fn parse_input(lines: Vec<String>) -> Result<(Character, Shop), PuzzleError> {
+        // Split input file by empty line: boss, weapons, armors and rings
+        let mut groups = Parser::group_lines(lines);
+
+        if groups.len() != 4 {
+            return Err(PuzzleError::GroupCount {
+                found: groups.len(),
+            });
+        }
+
+        // Parse Boss stats form the file
+        let lines = groups.remove(0);
+        if lines.len() != 3 {
+            return Err(PuzzleError::StatCount { found: lines.len() });
+        }
+
+        let boss = Character {
+            hit_points: Self::parse_stat(1, &lines[0], "Hit Points:")?,
+            damage: Self::parse_stat(2, &lines[1], "Damage:")?,
+            armor: Self::parse_stat(3, &lines[2], "Armor:")?,
+        };
+
+        // Parse items available in the shop, groups are separated by an empty line
+        let mut offset = lines.len() + 1;
+        let mut next_items = |category| {
+            let lines = groups.remove(0);
+            let items = Self::parse_items(&lines, offset, category);
+            offset += lines.len() + 1;
+            items
+        };
+
+        let shop = Shop {
+            weapons: next_items("Weapons")?,
+            armors: next_items("Armor")?,
+            rings: next_items("Rings")?,
+        };
+
+        Ok((boss, shop))
+    }
+
+    fn parse_stat(line: usize, text: &str, prefix: &str) -> Result<usize, PuzzleError> {
+        text.strip_prefix(prefix)
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .ok_or_else(|| PuzzleError::InvalidStat {
+                line,
+                text: text.to_string(),
+            })
+    }
+
+    fn parse_items(
+        lines: &[String],
+        offset: usize,
+        category: &'static str,
+    ) -> Result<Vec<Item>, PuzzleError> {
+        // First line is a header, e.g. 'Weapons:    Cost  Damage  Armor'
+        let header = &lines[0];
+        if !header.starts_with(&format!("{category}:")) {
+            return Err(PuzzleError::InvalidHeader {
+                line: offset + 1,
+                category,
+                text: header.clone(),
+            });
+        }
+
+        parser::parse_lines_with_regex_offset(
+            &lines[1..],
+            offset + 1,
+            r"^(.+?)\s+(\d+)\s+(\d+)\s+(\d+)$",
+            |line, params| {
+                Ok(Item {
+                    name: params[0].clone(),
+                    cost: Self::parse_number(line, "cost", &params[1])?,
+                    damage: Self::parse_number(line, "damage", &params[2])?,
+                    armor: Self::parse_number(line, "armor", &params[3])?,
+                })
+            },
+        )
+    }
+
+    fn parse_number(line: usize, field: &'static str, text: &str) -> Result<usize, PuzzleError> {
+        text.parse::<usize>()
+            .map_err(|_| PuzzleError::InvalidNumber {
+                line,
+                field,
+                text: text.to_string(),
+            })
+    }
+
+    // Player attacks first, every attack deals at least one damage
+    pub fn player_wins_fight(player: &Character, boss: &Character) -> bool {
         // Repeat until someone is alive
         let mut player = player.clone();
         let mut boss = boss.clone();
@@ -204,12 +212,14 @@ impl Solution {
         player.is_alive()
     }
 
-    fn fight_win_with_minimum_gold(&self) -> usize {
+    // Least gold spent on equipment which still wins the fight, None if the boss can not be
+    // defeated
+    pub fn fight_win_with_minimum_gold(&self) -> Option<usize> {
         // Generate all equipments
         let equipment = Self::combine_shop(&self.shop);
 
         // Keep track of minimum gold spent
-        let mut min_gold = usize::MAX;
+        let mut min_gold = None;
 
         // Construct all possible equipments and find minimum gold to still win
         for weapon in &equipment.weapons {
@@ -218,7 +228,7 @@ impl Solution {
                     let (player, gold) = Self::create_player(PLAYER_HIT_POINT, weapon, armor, ring);
 
                     if Self::player_wins_fight(&player, &self.boss) {
-                        min_gold = std::cmp::min(min_gold, gold);
+                        min_gold = Some(min_gold.map_or(gold, |min| std::cmp::min(min, gold)));
                     }
                 }
             }
@@ -227,12 +237,13 @@ impl Solution {
         min_gold
     }
 
-    fn fight_loose_with_maximum_gold(&self) -> usize {
+    // Most gold spent on equipment which still loses the fight, None if the player always wins
+    pub fn fight_loose_with_maximum_gold(&self) -> Option<usize> {
         // Generate all equipments
         let equipment = Self::combine_shop(&self.shop);
 
         // Keep track of maximum gold spent
-        let mut max_gold = usize::MIN;
+        let mut max_gold = None;
 
         // Construct all possible equipments and find maximum gold to still loose
         for weapon in &equipment.weapons {
@@ -241,7 +252,7 @@ impl Solution {
                     let (player, gold) = Self::create_player(PLAYER_HIT_POINT, weapon, armor, ring);
 
                     if !Self::player_wins_fight(&player, &self.boss) {
-                        max_gold = std::cmp::max(max_gold, gold);
+                        max_gold = Some(max_gold.map_or(gold, |max| std::cmp::max(max, gold)));
                     }
                 }
             }
@@ -250,7 +261,45 @@ impl Solution {
         max_gold
     }
 
-    fn combine_shop(shop: &Shop) -> Equipment {
+    // Cheapest loadout which wins the fight or the most expensive one which loses it. Returns
+    // names of the bought items, stats of the equipped player and spent gold, the first loadout
+    // in the shop order wins ties.
+    pub fn find_loadout(&self, win: bool) -> Option<(Vec<String>, Character, usize)> {
+        let equipment = Self::combine_shop(&self.shop);
+
+        let mut best: Option<(Vec<String>, Character, usize)> = None;
+
+        for weapon in &equipment.weapons {
+            for armor in &equipment.armors {
+                for ring in &equipment.rings {
+                    let (player, gold) = Self::create_player(PLAYER_HIT_POINT, weapon, armor, ring);
+
+                    if Self::player_wins_fight(&player, &self.boss) != win {
+                        continue;
+                    }
+
+                    let better = best.as_ref().is_none_or(|(_, _, best)| match win {
+                        true => gold < *best,
+                        false => gold > *best,
+                    });
+
+                    if better {
+                        let items = weapon
+                            .iter()
+                            .chain(armor)
+                            .chain(ring)
+                            .map(|item| item.name.clone())
+                            .collect();
+                        best = Some((items, player, gold));
+                    }
+                }
+            }
+        }
+
+        best
+    }
+
+    fn combine_shop(shop: &Shop) -> Equipment<'_> {
         // 1 weapon
         let weapons = Self::combine_items(&shop.weapons, 1..2);
 
@@ -317,28 +366,51 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::explain::Explain;
+    use common::fixtures;
+    use common::parser::{InvalidLine, SyntaxError};
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::{character::Character, solution::Solution};
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{
+        character::Character, error::PuzzleError, item::Item, shop::Shop, solution::Solution,
+    };
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "121");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "121");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "201");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "201");
+    }
+
+    // Puzzle statement has only a fight of a weaker player
+    #[test]
+    fn test_examples() {
+        let lines = fixtures::read_example(env!("CARGO_MANIFEST_DIR"), "example_01.txt");
+        let (boss, shop) = Solution::parse_input(lines).unwrap();
+
+        let player = Character {
+            hit_points: 8,
+            damage: 5,
+            armor: 5,
+        };
+        assert!(Solution::player_wins_fight(&player, &boss));
+        assert_eq!(
+            (shop.weapons.len(), shop.armors.len(), shop.rings.len()),
+            (5, 5, 6)
+        );
     }
 
     #[test]
@@ -357,9 +429,44 @@ mod tests {
         assert!(Solution::player_wins_fight(&player, &boss));
     }
 
+    #[test]
+    fn test_find_loadout() {
+        let item = |name: &str, cost, damage, armor| Item {
+            name: name.to_string(),
+            cost,
+            damage,
+            armor,
+        };
+        let shop = Shop {
+            weapons: vec![item("Dagger", 8, 4, 0), item("Sword", 10, 5, 0)],
+            armors: vec![item("Leather", 13, 0, 1)],
+            rings: vec![item("Damage +1", 25, 1, 0)],
+        };
+        let boss = Character {
+            hit_points: 45,
+            damage: 10,
+            armor: 0,
+        };
+        let mut solution = Solution::from_boss_and_shop(boss, shop);
+
+        let (items, player, gold) = solution.find_loadout(true).unwrap();
+        assert_eq!(items, ["Sword"]);
+        assert_eq!((player.damage, player.armor, gold), (5, 0, 10));
+        assert_eq!(solution.fight_win_with_minimum_gold(), Some(10));
+
+        let witness = solution.explain_part2().unwrap().unwrap();
+        assert_eq!(
+            witness.to_string(),
+            "items: [Dagger]; damage: 4; armor: 0; gold: 8"
+        );
+        assert_eq!(solution.fight_loose_with_maximum_gold(), Some(8));
+    }
+
     #[test]
     fn test_combine_items() {
-        let solution = Solution::new();
+        let Some(solution) = get_puzzle() else {
+            return;
+        };
 
         assert_eq!(
             Solution::combine_items(&solution.shop.armors, 1..2).len(),
@@ -376,4 +483,38 @@ mod tests {
             22
         );
     }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let lines = [
+            "Hit Points: 100",
+            "Damage: 8",
+            "Armor: 2",
+            "",
+            "Weapons:    Cost  Damage  Armor",
+            "Dagger        8     4       0",
+            "",
+            "Armor:      Cost  Damage  Armor",
+            "Leather      13     0       x",
+            "",
+            "Rings:      Cost  Damage  Armor",
+            "Damage +1    25     1       0",
+        ];
+
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(lines.iter().map(|line| line.to_string()).collect())
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::Syntax(SyntaxError {
+                lines: vec![InvalidLine {
+                    line: 9,
+                    column: 30,
+                    text: String::from("Leather      13     0       x")
+                }]
+            }))
+        );
+    }
 }
@@ -0,0 +1,10 @@
+// Day 22: Wizard Simulator 20XX. Wizard fights the boss with spells and effects.
+#![allow(non_snake_case)]
+
+pub mod puzzle;
+
+pub use puzzle::boss::Boss;
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
+pub use puzzle::spell::SpellType;
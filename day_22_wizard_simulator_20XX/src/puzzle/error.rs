@@ -0,0 +1,38 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::budget::Exceeded;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    LineCount {
+        found: usize,
+    },
+    InvalidStat {
+        line: usize,
+        text: String,
+    },
+    NoWinningStrategy,
+    BudgetExceeded {
+        exceeded: Exceeded,
+        best: Option<usize>,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::LineCount { found } => write!(f, "Expected 2 lines, found '{found}'"),
+            PuzzleError::InvalidStat { line, text } => {
+                write!(f, "Line {line}: invalid boss stat '{text}'")
+            }
+            PuzzleError::NoWinningStrategy => write!(f, "Wizard can not win the game"),
+            PuzzleError::BudgetExceeded { exceeded, best } => match best {
+                Some(best) => write!(f, "{exceeded}, least mana found so far '{best}'"),
+                None => write!(f, "{exceeded}, no winning strategy found so far"),
+            },
+        }
+    }
+}
+
+impl Error for PuzzleError {}
@@ -0,0 +1,32 @@
+use common::random::Rng;
+
+// Boss with random stats, size is the upper bound of its hit points. Search of the solution
+// grows fast with hit points, the puzzle input has about fifty.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    vec![
+        format!("Hit Points: {}", rng.range(size.div_ceil(2)..=size)),
+        format!("Damage: {}", rng.range(4..=10)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 20);
+        assert_eq!(lines, generate(7, 20));
+        assert_ne!(lines, generate(8, 20));
+
+        let boss = Solution::parse_input(&lines).unwrap();
+        assert!(
+            Solution::from_boss(boss)
+                .find_minimum_mana_to_win(false)
+                .is_ok()
+        );
+    }
+}
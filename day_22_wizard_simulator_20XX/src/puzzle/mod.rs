@@ -1,5 +1,7 @@
 pub mod boss;
+pub mod error;
 pub mod game;
+pub mod generator;
 pub mod solution;
 pub mod spell;
 pub mod winner;
@@ -1,13 +1,15 @@
 use std::error::Error;
 use std::path::PathBuf;
 
-use puzzler::env::project;
-use puzzler::parsers::parser::Parser;
+use common::budget::{self, Budget};
+use common::explain::{Explain, Witness};
+use common::input;
 use puzzler::puzzler::puzzle::Puzzle;
 
 use crate::puzzle::boss::Boss;
+use crate::puzzle::error::PuzzleError;
 use crate::puzzle::game::Game;
-use crate::puzzle::spell::Spell;
+use crate::puzzle::spell::{Spell, SpellType};
 use crate::puzzle::winner::Winner;
 use crate::puzzle::wizard::Wizard;
 
@@ -21,36 +23,51 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<PathBuf> {
-        Some(
-            project::get_project_file("../input/day_22.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_22.txt [{err}]")),
-        )
+        Some(input::file_path(22))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        // Parse Boss stats form the file
-        if lines.len() != 2 {
-            return Err(format!("Expected 2 lines, found '{}'", lines.len()).into());
-        }
-
-        let hit_points = Parser::decode_line_to_unsigned_integer(&lines[0], "Hit Points:")?;
-        let damage = Parser::decode_line_to_unsigned_integer(&lines[1], "Damage:")?;
-
-        self.boss = Boss::new(hit_points, damage);
+        self.boss = Self::parse_input(&lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let min_mana = self.find_minimum_mana_to_win(false);
+        let min_mana = self.find_minimum_mana_to_win(false)?;
         Ok(min_mana.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let min_mana = self.find_minimum_mana_to_win(true);
+        let min_mana = self.find_minimum_mana_to_win(true)?;
         Ok(min_mana.to_string())
     }
 }
 
+impl Explain for Solution {
+    // Cheapest sequence of spells cast by the wizard
+    fn explain_part1(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        let spells = self.find_minimum_mana_spells(false);
+        Some(spells.map(spells_witness).map_err(Into::into))
+    }
+
+    // Cheapest sequence of spells cast by the wizard in hard mode
+    fn explain_part2(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        let spells = self.find_minimum_mana_spells(true);
+        Some(spells.map(spells_witness).map_err(Into::into))
+    }
+}
+
+fn spells_witness((spells, mana): (Vec<SpellType>, usize)) -> Witness {
+    let spells = spells.iter().map(SpellType::name).collect::<Vec<_>>();
+
+    Witness::new().with("spells", spells).with("mana", mana)
+}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -58,21 +75,74 @@ impl Solution {
         }
     }
 
-    fn find_minimum_mana_to_win(&self, hard_mode: bool) -> usize {
-        let wizard = Wizard::new(50, 500);
-        let game = Game::new(wizard, self.boss.clone(), hard_mode);
+    pub fn from_boss(boss: Boss) -> Self {
+        Self { boss }
+    }
+
+    // Stats of the boss, i.e. hit points and damage on separate lines
+    pub fn parse_input(lines: &[String]) -> Result<Boss, PuzzleError> {
+        // Parse Boss stats form the file
+        if lines.len() != 2 {
+            return Err(PuzzleError::LineCount { found: lines.len() });
+        }
+
+        let hit_points = Self::parse_stat(1, &lines[0], "Hit Points:")?;
+        let damage = Self::parse_stat(2, &lines[1], "Damage:")?;
+
+        Ok(Boss::new(hit_points, damage))
+    }
 
-        Self::bfs(&game)
+    fn parse_stat(line: usize, text: &str, prefix: &str) -> Result<usize, PuzzleError> {
+        text.strip_prefix(prefix)
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .ok_or_else(|| PuzzleError::InvalidStat {
+                line,
+                text: text.to_string(),
+            })
     }
 
-    fn bfs(game: &Game) -> usize {
+    // Least mana spent by the wizard to win the fight, the wizard loses a hit point at the
+    // start of every own turn in hard mode. Search is limited by the budget of the thread.
+    pub fn find_minimum_mana_to_win(&self, hard_mode: bool) -> Result<usize, PuzzleError> {
+        Self::bfs::<false>(&self.new_game(hard_mode), &budget::current())?
+            .map(|(_, mana)| mana)
+            .ok_or(PuzzleError::NoWinningStrategy)
+    }
+
+    // Spells cast by the wizard in the cheapest winning fight together with the spent mana
+    pub fn find_minimum_mana_spells(
+        &self,
+        hard_mode: bool,
+    ) -> Result<(Vec<SpellType>, usize), PuzzleError> {
+        Self::bfs::<true>(&self.new_game(hard_mode), &budget::current())?
+            .ok_or(PuzzleError::NoWinningStrategy)
+    }
+
+    fn new_game(&self, hard_mode: bool) -> Game {
+        let wizard = Wizard::new(50, 500);
+        Game::new(wizard, self.boss.clone(), hard_mode)
+    }
+
+    // Returns None if wizard can not win, spells of the cheapest fight are kept only with HISTORY
+    fn bfs<const HISTORY: bool>(
+        game: &Game,
+        budget: &Budget,
+    ) -> Result<Option<(Vec<SpellType>, usize)>, PuzzleError> {
+        let mut meter = budget.meter();
         let mut min_mana = usize::MAX;
-        // let mut min_history = Vec::new();
+        let mut min_history = Vec::new();
 
         let mut queue = Vec::new();
         queue.push(game.clone());
 
         while let Some(game) = queue.pop() {
+            meter
+                .step()
+                .map_err(|exceeded| PuzzleError::BudgetExceeded {
+                    exceeded,
+                    best: (min_mana != usize::MAX).then_some(min_mana),
+                })?;
+
             // Check for end of game
             if let Some(winner) = game.get_winner() {
                 if winner == &Winner::Wizard {
@@ -80,7 +150,10 @@ impl Solution {
 
                     if spent_mana < min_mana {
                         min_mana = spent_mana;
-                        // min_history = game.get_history().clone();
+
+                        if HISTORY {
+                            min_history = game.get_history().clone();
+                        }
                     }
                 }
                 continue;
@@ -102,34 +175,123 @@ impl Solution {
             }
         }
 
-        // println!("{min_history:?}");
-        min_mana
+        Ok((min_mana != usize::MAX).then_some((min_history, min_mana)))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::budget::{Budget, Exceeded, Limit, Limits};
+    use common::explain::Explain;
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{
+        boss::Boss, error::PuzzleError, game::Game, solution::Solution, spell::SpellType,
+        wizard::Wizard,
+    };
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     // #[test]
     // fn test_solve_part1() {
-    //     assert_eq!(get_puzzle().solve_part1().unwrap(), "953");
+    //     let Some(mut puzzle) = get_puzzle() else {
+    //         return;
+    //     };
+    //     assert_eq!(puzzle.solve_part1().unwrap(), "953");
     // }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "1289");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "1289");
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![
+                String::from("Hit Points: 58"),
+                String::from("Damage 9"),
+            ])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidStat {
+                line: 2,
+                text: String::from("Damage 9")
+            })
+        );
+    }
+
+    // Wizard of the puzzle statement has 10 hit points and 250 mana
+    #[test]
+    fn test_examples() {
+        let lines = fixtures::read_example(env!("CARGO_MANIFEST_DIR"), "example_01.txt");
+        let boss = Solution::parse_input(&lines).unwrap();
+        let game = Game::new(Wizard::new(10, 250), boss, false);
+
+        assert_eq!(
+            Solution::bfs::<true>(&game, &Budget::unlimited()),
+            Ok(Some((
+                vec![SpellType::Poison, SpellType::MagicMissile],
+                226
+            )))
+        );
+    }
+
+    #[test]
+    fn test_bfs() {
+        let game = Game::new(Wizard::new(10, 250), Boss::new(13, 8), false);
+        assert_eq!(
+            Solution::bfs::<true>(&game, &Budget::unlimited()),
+            Ok(Some((
+                vec![SpellType::Poison, SpellType::MagicMissile],
+                226
+            )))
+        );
+
+        // Spells are not tracked when only the mana is needed
+        assert_eq!(
+            Solution::bfs::<false>(&game, &Budget::unlimited()),
+            Ok(Some((Vec::new(), 226)))
+        );
+        assert_eq!(
+            Solution::from_boss(Boss::new(13, 8)).find_minimum_mana_to_win(false),
+            Ok(212)
+        );
+
+        let mut solution = Solution::from_boss(Boss::new(13, 8));
+        let witness = solution.explain_part1().unwrap().unwrap();
+        assert_eq!(
+            witness.to_string(),
+            "spells: [Magic Missile, Magic Missile, Magic Missile, Magic Missile]; mana: 212"
+        );
+    }
+
+    #[test]
+    fn test_bfs_budget_exceeded() {
+        let game = Game::new(Wizard::new(50, 500), Boss::new(58, 9), false);
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(3),
+        });
+
+        assert_eq!(
+            Solution::bfs::<false>(&game, &budget),
+            Err(PuzzleError::BudgetExceeded {
+                exceeded: Exceeded {
+                    limit: Limit::Steps(3),
+                    steps: 3
+                },
+                best: None
+            })
+        );
     }
 }
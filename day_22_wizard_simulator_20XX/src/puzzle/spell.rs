@@ -9,6 +9,18 @@ pub enum SpellType {
     Recharge,
 }
 
+impl SpellType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpellType::MagicMissile => "Magic Missile",
+            SpellType::Drain => "Drain",
+            SpellType::Shield => "Shield",
+            SpellType::Poison => "Poison",
+            SpellType::Recharge => "Recharge",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Spell {
     spell_type: SpellType,
@@ -0,0 +1,7 @@
+// Day 23: Opening the Turing Lock. Interpreter of programs of the computer with two registers.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::instruction::Instruction;
+pub use puzzle::solution::Solution;
@@ -0,0 +1,37 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::budget::Exceeded;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    InvalidInstruction { line: usize, text: String },
+    UnknownRegister { line: usize, text: String },
+    UnknownOutputRegister { register: String },
+    RegisterOverflow { line: usize, register: String },
+    BudgetExceeded { exceeded: Exceeded, output: usize },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::InvalidInstruction { line, text } => {
+                write!(f, "Line {line}: unsupported instruction '{text}'")
+            }
+            PuzzleError::UnknownRegister { line, text } => {
+                write!(f, "Line {line}: unsupported register '{text}'")
+            }
+            PuzzleError::UnknownOutputRegister { register } => {
+                write!(f, "Unsupported output register '{register}'")
+            }
+            PuzzleError::RegisterOverflow { line, register } => {
+                write!(f, "Line {line}: register '{register}' overflows")
+            }
+            PuzzleError::BudgetExceeded { exceeded, output } => {
+                write!(f, "{exceeded}, output register holds '{output}'")
+            }
+        }
+    }
+}
+
+impl Error for PuzzleError {}
@@ -0,0 +1,56 @@
+use common::random::Rng;
+
+// Register 'a' can be tripled this many times without overflow, even if incremented often
+const MAX_TRIPLES: usize = 25;
+
+// Longest jump of the program
+const MAX_OFFSET: usize = 10;
+
+// Program of size instructions. Jumps are forward only, therefore the program terminates.
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let mut triples = 0;
+
+    (0..size)
+        .map(|index| {
+            let register = *rng.choose(&["a", "b"]);
+
+            // Jump may leave the program which ends it
+            let offset = rng.range(1..=MAX_OFFSET.min(size - index));
+
+            match rng.range(0..=6) {
+                0 if triples < MAX_TRIPLES => {
+                    triples += 1;
+                    "tpl a".to_string()
+                }
+                0 | 1 => format!("inc {register}"),
+                2 => format!("hlf {register}"),
+                3 => format!("jmp +{offset}"),
+                4 => format!("jie {register}, +{offset}"),
+                5 => format!("jio {register}, +{offset}"),
+                _ => "inc a".to_string(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::budget::Budget;
+
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 200);
+        assert_eq!(lines, generate(7, 200));
+        assert_ne!(lines, generate(8, 200));
+
+        let program = Solution::parse_input(&lines).unwrap();
+        assert_eq!(program.len(), 200);
+
+        for register_a in [0, 1] {
+            assert!(Solution::run_program(&program, register_a, "b", &Budget::unlimited()).is_ok());
+        }
+    }
+}
@@ -1,37 +1,38 @@
-use std::ops::{Index, IndexMut};
-
 #[derive(Debug)]
 pub struct Registers {
     a: usize,
     b: usize,
 }
 
-impl Index<&str> for Registers {
-    type Output = usize;
-
-    fn index(&self, index: &str) -> &Self::Output {
-        match index {
-            "a" => &self.a,
-            "b" => &self.b,
-            r => panic!("Unsupported register '{r}'"),
-        }
-    }
-}
-
-impl IndexMut<&str> for Registers {
-    fn index_mut(&mut self, index: &str) -> &mut Self::Output {
-        match index {
-            "a" => &mut self.a,
-            "b" => &mut self.b,
-            r => panic!("Unsupported register '{r}'"),
-        }
+impl Default for Registers {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl Registers {
+    pub const NAMES: [&str; 2] = ["a", "b"];
+
     pub fn new() -> Self {
         Self { a: 0, b: 0 }
     }
+
+    // None for unsupported register
+    pub fn get(&self, name: &str) -> Option<&usize> {
+        match name {
+            "a" => Some(&self.a),
+            "b" => Some(&self.b),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut usize> {
+        match name {
+            "a" => Some(&mut self.a),
+            "b" => Some(&mut self.b),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -42,13 +43,15 @@ mod tests {
     fn test_registers() {
         let mut registers = Registers::new();
 
-        assert_eq!(registers["a"], 0);
-        assert_eq!(registers["b"], 0);
+        assert_eq!(registers.get("a"), Some(&0));
+        assert_eq!(registers.get("b"), Some(&0));
 
-        registers["a"] = 42;
-        registers["b"] = 24;
+        *registers.get_mut("a").unwrap() = 42;
+        *registers.get_mut("b").unwrap() = 24;
 
-        assert_eq!(registers["a"], 42);
-        assert_eq!(registers["b"], 24);
+        assert_eq!(registers.get("a"), Some(&42));
+        assert_eq!(registers.get("b"), Some(&24));
+        assert_eq!(registers.get("c"), None);
+        assert_eq!(registers.get_mut("c"), None);
     }
 }
@@ -1,9 +1,12 @@
 use std::error::Error;
 use std::path::PathBuf;
 
-use puzzler::env::project;
+use common::budget::{self, Budget};
+use common::explain::Explain;
+use common::input;
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
 use crate::puzzle::instruction::Instruction;
 use crate::puzzle::registers::Registers;
 
@@ -17,110 +20,33 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<PathBuf> {
-        Some(
-            project::get_project_file("../input/day_23.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_23.txt [{err}]")),
-        )
+        Some(input::file_path(23))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        let mut instructions = Vec::new();
-
-        for line in lines {
-            // Split line by whitespaces
-            let mut split = line.split_whitespace();
-
-            // First word is type of the instruction
-            let cmd = split
-                .next()
-                .ok_or_else(|| format!("Failed to extract type of instruction '{line}'"))?;
-
-            let instr = match cmd {
-                "hlf" | "tpl" | "inc" => {
-                    let register = split
-                        .next()
-                        .ok_or_else(|| format!("Failed to extract register '{line}'"))?
-                        .trim();
-
-                    match cmd {
-                        "hlf" => Instruction::Hlf {
-                            reg: String::from(register),
-                        },
-                        "tpl" => Instruction::Tpl {
-                            reg: String::from(register),
-                        },
-                        "inc" => Instruction::Inc {
-                            reg: String::from(register),
-                        },
-                        _ => {
-                            panic!("Unexpected command '{cmd}'")
-                        }
-                    }
-                }
-                "jmp" => {
-                    let offset = split
-                        .next()
-                        .ok_or_else(|| format!("Failed to extract offset '{line}'"))?
-                        .trim()
-                        .replace("+", "")
-                        .parse::<isize>()
-                        .map_err(|_| format!("Failed to extract offset '{line}'"))?;
-
-                    Instruction::Jmp { offset }
-                }
-                "jie" | "jio" => {
-                    let register = split
-                        .next()
-                        .ok_or_else(|| format!("Failed to extract register '{line}'"))?
-                        .trim();
-
-                    let register = register.replace(",", "");
-
-                    let offset = split
-                        .next()
-                        .ok_or_else(|| format!("Failed to extract offset '{line}'"))?
-                        .replace("+", "")
-                        .trim()
-                        .parse::<isize>()
-                        .map_err(|_| format!("Failed to extract offset '{line}'"))?;
-
-                    match cmd {
-                        "jie" => Instruction::Jie {
-                            reg: register,
-                            offset,
-                        },
-                        "jio" => Instruction::Jio {
-                            reg: register,
-                            offset,
-                        },
-                        _ => {
-                            panic!("Unexpected command '{cmd}'")
-                        }
-                    }
-                }
-                _ => {
-                    return Err(format!("Unsupported instruction '{cmd}' [{line}]").into());
-                }
-            };
-
-            instructions.push(instr);
-        }
-
-        self.program = instructions;
+        self.program = Self::parse_input(&lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let register_b = Self::run_program(&self.program, 0, "b");
+        let register_b = Self::run_program(&self.program, 0, "b", &budget::current())?;
         Ok(register_b.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let register_b = Self::run_program(&self.program, 1, "b");
+        let register_b = Self::run_program(&self.program, 1, "b", &budget::current())?;
         Ok(register_b.to_string())
     }
 }
 
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -128,88 +54,220 @@ impl Solution {
         }
     }
 
-    fn run_program(program: &[Instruction], register_a: usize, register_output: &str) -> usize {
-        // Create registers
+    // Every line is a single instruction, e.g. 'jio a, +2'
+    pub fn parse_input(lines: &[String]) -> Result<Vec<Instruction>, PuzzleError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| Self::parse_instruction(index + 1, line))
+            .collect()
+    }
+
+    fn parse_instruction(line: usize, text: &str) -> Result<Instruction, PuzzleError> {
+        let invalid = || PuzzleError::InvalidInstruction {
+            line,
+            text: text.to_string(),
+        };
+
+        // Split line by whitespaces, register of conditional jumps is followed by a comma
+        let words = text
+            .split_whitespace()
+            .map(|word| word.trim_end_matches(','))
+            .collect::<Vec<_>>();
+
+        let register = |name: &str| {
+            if Registers::NAMES.contains(&name) {
+                Ok(name.to_string())
+            } else {
+                Err(PuzzleError::UnknownRegister {
+                    line,
+                    text: name.to_string(),
+                })
+            }
+        };
+
+        let offset = |value: &str| {
+            value
+                .trim_start_matches('+')
+                .parse::<isize>()
+                .map_err(|_| invalid())
+        };
+
+        let instr = match words.as_slice() {
+            ["hlf", reg] => Instruction::Hlf {
+                reg: register(reg)?,
+            },
+            ["tpl", reg] => Instruction::Tpl {
+                reg: register(reg)?,
+            },
+            ["inc", reg] => Instruction::Inc {
+                reg: register(reg)?,
+            },
+            ["jmp", value] => Instruction::Jmp {
+                offset: offset(value)?,
+            },
+            ["jie", reg, value] => Instruction::Jie {
+                reg: register(reg)?,
+                offset: offset(value)?,
+            },
+            ["jio", reg, value] => Instruction::Jio {
+                reg: register(reg)?,
+                offset: offset(value)?,
+            },
+            _ => return Err(invalid()),
+        };
+
+        Ok(instr)
+    }
+
+    // Value of the output register once the program ends, register a starts with the given
+    // value. Every executed instruction is a step of the budget.
+    pub fn run_program(
+        program: &[Instruction],
+        register_a: usize,
+        register_output: &str,
+        budget: &Budget,
+    ) -> Result<usize, PuzzleError> {
+        // Looping program never ends, every executed instruction counts as a step
+        let mut meter = budget.meter();
+
+        // Create registers, unknown output register is reported before running anything
         let mut registers = Registers::new();
-        registers["a"] = register_a;
+        *Self::register(&mut registers, "a", 0)? = register_a;
+        Self::output(&registers, register_output)?;
 
         // Start at the beginning of the program
         let mut stack_index = 0;
         let program_length = program.len();
 
-        loop {
-            let inst = &program[stack_index];
+        while let Some(inst) = program.get(stack_index) {
+            if let Err(exceeded) = meter.step() {
+                return Err(PuzzleError::BudgetExceeded {
+                    exceeded,
+                    output: Self::output(&registers, register_output)?,
+                });
+            }
+
+            let line = stack_index + 1;
 
-            let new_offest = match inst {
+            let new_index = match inst {
                 Instruction::Hlf { reg } => {
                     // Half the value
-                    registers[reg] /= 2;
-                    (stack_index + 1) as isize
+                    *Self::register(&mut registers, reg, line)? /= 2;
+                    Some(stack_index + 1)
                 }
                 Instruction::Tpl { reg } => {
                     // Triple the value
-                    registers[reg] *= 3;
-                    (stack_index + 1) as isize
+                    let value = Self::register(&mut registers, reg, line)?;
+                    *value = value
+                        .checked_mul(3)
+                        .ok_or_else(|| Self::overflow(reg, line))?;
+                    Some(stack_index + 1)
                 }
                 Instruction::Inc { reg } => {
                     // Increment by 1
-                    registers[reg] += 1;
-                    (stack_index + 1) as isize
+                    let value = Self::register(&mut registers, reg, line)?;
+                    *value = value
+                        .checked_add(1)
+                        .ok_or_else(|| Self::overflow(reg, line))?;
+                    Some(stack_index + 1)
                 }
-                Instruction::Jmp { offset } => stack_index as isize + offset,
+                Instruction::Jmp { offset } => stack_index.checked_add_signed(*offset),
                 Instruction::Jie { reg, offset } => {
                     // Jump if even
-                    match registers[reg] % 2 == 0 {
-                        true => stack_index as isize + offset,
-                        false => (stack_index + 1) as isize,
+                    match Self::register(&mut registers, reg, line)?.is_multiple_of(2) {
+                        true => stack_index.checked_add_signed(*offset),
+                        false => Some(stack_index + 1),
                     }
                 }
                 Instruction::Jio { reg, offset } => {
                     // Jump if one
-                    match registers[reg] == 1 {
-                        true => stack_index as isize + offset,
-                        false => (stack_index + 1) as isize,
+                    match *Self::register(&mut registers, reg, line)? == 1 {
+                        true => stack_index.checked_add_signed(*offset),
+                        false => Some(stack_index + 1),
                     }
                 }
             };
 
-            // Program ends when new offset is out of program bounds
-            if new_offest < 0 || new_offest >= program_length as isize {
-                break;
+            // Program ends when new index is out of program bounds, jumps beyond any index too
+            match new_index {
+                Some(index) if index < program_length => stack_index = index,
+                _ => break,
             }
-
-            // Assign index to the next instruction
-            stack_index = new_offest as usize;
         }
 
-        registers[register_output]
+        Self::output(&registers, register_output)
+    }
+
+    // Output register is not a part of the program, it is reported by its own error
+    fn output(registers: &Registers, reg: &str) -> Result<usize, PuzzleError> {
+        registers
+            .get(reg)
+            .copied()
+            .ok_or_else(|| PuzzleError::UnknownOutputRegister {
+                register: reg.to_string(),
+            })
+    }
+
+    fn register<'a>(
+        registers: &'a mut Registers,
+        reg: &str,
+        line: usize,
+    ) -> Result<&'a mut usize, PuzzleError> {
+        registers
+            .get_mut(reg)
+            .ok_or_else(|| PuzzleError::UnknownRegister {
+                line,
+                text: reg.to_string(),
+            })
+    }
+
+    fn overflow(reg: &str, line: usize) -> PuzzleError {
+        PuzzleError::RegisterOverflow {
+            line,
+            register: reg.to_string(),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::budget::{Budget, Exceeded, Limit, Limits};
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::{instruction::Instruction, solution::Solution};
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{error::PuzzleError, instruction::Instruction, solution::Solution};
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "170");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "170");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "247");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "247");
+    }
+
+    // Program of the puzzle statement leaves the result in register 'a'
+    #[test]
+    fn test_examples() {
+        let lines = fixtures::read_example(env!("CARGO_MANIFEST_DIR"), "example_01.txt");
+        let program = Solution::parse_input(&lines).unwrap();
+
+        assert_eq!(
+            Solution::run_program(&program, 0, "a", &Budget::unlimited()),
+            Ok(2)
+        );
     }
 
     #[test]
@@ -230,6 +288,127 @@ mod tests {
             },
         ];
 
-        assert_eq!(Solution::run_program(&program, 0, "a"), 2);
+        assert_eq!(
+            Solution::run_program(&program, 0, "a", &Budget::unlimited()),
+            Ok(2)
+        );
+        assert_eq!(
+            Solution::run_program(&[], 7, "a", &Budget::unlimited()),
+            Ok(7)
+        );
+    }
+
+    #[test]
+    fn test_run_program_unknown_register() {
+        let program = vec![Instruction::Inc {
+            reg: String::from("c"),
+        }];
+
+        assert_eq!(
+            Solution::run_program(&program, 0, "a", &Budget::unlimited()),
+            Err(PuzzleError::UnknownRegister {
+                line: 1,
+                text: String::from("c")
+            })
+        );
+    }
+
+    #[test]
+    fn test_run_program_unknown_output() {
+        let program = vec![Instruction::Inc {
+            reg: String::from("a"),
+        }];
+
+        assert_eq!(
+            Solution::run_program(&program, 0, "c", &Budget::unlimited()),
+            Err(PuzzleError::UnknownOutputRegister {
+                register: String::from("c")
+            })
+        );
+    }
+
+    #[test]
+    fn test_run_program_jump_overflow() {
+        // Jumps beyond any index leave the program
+        let program = vec![
+            Instruction::Inc {
+                reg: String::from("a"),
+            },
+            Instruction::Jmp { offset: isize::MAX },
+            Instruction::Inc {
+                reg: String::from("a"),
+            },
+        ];
+
+        assert_eq!(
+            Solution::run_program(&program, 0, "a", &Budget::unlimited()),
+            Ok(1)
+        );
+
+        let mut solution = Solution::new();
+        solution
+            .parse_content(vec![
+                String::from("inc a"),
+                String::from("jmp +9223372036854775807"),
+                String::from("inc a"),
+            ])
+            .unwrap();
+        assert_eq!(
+            Solution::run_program(&solution.program, 0, "a", &Budget::unlimited()),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn test_run_program_budget_exceeded() {
+        // Endless loop incrementing register a
+        let program = vec![
+            Instruction::Inc {
+                reg: String::from("a"),
+            },
+            Instruction::Jmp { offset: -1 },
+        ];
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(10),
+        });
+
+        assert_eq!(
+            Solution::run_program(&program, 0, "a", &budget),
+            Err(PuzzleError::BudgetExceeded {
+                exceeded: Exceeded {
+                    limit: Limit::Steps(10),
+                    steps: 10
+                },
+                output: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+
+        let err = solution
+            .parse_content(vec![String::from("inc a"), String::from("jie c, +2")])
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::UnknownRegister {
+                line: 2,
+                text: String::from("c")
+            })
+        );
+
+        let err = solution
+            .parse_content(vec![String::from("jmp x")])
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidInstruction {
+                line: 1,
+                text: String::from("jmp x")
+            })
+        );
     }
 }
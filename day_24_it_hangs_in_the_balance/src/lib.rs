@@ -0,0 +1,6 @@
+// Day 24: It Hangs in the Balance. Balanced groups of packages of Santa's sleigh.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
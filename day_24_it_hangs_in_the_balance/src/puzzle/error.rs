@@ -0,0 +1,57 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::budget::Exceeded;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    InvalidNumber {
+        line: usize,
+        text: String,
+    },
+    UnevenWeight {
+        total: usize,
+        groups: usize,
+    },
+    NoBalancedGroup {
+        groups: usize,
+    },
+    BudgetExceeded {
+        exceeded: Exceeded,
+        packages: usize,
+        best: Option<(Vec<usize>, usize)>,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::InvalidNumber { line, text } => {
+                write!(f, "Line {line}: failed to parse package weight '{text}'")
+            }
+            PuzzleError::UnevenWeight { total, groups } => write!(
+                f,
+                "Total weight '{total}' can not be split into {groups} equal groups"
+            ),
+            PuzzleError::NoBalancedGroup { groups } => {
+                write!(f, "Packages can not be balanced into {groups} groups")
+            }
+            PuzzleError::BudgetExceeded {
+                exceeded,
+                packages,
+                best,
+            } => match best {
+                Some((group, entanglement)) => write!(
+                    f,
+                    "{exceeded}, searching groups of {packages} package(s), best group found so far {group:?} with entanglement '{entanglement}'"
+                ),
+                None => write!(
+                    f,
+                    "{exceeded}, searching groups of {packages} package(s), no group found so far"
+                ),
+            },
+        }
+    }
+}
+
+impl Error for PuzzleError {}
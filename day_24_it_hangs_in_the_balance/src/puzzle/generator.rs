@@ -0,0 +1,53 @@
+use common::random::Rng;
+
+// Packages are split into this many blocks of the same weight, therefore they can be
+// balanced in three as well as in four groups
+const BLOCKS: usize = 12;
+
+// Upper bound of a random package weight
+const MAX_WEIGHT: usize = 20;
+
+// Package weights, size is the number of packages
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+
+    // Every block has at least one package
+    let mut counts = [1; BLOCKS];
+    for _ in BLOCKS..size {
+        counts[rng.range(0..=BLOCKS - 1)] += 1;
+    }
+
+    // Last package of every block completes the weight, it is always the heaviest one
+    let max_count = counts.iter().max().unwrap();
+    let weight = max_count * MAX_WEIGHT + rng.range(1..=MAX_WEIGHT);
+
+    let mut packages = Vec::new();
+    for count in counts {
+        let block = (1..count)
+            .map(|_| rng.range(1..=MAX_WEIGHT))
+            .collect::<Vec<_>>();
+
+        packages.push(weight - block.iter().sum::<usize>());
+        packages.extend(block);
+    }
+
+    rng.shuffle(&mut packages);
+    packages.iter().map(|package| package.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 20);
+        assert_eq!(lines, generate(7, 20));
+        assert_ne!(lines, generate(8, 20));
+
+        let packages = Solution::parse_input(&lines).unwrap();
+        assert_eq!(packages.len(), 20);
+        assert!(Solution::find_min_quantum_entaglement(&packages, 3).is_ok());
+        assert!(Solution::find_min_quantum_entaglement(&packages, 4).is_ok());
+    }
+}
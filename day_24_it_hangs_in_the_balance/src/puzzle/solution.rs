@@ -1,11 +1,14 @@
 use std::error::Error;
 use std::path::PathBuf;
 
+use common::budget::{self, Budget};
+use common::explain::{Explain, Witness};
+use common::input;
 use itertools::Itertools;
-use puzzler::env::project;
-use puzzler::parsers::parser::Parser;
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
+
 pub struct Solution {
     packages: Vec<usize>,
 }
@@ -16,28 +19,53 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<PathBuf> {
-        Some(
-            project::get_project_file("../input/day_24.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_24.txt [{err}]")),
-        )
+        Some(input::file_path(24))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        self.packages = Parser::parse_lines_to_unsigned_integer(lines)?;
+        self.packages = Self::parse_input(&lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let min_entaglement = Solution::find_min_quantum_entaglement(&self.packages, 3);
+        let min_entaglement = Solution::find_min_quantum_entaglement(&self.packages, 3)?;
         Ok(min_entaglement.to_string())
     }
 
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        let min_entaglement = Solution::find_min_quantum_entaglement(&self.packages, 4);
+        let min_entaglement = Solution::find_min_quantum_entaglement(&self.packages, 4)?;
         Ok(min_entaglement.to_string())
     }
 }
 
+impl Explain for Solution {
+    // First group of packages when split into three groups
+    fn explain_part1(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        let group = Solution::find_first_group(&self.packages, 3);
+        Some(group.map(group_witness).map_err(Into::into))
+    }
+
+    // First group of packages when split into four groups
+    fn explain_part2(&mut self) -> Option<Result<Witness, Box<dyn Error>>> {
+        let group = Solution::find_first_group(&self.packages, 4);
+        Some(group.map(group_witness).map_err(Into::into))
+    }
+}
+
+fn group_witness(group: Vec<usize>) -> Witness {
+    let entanglement = group.iter().product::<usize>();
+
+    Witness::new()
+        .with("group", group)
+        .with("entanglement", entanglement)
+}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         Self {
@@ -45,84 +73,142 @@ impl Solution {
         }
     }
 
-    fn find_min_quantum_entaglement(packages: &[usize], nr_of_groups: usize) -> usize {
+    // Every line is a weight of a single package
+    pub fn parse_input(lines: &[String]) -> Result<Vec<usize>, PuzzleError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, text)| {
+                text.trim()
+                    .parse::<usize>()
+                    .map_err(|_| PuzzleError::InvalidNumber {
+                        line: index + 1,
+                        text: text.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    // Quantum entanglement of the first group with the fewest packages when packages are split
+    // into groups of the same weight
+    pub fn find_min_quantum_entaglement(
+        packages: &[usize],
+        nr_of_groups: usize,
+    ) -> Result<usize, PuzzleError> {
+        Self::find_first_group(packages, nr_of_groups).map(|group| group.iter().product())
+    }
+
+    // Packages of the first group with the fewest packages and the least quantum entanglement,
+    // packages are in increasing order of weight. Search is limited by the budget of the thread.
+    pub fn find_first_group(
+        packages: &[usize],
+        nr_of_groups: usize,
+    ) -> Result<Vec<usize>, PuzzleError> {
+        Self::search_first_group(packages, nr_of_groups, &budget::current())
+    }
+
+    // Every combination of packages is a single step of the budget
+    fn search_first_group(
+        packages: &[usize],
+        nr_of_groups: usize,
+        budget: &Budget,
+    ) -> Result<Vec<usize>, PuzzleError> {
         // Sort in decreasing order to find solution faster
         let mut packages = packages.to_vec();
         packages.sort();
 
         // Find target sum
-        let target = packages.iter().sum::<usize>() / nr_of_groups;
+        let total = packages.iter().sum::<usize>();
+        if !total.is_multiple_of(nr_of_groups) {
+            return Err(PuzzleError::UnevenWeight {
+                total,
+                groups: nr_of_groups,
+            });
+        }
+        let target = total / nr_of_groups;
 
-        let mut valid_groups = Vec::new();
-        let mut min_length = usize::MAX;
+        // Group with the least entanglement and its entanglement, groups are searched by size
+        let mut best: Option<(Vec<usize>, usize)> = None;
+        let mut meter = budget.meter();
 
-        // Collect all valid combinations
         for i in 1..packages.len() + 1 {
-            // If number of combinations is greater than found min value, we can skip this iteration
-            if min_length < i {
-                continue;
+            // Groups of the fewest packages are already found, larger groups are not needed
+            if best.is_some() {
+                break;
             }
 
-            // Find all groups with given target sum & with less than minimum length
-            let mut groups = packages
-                .iter()
-                .combinations(i)
-                .map(|comb| comb.into_iter().copied().collect::<Vec<_>>())
-                .filter(|group| group.iter().sum::<usize>() == target)
-                .filter(|group| group.len() <= min_length)
-                .map(|group| group.to_vec())
-                .collect::<Vec<_>>();
-
-            // Update minimal length
-            if let Some(current_min_length) = groups.iter().map(|group| group.len()).min() {
-                min_length = std::cmp::min(min_length, current_min_length);
-            }
+            for comb in packages.iter().combinations(i) {
+                if let Err(exceeded) = meter.step() {
+                    return Err(PuzzleError::BudgetExceeded {
+                        exceeded,
+                        packages: i,
+                        best,
+                    });
+                }
+
+                let group = comb.into_iter().copied().collect::<Vec<_>>();
+
+                if group.iter().sum::<usize>() != target {
+                    continue;
+                }
 
-            // Append found groups
-            valid_groups.append(&mut groups);
+                // Keep the group with minimum entanglement
+                let entanglement = group.iter().product::<usize>();
+                if best
+                    .as_ref()
+                    .is_none_or(|(_, min_entanglement)| entanglement < *min_entanglement)
+                {
+                    best = Some((group, entanglement));
+                }
+            }
         }
 
-        // Find minimum entaglement
-        valid_groups
-            .iter()
-            .filter(|group| group.len() == min_length)
-            .map(|group| group.iter().product::<usize>())
-            .min()
-            .unwrap()
+        best.map(|(group, _)| group)
+            .ok_or(PuzzleError::NoBalancedGroup {
+                groups: nr_of_groups,
+            })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::budget::{Budget, Exceeded, Limit, Limits};
+    use common::explain::Explain;
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
-
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "11266889531");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "11266889531");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "77387711");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "77387711");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
     fn test_find_min_quantum_entaglement_3_groups() {
         assert_eq!(
             Solution::find_min_quantum_entaglement(&[1, 2, 3, 4, 5, 7, 8, 9, 10, 11], 3),
-            99
+            Ok(99)
         );
     }
 
@@ -130,7 +216,73 @@ mod tests {
     fn test_find_min_quantum_entaglement_4_groups() {
         assert_eq!(
             Solution::find_min_quantum_entaglement(&[1, 2, 3, 4, 5, 7, 8, 9, 10, 11], 4),
-            44
+            Ok(44)
+        );
+    }
+
+    #[test]
+    fn test_find_first_group() {
+        let packages = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+        assert_eq!(Solution::find_first_group(&packages, 3), Ok(vec![9, 11]));
+
+        let mut solution = Solution {
+            packages: packages.to_vec(),
+        };
+        let witness = solution.explain_part2().unwrap().unwrap();
+        assert_eq!(witness.to_string(), "group: [4, 11]; entanglement: 44");
+    }
+
+    #[test]
+    fn test_search_first_group_budget_exceeded() {
+        // 10 groups of a single package and 45 groups of two packages
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(20),
+        });
+
+        assert_eq!(
+            Solution::search_first_group(&[1, 2, 3, 4, 5, 7, 8, 9, 10, 11], 3, &budget),
+            Err(PuzzleError::BudgetExceeded {
+                exceeded: Exceeded {
+                    limit: Limit::Steps(20),
+                    steps: 20
+                },
+                packages: 2,
+                best: None
+            })
+        );
+
+        // Only group of two packages is 9 and 11, found before the last combination
+        let budget = Budget::new(Limits {
+            timeout: None,
+            max_steps: Some(54),
+        });
+
+        assert_eq!(
+            Solution::search_first_group(&[1, 2, 3, 4, 5, 7, 8, 9, 10, 11], 3, &budget),
+            Err(PuzzleError::BudgetExceeded {
+                exceeded: Exceeded {
+                    limit: Limit::Steps(54),
+                    steps: 54
+                },
+                packages: 2,
+                best: Some((vec![9, 11], 99))
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_min_quantum_entaglement_invalid() {
+        assert_eq!(
+            Solution::find_min_quantum_entaglement(&[1, 2, 4], 3),
+            Err(PuzzleError::UnevenWeight {
+                total: 7,
+                groups: 3
+            })
+        );
+        assert_eq!(
+            Solution::find_min_quantum_entaglement(&[], 3),
+            Err(PuzzleError::NoBalancedGroup { groups: 3 })
         );
     }
 }
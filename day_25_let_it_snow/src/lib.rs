@@ -0,0 +1,6 @@
+// Day 25: Let It Snow. Code of the weather machine from the manual.
+pub mod puzzle;
+
+pub use puzzle::error::PuzzleError;
+pub use puzzle::generator::generate;
+pub use puzzle::solution::Solution;
@@ -0,0 +1,49 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use common::parser::SyntaxError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    Syntax(SyntaxError),
+    InvalidPosition {
+        line: usize,
+        field: &'static str,
+        text: String,
+    },
+    LineCount {
+        found: usize,
+    },
+    PositionOutOfGrid {
+        row: usize,
+        col: usize,
+    },
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Syntax(err) => err.fmt(f),
+            PuzzleError::InvalidPosition { line, field, text } => write!(
+                f,
+                "Line {line}: '{field}' shall be a positive number, found '{text}'"
+            ),
+            PuzzleError::LineCount { found } => write!(
+                f,
+                "Expected exactly one parsed row/col in input file, found {found}"
+            ),
+            PuzzleError::PositionOutOfGrid { row, col } => write!(
+                f,
+                "Position row {row}, col {col} is out of the grid indexed from 1"
+            ),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<SyntaxError> for PuzzleError {
+    fn from(err: SyntaxError) -> Self {
+        PuzzleError::Syntax(err)
+    }
+}
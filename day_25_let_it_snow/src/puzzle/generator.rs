@@ -0,0 +1,28 @@
+use common::random::Rng;
+
+// Position of the code in the manual, size is the upper bound of the row and the column
+pub fn generate(seed: u64, size: usize) -> Vec<String> {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    vec![format!(
+        "To continue, please consult the code grid in the manual.  Enter the code at row {}, column {}.",
+        rng.range(1..=size),
+        rng.range(1..=size)
+    )]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{generator::generate, solution::Solution};
+
+    #[test]
+    fn test_generate() {
+        let lines = generate(7, 100);
+        assert_eq!(lines, generate(7, 100));
+        assert_ne!(lines, generate(8, 100));
+
+        let (row, col) = Solution::parse_input(&lines).unwrap();
+        assert!((1..=100).contains(&row) && (1..=100).contains(&col));
+    }
+}
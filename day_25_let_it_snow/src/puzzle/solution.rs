@@ -1,10 +1,13 @@
 use std::error::Error;
 use std::path::PathBuf;
 
-use puzzler::env::project;
-use puzzler::parsers::parser::Parser;
+use common::answer;
+use common::explain::Explain;
+use common::{input, parser};
 use puzzler::puzzler::puzzle::Puzzle;
 
+use crate::puzzle::error::PuzzleError;
+
 pub struct Solution {
     row: usize,
     col: usize,
@@ -16,55 +19,30 @@ impl Puzzle for Solution {
     }
 
     fn get_input_file_path(&self) -> Option<PathBuf> {
-        Some(
-            project::get_project_file("../input/day_25.txt")
-                .unwrap_or_else(|err| panic!("Failed to fetch file ../input/day_25.txt [{err}]")),
-        )
+        Some(input::file_path(25))
     }
 
     fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
-        let info = Parser::parse_lines_with_regex(
-            lines,
-            r"Enter the code at row (\d+), column (\d+).$",
-            |params| {
-                // Check params
-                if params.len() != 2 {
-                    return Err(format!("Expected 2 parameters, found {}", params.len()).into());
-                }
-
-                // Extract values
-                let rows = params[0]
-                    .parse::<usize>()
-                    .map_err(|_err| format!("Failed to parse 'row' to usize [{}]", params[0]))?;
-
-                let cols = params[1]
-                    .parse::<usize>()
-                    .map_err(|_err| format!("Failed to parse 'col' to usize [{}]", params[1]))?;
-
-                Ok((rows, cols))
-            },
-        )?;
-
-        if info.len() != 1 {
-            return Err(format!(
-                "Expected exactly one parsed row/col in input file, found {}",
-                info.len()
-            )
-            .into());
-        }
-
-        (self.row, self.col) = info[0];
-
+        (self.row, self.col) = Self::parse_input(&lines)?;
         Ok(())
     }
 
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
-        let code = Solution::generate_code(self.row, self.col);
+        let code = Solution::generate_code(self.row, self.col)?;
         Ok(code.to_string())
     }
 
+    // There is no puzzle for the last star, it is given for all the others
     fn solve_part2(&mut self) -> Result<String, Box<dyn Error>> {
-        Ok("Not solved".into())
+        Ok(answer::NOT_SOLVED.into())
+    }
+}
+
+impl Explain for Solution {}
+
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -73,12 +51,50 @@ impl Solution {
         Self { row: 0, col: 0 }
     }
 
-    fn generate_code(row: usize, col: usize) -> usize {
+    // Position of the code in the manual, returns (row, column)
+    pub fn parse_input(lines: &[String]) -> Result<(usize, usize), PuzzleError> {
+        let info = parser::parse_lines_with_regex(
+            lines,
+            r"Enter the code at row (\d+), column (\d+)\.$",
+            |line, params| -> Result<_, PuzzleError> {
+                let rows = Self::parse_position(line, "row", &params[0])?;
+                let cols = Self::parse_position(line, "col", &params[1])?;
+
+                Ok((rows, cols))
+            },
+        )?;
+
+        if info.len() != 1 {
+            return Err(PuzzleError::LineCount { found: info.len() });
+        }
+
+        Ok(info[0])
+    }
+
+    // Grid is indexed from 1, zero would never be reached by calculate_index()
+    fn parse_position(line: usize, field: &'static str, text: &str) -> Result<usize, PuzzleError> {
+        text.parse::<usize>()
+            .ok()
+            .filter(|&value| value > 0)
+            .ok_or_else(|| PuzzleError::InvalidPosition {
+                line,
+                field,
+                text: text.to_string(),
+            })
+    }
+
+    // Code of the weather machine at the given position of the manual, both indexed from 1
+    pub fn generate_code(row: usize, col: usize) -> Result<usize, PuzzleError> {
         // Code machine constants
         const INITIAL_CODE: usize = 20151125;
         const MULTIPLIER: usize = 252533;
         const DIVIDER: usize = 33554393;
 
+        // Zero would never be reached by calculate_index()
+        if row == 0 || col == 0 {
+            return Err(PuzzleError::PositionOutOfGrid { row, col });
+        }
+
         let index = Self::calculate_index(row, col);
 
         let mut code = INITIAL_CODE;
@@ -87,7 +103,7 @@ impl Solution {
             code = (code * MULTIPLIER) % DIVIDER;
         }
 
-        code
+        Ok(code)
     }
 
     fn calculate_index(target_row: usize, target_col: usize) -> usize {
@@ -112,41 +128,59 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
+    use common::fixtures;
     use puzzler::puzzler::puzzle::Puzzle;
 
-    use crate::puzzle::solution::Solution;
-
-    fn get_puzzle() -> Solution {
-        let mut solution = Solution::new();
-
-        solution
-            .parse_input_file()
-            .unwrap_or_else(|err| panic!("Failed to parse input file [{err}]"));
+    use crate::puzzle::{error::PuzzleError, solution::Solution};
 
-        solution
+    fn get_puzzle() -> Option<Solution> {
+        fixtures::parse_real_input(Solution::new())
     }
 
     #[test]
     fn test_solve_part1() {
-        assert_eq!(get_puzzle().solve_part1().unwrap(), "19980801");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part1().unwrap(), "19980801");
     }
 
     #[test]
     fn test_solve_part2() {
-        assert_eq!(get_puzzle().solve_part2().unwrap(), "Not solved");
+        let Some(mut puzzle) = get_puzzle() else {
+            return;
+        };
+        assert_eq!(puzzle.solve_part2().unwrap(), "Not solved");
+    }
+
+    #[test]
+    fn test_examples() {
+        fixtures::check_examples(env!("CARGO_MANIFEST_DIR"), Solution::new);
     }
 
     #[test]
     fn test_generate_code() {
-        assert_eq!(Solution::generate_code(1, 1), 20151125);
-        assert_eq!(Solution::generate_code(1, 2), 18749137);
-        assert_eq!(Solution::generate_code(1, 3), 17289845);
-        assert_eq!(Solution::generate_code(2, 1), 31916031);
-        assert_eq!(Solution::generate_code(2, 2), 21629792);
-        assert_eq!(Solution::generate_code(3, 1), 16080970);
-        assert_eq!(Solution::generate_code(5, 6), 31663883);
-        assert_eq!(Solution::generate_code(6, 5), 1534922);
-        assert_eq!(Solution::generate_code(6, 6), 27995004);
+        assert_eq!(Solution::generate_code(1, 1), Ok(20151125));
+        assert_eq!(Solution::generate_code(1, 2), Ok(18749137));
+        assert_eq!(Solution::generate_code(1, 3), Ok(17289845));
+        assert_eq!(Solution::generate_code(2, 1), Ok(31916031));
+        assert_eq!(Solution::generate_code(2, 2), Ok(21629792));
+        assert_eq!(Solution::generate_code(3, 1), Ok(16080970));
+        assert_eq!(Solution::generate_code(5, 6), Ok(31663883));
+        assert_eq!(Solution::generate_code(6, 5), Ok(1534922));
+        assert_eq!(Solution::generate_code(6, 6), Ok(27995004));
+    }
+
+    #[test]
+    fn test_generate_code_out_of_grid() {
+        assert_eq!(
+            Solution::generate_code(0, 3),
+            Err(PuzzleError::PositionOutOfGrid { row: 0, col: 3 })
+        );
+        assert_eq!(
+            Solution::generate_code(3, 0),
+            Err(PuzzleError::PositionOutOfGrid { row: 3, col: 0 })
+        );
     }
 
     #[test]
@@ -173,4 +207,23 @@ mod tests {
         assert_eq!(Solution::calculate_index(5, 2), 17);
         assert_eq!(Solution::calculate_index(6, 1), 16);
     }
+
+    #[test]
+    fn test_parse_content_invalid() {
+        let mut solution = Solution::new();
+        let err = solution
+            .parse_content(vec![String::from(
+                "To continue, please consult the code grid in the manual.  Enter the code at row 0, column 5.",
+            )])
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<PuzzleError>(),
+            Some(&PuzzleError::InvalidPosition {
+                line: 1,
+                field: "row",
+                text: String::from("0")
+            })
+        );
+    }
 }